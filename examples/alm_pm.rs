@@ -60,7 +60,7 @@ fn main() {
         Some(f1_jacobian_product),
         NO_MAPPING,
         NO_JACOBIAN_MAPPING,
-        Some(set_c),
+        Some(set_c.clone()),
         n2,
     );
 
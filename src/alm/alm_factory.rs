@@ -219,7 +219,6 @@ where
         (self.f)(u, cost)?;
         let ny = if !xi.is_empty() { xi.len() - 1 } else { 0 };
         let mut f1_u_plus_y_over_c = vec![0.0; ny];
-        let mut s = vec![0.0; ny];
         if let (Some(set_c), Some(mapping_f1)) = (&self.set_c, &self.mapping_f1) {
             let penalty_parameter = xi[0];
             mapping_f1(u, &mut f1_u_plus_y_over_c)?; // f1_u = F1(u)
@@ -232,11 +231,7 @@ where
                 .iter_mut()
                 .zip(y_lagrange_mult.iter())
                 .for_each(|(ti, yi)| *ti += yi / f64::max(penalty_parameter, 1.0));
-            s.copy_from_slice(&f1_u_plus_y_over_c);
-            set_c.project(&mut s);
-            *cost += 0.5
-                * penalty_parameter
-                * matrix_operations::norm2_squared_diff(&f1_u_plus_y_over_c, &s);
+            *cost += 0.5 * penalty_parameter * set_c.distance_squared(&f1_u_plus_y_over_c);
         }
         if let Some(f2) = &self.mapping_f2 {
             let c = xi[0];
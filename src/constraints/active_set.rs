@@ -0,0 +1,140 @@
+use super::Constraint;
+
+/// Relative tolerance used to decide whether a vector is a multiple of another one
+const PARALLEL_TOLERANCE: f64 = 1e-12;
+
+/// A constraint which is active at a given point
+///
+/// The constraints are indexed in the way the set is defined; for example, the
+/// lower bound on the $i$-th element of a rectangle is `LowerBound(i)`.
+///
+/// In a Cartesian product, the bounds are indexed by the elements of the
+/// product, so the bounds of each segment are reported with offset indices,
+/// while the other constraints of the $k$-th segment are reported as
+/// `Segment(k, constraint)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ActiveConstraint {
+    /// The lower bound on the $i$-th element is active
+    LowerBound(usize),
+    /// The upper bound on the $i$-th element is active
+    UpperBound(usize),
+    /// The $i$-th equality constraint (which is always active)
+    Equality(usize),
+    /// The $i$-th inequality constraint (e.g., the $i$-th row of $Ax \leq b$
+    /// in a polyhedron) is active
+    Inequality(usize),
+    /// The point is on the boundary of a set which is described by a single
+    /// constraint (e.g., a halfspace, a ball or a second-order cone)
+    Boundary,
+    /// A constraint of the $k$-th segment of a Cartesian product is active
+    Segment(usize, Box<ActiveConstraint>),
+}
+
+/// Information about the constraints which define a set
+///
+/// This trait is implemented by the sets which are described by finitely many
+/// simple constraints (bounds, halfspaces, polyhedra, affine spaces, norm-balls,
+/// second-order cones) and their Cartesian products, and can be used for
+/// post-solve analysis, e.g., to report which limits were hit, or to verify
+/// the KKT conditions: for a closed convex set, $C$, a point $x\in C$ and a
+/// vector $y$, it is $y \in N_C(x)$ if and only if $y$ coincides with its
+/// projection on the normal cone, $N_C(x)$.
+///
+/// The trait is not implemented by nonconvex sets (e.g., `Stiefel`, `Sphere2`,
+/// `FiniteSet`, `Cardinality` or `Union`), where the normal cone is not the
+/// polar of the tangent cone and the KKT conditions above do not characterise
+/// projections, nor by the wrappers `Scaled`, `Permuted`, `Translated` and
+/// `Intersection`, which do not know how the wrapped sets are described. It is
+/// not yet implemented for the other convex sets (e.g., `Ellipsoid`, `PsdCone`
+/// or `ExponentialCone`), whose boundaries are curved and are not described by
+/// a single norm constraint.
+///
+/// # Example
+///
+/// ```
+/// use optimization_engine::constraints::*;
+///
+/// let xmin = [-1.0, -1.0];
+/// let xmax = [1.0, 1.0];
+/// let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+/// let mut x = [0.5, 3.0];
+/// rectangle.project(&mut x);
+/// // which limits were hit?
+/// let active = rectangle.active_constraints(&x, 1e-8);
+/// assert_eq!(vec![ActiveConstraint::UpperBound(1)], active);
+/// ```
+///
+pub trait ActiveSet: Constraint {
+    /// Constraints which are active at `x`, that is, constraints which are
+    /// satisfied with equality up to a tolerance, `tol`
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: a point of the set
+    /// - `tol`: tolerance
+    ///
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint>;
+
+    /// Support function of the set, that is,
+    ///
+    /// $$
+    /// \sigma_C(y) = \sup_{z\in C}\langle y, z\rangle,
+    /// $$
+    ///
+    /// which is equal to $+\infty$ if the supremum is not attained
+    ///
+    /// ## Arguments
+    ///
+    /// - `y`: the given vector
+    ///
+    fn support_function(&self, y: &[f64]) -> f64;
+
+    /// Projection on the normal cone of the set at `x`, $N_C(x)$
+    ///
+    /// The normal cone is determined by the constraints which are active at `x`
+    /// (see `active_constraints`).
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: a point of the set
+    /// - `y`: (in) the given vector, (out) its projection on $N_C(x)$
+    /// - `tol`: tolerance used to determine the active constraints
+    ///
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64);
+}
+
+/// Finds the root of the increasing function
+/// $h(\mu) = k\mu - s - \sum_j \max\\{0, v_j - \mu\\}$, where $k > 0$
+///
+/// This is needed to project on normal cones which are generated by a vector
+/// and a set of (one-sided) coordinate directions.
+pub(super) fn piecewise_linear_root(count: usize, sum: f64, values: &mut [f64]) -> f64 {
+    values.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap());
+    let mut count = count as f64;
+    let mut sum = sum;
+    let mut mu = sum / count;
+    for &v in values.iter() {
+        if v <= mu {
+            break;
+        }
+        sum += v;
+        count += 1.0;
+        mu = sum / count;
+    }
+    mu
+}
+
+/// Returns $\lambda$ if $y = \lambda c$ (up to a small relative tolerance),
+/// or `None` otherwise
+pub(super) fn multiple_of(y: &[f64], c: &[f64], c_squared_norm: f64) -> Option<f64> {
+    let lambda = crate::matrix_operations::inner_product(y, c) / c_squared_norm;
+    let residual = y
+        .iter()
+        .zip(c.iter())
+        .fold(0.0, |sum, (yi, ci)| sum + (yi - lambda * ci).powi(2));
+    if residual.sqrt() <= PARALLEL_TOLERANCE * crate::matrix_operations::norm2(y) {
+        Some(lambda)
+    } else {
+        None
+    }
+}
@@ -1,23 +1,51 @@
-use super::Constraint;
+use super::sparse_ldl::{CsrMatrix, SparseLdl};
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
 
-extern crate modcholesky;
 extern crate ndarray;
 
-use modcholesky::ModCholeskySE99;
-use ndarray::{Array1, Array2, ArrayBase, Dim, OwnedRepr};
+use ndarray::{Array2, ArrayBase, Dim, OwnedRepr};
 
 type OpenMat<T> = ArrayBase<OwnedRepr<T>, Dim<[usize; 2]>>;
-type OpenVec<T> = ArrayBase<OwnedRepr<T>, Dim<[usize; 1]>>;
+
+/// Columns of the QR factorisation of $A^\intercal$ whose norm is smaller than this
+/// tolerance (relative to the largest norm of the rows of $A$) are treated as zero,
+/// that is, the corresponding rows of $A$ are linearly dependent on the other rows
+const RANK_TOLERANCE: f64 = 1e-10;
+
+/// Tolerance (relative to the infinity norm of $b$) of the check that the system
+/// $Ax = b$ is consistent
+const CONSISTENCY_TOLERANCE: f64 = 1e-8;
+
+/// Matrix $A$ and its factorisation
+#[derive(Clone)]
+enum AffineSpaceMatrix {
+    /// Dense matrix and QR factorisation of $A^\intercal$ with column pivoting,
+    /// $A^\intercal P = QR$, where only the first $r$ columns of $Q$ and rows of
+    /// $R$ are kept, with $r$ being the rank of $A$
+    Dense {
+        a_mat: OpenMat<f64>,
+        q_mat: OpenMat<f64>,
+        r_mat: OpenMat<f64>,
+        perm: Vec<usize>,
+        /// solution of $R_{11}^\intercal c = (P^\intercal b)_{1:r}$, so that the
+        /// projection of $x$ is $x - Q(Q^\intercal x - c)$
+        c: Vec<f64>,
+    },
+    /// Sparse matrix and $LDL^\intercal$ factorisation of $AA^\intercal$
+    Sparse { a_mat: CsrMatrix, ldl: SparseLdl },
+}
 
 #[derive(Clone)]
 /// An affine space here is defined as the set of solutions of a linear equation, $Ax = b$,
-/// that is, $E=\\{x\in\mathbb{R}^n: Ax = b\\}$, which is an affine space. It is assumed that
-/// the matrix $AA^\intercal$ is full-rank.
+/// that is, $E=\\{x\in\mathbb{R}^n: Ax = b\\}$, which is an affine space.
+///
+/// The matrix $A$ does not need to have full row rank (for example, some of the
+/// equations may be redundant), but the system $Ax = b$ must be consistent. The
+/// matrix can be either dense (see `new`) or sparse (see `new_sparse`).
 pub struct AffineSpace {
-    a_mat: OpenMat<f64>,
-    b_vec: OpenVec<f64>,
-    l: OpenMat<f64>,
-    p: OpenVec<usize>,
+    matrix: AffineSpaceMatrix,
+    b_vec: Vec<f64>,
     n_rows: usize,
     n_cols: usize,
 }
@@ -34,6 +62,27 @@ impl AffineSpace {
     /// ## Returns
     /// New Affine Space structure
     ///
+    /// ## Panics
+    ///
+    /// The method panics if `a` and `b` have incompatible dimensions, or if the
+    /// system $Ax = b$ is inconsistent
+    ///
+    /// ## Example
+    ///
+    /// The matrix $A$ may be rank deficient, provided that $Ax = b$ is consistent
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // the third equation is the sum of the other two
+    /// let a = vec![1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0];
+    /// let b = vec![1.0, 2.0, 3.0];
+    /// let affine_set = AffineSpace::new(a, b);
+    /// let mut x = [0.0, 0.0, 0.0];
+    /// affine_set.project(&mut x);
+    /// assert!((x[0] + x[1] - 1.0).abs() < 1e-12 && (x[1] + x[2] - 2.0).abs() < 1e-12);
+    /// ```
+    ///
     pub fn new(a: Vec<f64>, b: Vec<f64>) -> Self {
         // Infer dimensions of A and b
         let n_rows = b.len();
@@ -42,39 +91,474 @@ impl AffineSpace {
             n_elements_a % n_rows == 0,
             "A and b have incompatible dimensions"
         );
-        let n_cols = n_elements_a / n_rows;
-        // Cast A and b as ndarray structures
+        let affine_space = AffineSpace::new_dense_unchecked(a, b);
+        assert!(
+            affine_space.is_consistent(),
+            "the system Ax = b is inconsistent"
+        );
+        affine_space
+    }
+
+    /// Construct a new affine space given the matrix $A\in\mathbb{R}^{m\times n}$ and
+    /// the vector $b\in\mathbb{R}^m$, or return an error if the data is invalid
+    ///
+    /// ## Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `a` or `b` is empty
+    /// - `ConstructionError::DimensionMismatch` if the number of elements of `a` is
+    ///   not a multiple of the length of `b` (`expected` is the next multiple)
+    /// - `ConstructionError::InconsistentSystem` if $Ax = b$ has no solution
+    ///
+    pub fn try_new(a: Vec<f64>, b: Vec<f64>) -> Result<Self, ConstructionError> {
+        let n_rows = b.len();
+        if n_rows == 0 || a.is_empty() {
+            return Err(ConstructionError::EmptyData);
+        }
+        let n_cols = a.len().div_ceil(n_rows);
+        if n_rows * n_cols != a.len() {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: n_rows * n_cols,
+                found: a.len(),
+            });
+        }
+        let affine_space = AffineSpace::new_dense_unchecked(a, b);
+        if !affine_space.is_consistent() {
+            return Err(ConstructionError::InconsistentSystem);
+        }
+        Ok(affine_space)
+    }
+
+    /// Construct a new affine space given a sparse matrix $A\in\mathbb{R}^{m\times n}$
+    /// in compressed sparse row (CSR) format and the vector $b\in\mathbb{R}^m$
+    ///
+    /// The projection is computed using a sparse factorisation of $AA^\intercal$
+    /// (see `project`), which is computed once, upon construction. This is suitable
+    /// for large, sparse and possibly redundant systems of equations, such as the
+    /// dynamics of multiple-shooting formulations of optimal control problems.
+    ///
+    /// ## Arguments
+    ///
+    /// - `n_cols`: number of columns of $A$, $n$
+    /// - `row_offsets`: the column indices and values of the nonzero elements of
+    ///   row `i` are at positions `row_offsets[i]..row_offsets[i + 1]` of `col_indices`
+    ///   and `values`; this vector has $m + 1$ elements, the first of which is zero
+    /// - `col_indices`: column indices of the nonzero elements
+    /// - `values`: values of the nonzero elements
+    /// - `b`: vector $b$
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if the given data is not a valid matrix in CSR format,
+    /// if it is incompatible with $b$, or if the system $Ax = b$ is inconsistent
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // A = [1 -1  0  0]
+    /// //     [0  1 -1  0]
+    /// //     [0  0  1 -1]
+    /// let row_offsets = vec![0, 2, 4, 6];
+    /// let col_indices = vec![0, 1, 1, 2, 2, 3];
+    /// let values = vec![1.0, -1.0, 1.0, -1.0, 1.0, -1.0];
+    /// let b = vec![1.0, 1.0, 1.0];
+    /// let affine_set = AffineSpace::new_sparse(4, row_offsets, col_indices, values, b);
+    /// let mut x = [0.0; 4];
+    /// affine_set.project(&mut x);
+    /// assert!((x[0] - 1.5).abs() < 1e-12 && (x[3] + 1.5).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn new_sparse(
+        n_cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<f64>,
+        b: Vec<f64>,
+    ) -> Self {
+        let n_rows = b.len();
+        assert!(n_rows > 0, "empty data not allowed");
+        assert!(
+            row_offsets.len() == n_rows + 1,
+            "A and b have incompatible dimensions"
+        );
+        assert!(
+            col_indices.len() == values.len() && row_offsets[n_rows] == values.len(),
+            "inconsistent number of nonzero elements"
+        );
+        assert!(
+            row_offsets[0] == 0 && row_offsets.windows(2).all(|w| w[0] <= w[1]),
+            "row offsets must be nondecreasing and start from zero"
+        );
+        assert!(
+            col_indices.iter().all(|&j| j < n_cols),
+            "column index out of bounds"
+        );
+        let affine_space =
+            AffineSpace::new_sparse_unchecked(n_cols, row_offsets, col_indices, values, b);
+        assert!(
+            affine_space.is_consistent(),
+            "the system Ax = b is inconsistent"
+        );
+        affine_space
+    }
+
+    /// Construct a new affine space given a sparse matrix in CSR format and the
+    /// vector $b$ (see `new_sparse`), or return an error if the data is invalid
+    ///
+    /// ## Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `b` is empty
+    /// - `ConstructionError::DimensionMismatch` if `row_offsets` does not have
+    ///   $m + 1$ elements, or if the number of nonzero elements given by `row_offsets`,
+    ///   `col_indices` and `values` is not the same
+    /// - `ConstructionError::InvalidParameter` if the row offsets are decreasing
+    ///   or do not start from zero (`row_offsets`), or if a column index is out of
+    ///   bounds (`col_indices`)
+    /// - `ConstructionError::InconsistentSystem` if $Ax = b$ has no solution
+    ///
+    pub fn try_new_sparse(
+        n_cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<f64>,
+        b: Vec<f64>,
+    ) -> Result<Self, ConstructionError> {
+        let n_rows = b.len();
+        if n_rows == 0 {
+            return Err(ConstructionError::EmptyData);
+        }
+        if row_offsets.len() != n_rows + 1 {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: n_rows + 1,
+                found: row_offsets.len(),
+            });
+        }
+        if col_indices.len() != values.len() {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: values.len(),
+                found: col_indices.len(),
+            });
+        }
+        if row_offsets[n_rows] != values.len() {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: values.len(),
+                found: row_offsets[n_rows],
+            });
+        }
+        let mut previous = 0;
+        for (i, &offset) in row_offsets.iter().enumerate() {
+            if offset < previous || (i == 0 && offset != 0) {
+                return Err(ConstructionError::InvalidParameter {
+                    name: "row_offsets",
+                    value: offset as f64,
+                });
+            }
+            previous = offset;
+        }
+        if let Some(&j) = col_indices.iter().find(|&&j| j >= n_cols) {
+            return Err(ConstructionError::InvalidParameter {
+                name: "col_indices",
+                value: j as f64,
+            });
+        }
+        let affine_space =
+            AffineSpace::new_sparse_unchecked(n_cols, row_offsets, col_indices, values, b);
+        if !affine_space.is_consistent() {
+            return Err(ConstructionError::InconsistentSystem);
+        }
+        Ok(affine_space)
+    }
+
+    /// Constructs an affine space with a dense matrix (without checking the data)
+    fn new_dense_unchecked(a: Vec<f64>, b: Vec<f64>) -> Self {
+        let n_rows = b.len();
+        let n_cols = a.len() / n_rows;
+        // Cast A as an ndarray structure
         let a_mat = Array2::from_shape_vec((n_rows, n_cols), a).unwrap();
-        let b_vec = Array1::from_shape_vec((n_rows,), b).unwrap();
-        // Compute a permuted Cholesky factorisation of AA'; in particular, we are looking for a
-        // minimum-norm matrix E, a permulation matrix P and a lower-trianular L, such that
-        //  P(AA' + E)P' = LL'
-        // and E should be 0 if A is full rank.
-        let a_times_a_t = a_mat.dot(&a_mat.t());
-        let res = a_times_a_t.mod_cholesky_se99();
-        let l = res.l;
-        let p = res.p;
-
-        // Construct and return new AffineSpace structure
+        let (q_mat, r_mat, perm) = AffineSpace::factorize(&a_mat);
+        let mut affine_space = AffineSpace {
+            matrix: AffineSpaceMatrix::Dense {
+                a_mat,
+                q_mat,
+                r_mat,
+                perm,
+                c: vec![],
+            },
+            b_vec: b,
+            n_rows,
+            n_cols,
+        };
+        affine_space.update_c();
+        affine_space
+    }
+
+    /// Constructs an affine space with a sparse matrix (without checking the data)
+    fn new_sparse_unchecked(
+        n_cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<f64>,
+        b: Vec<f64>,
+    ) -> Self {
+        let n_rows = b.len();
+        let a_mat = CsrMatrix::new(n_cols, row_offsets, col_indices, values);
+        let ldl = SparseLdl::new(&a_mat);
         AffineSpace {
-            a_mat,
-            b_vec,
-            l,
-            p,
+            matrix: AffineSpaceMatrix::Sparse { a_mat, ldl },
+            b_vec: b,
             n_rows,
             n_cols,
         }
     }
+
+    /// Compute the QR factorisation of $A^\intercal$ with column pivoting,
+    /// $A^\intercal P = QR$, using Householder reflections
+    ///
+    /// The pivoting stops when the norms of all remaining columns are (relatively)
+    /// small, which determines the rank, $r$, of $A$; the method returns the first
+    /// $r$ columns of $Q$, the first $r$ rows of $R$ and the permutation
+    fn factorize(a_mat: &OpenMat<f64>) -> (OpenMat<f64>, OpenMat<f64>, Vec<usize>) {
+        let (m, n) = a_mat.dim();
+        let mut r_mat = a_mat.t().to_owned();
+        let mut perm: Vec<usize> = (0..m).collect();
+        let mut reflectors: Vec<Vec<f64>> = Vec::new();
+        let mut max_norm = 0.0;
+        for k in 0..m.min(n) {
+            // pivot: the column with the largest norm (rows k..n)
+            let (j_max, norm_squared) = (k..m)
+                .map(|j| (j, (k..n).fold(0.0, |sum, i| sum + r_mat[(i, j)].powi(2))))
+                .fold(
+                    (k, -1.0),
+                    |best, (j, s)| if s > best.1 { (j, s) } else { best },
+                );
+            let norm = norm_squared.sqrt();
+            if k == 0 {
+                max_norm = norm;
+            }
+            if norm <= RANK_TOLERANCE * max_norm || norm == 0.0 {
+                break;
+            }
+            if j_max != k {
+                for i in 0..n {
+                    r_mat.swap((i, k), (i, j_max));
+                }
+                perm.swap(k, j_max);
+            }
+            // Householder reflection, H = I - 2vv'/(v'v), which maps column k
+            // (rows k..n) to a multiple of the first unit vector
+            let alpha = if r_mat[(k, k)] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k..n).map(|i| r_mat[(i, k)]).collect();
+            v[0] -= alpha;
+            let v_norm_squared = crate::matrix_operations::norm2_squared(&v);
+            for j in k..m {
+                let s = 2.0
+                    * v.iter()
+                        .enumerate()
+                        .fold(0.0, |sum, (i, vi)| sum + vi * r_mat[(k + i, j)])
+                    / v_norm_squared;
+                v.iter()
+                    .enumerate()
+                    .for_each(|(i, vi)| r_mat[(k + i, j)] -= s * vi);
+            }
+            reflectors.push(v);
+        }
+
+        // Q = H(0) H(1) ... H(r-1) [I; 0]
+        let rank = reflectors.len();
+        let mut q_mat = Array2::<f64>::zeros((n, rank));
+        for i in 0..rank {
+            q_mat[(i, i)] = 1.0;
+        }
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            let v_norm_squared = crate::matrix_operations::norm2_squared(v);
+            for j in 0..rank {
+                let s = 2.0
+                    * v.iter()
+                        .enumerate()
+                        .fold(0.0, |sum, (i, vi)| sum + vi * q_mat[(k + i, j)])
+                    / v_norm_squared;
+                v.iter()
+                    .enumerate()
+                    .for_each(|(i, vi)| q_mat[(k + i, j)] -= s * vi);
+            }
+        }
+        let r_mat =
+            Array2::from_shape_fn((rank, m), |(i, j)| if i <= j { r_mat[(i, j)] } else { 0.0 });
+        (q_mat, r_mat, perm)
+    }
+
+    /// Solve $R_{11}^\intercal c = (P^\intercal b)_{1:r}$ (dense matrices)
+    fn update_c(&mut self) {
+        if let AffineSpaceMatrix::Dense { r_mat, perm, c, .. } = &mut self.matrix {
+            let rank = r_mat.nrows();
+            c.resize(rank, 0.0);
+            for i in 0..rank {
+                let mut ci = self.b_vec[perm[i]];
+                for k in 0..i {
+                    ci -= r_mat[(k, i)] * c[k];
+                }
+                c[i] = ci / r_mat[(i, i)];
+            }
+        }
+    }
+
+    /// Returns true if and only if the system $Ax = b$ is consistent (up to a
+    /// tolerance), that is, if $b$ is in the range of $A$
+    ///
+    /// For dense matrices, the equations which correspond to the last $m - r$
+    /// columns of $A^\intercal P$ are linear combinations of the first $r$ ones;
+    /// these are satisfied by all $x$ with $Q^\intercal x = c$ if and only if
+    /// $(P^\intercal b)_i = \sum_k R_{ki} c_k$ for $i > r$. For sparse matrices,
+    /// see `SparseLdl::in_range`, which needs a vector of dimension $m$ (this is
+    /// allocated here).
+    fn is_consistent(&self) -> bool {
+        let tol = CONSISTENCY_TOLERANCE * (1.0 + crate::matrix_operations::norm_inf(&self.b_vec));
+        match &self.matrix {
+            AffineSpaceMatrix::Dense { r_mat, perm, c, .. } => {
+                (r_mat.nrows()..self.n_rows).all(|i| {
+                    let ri_c = c
+                        .iter()
+                        .enumerate()
+                        .fold(0.0, |sum, (k, ck)| sum + r_mat[(k, i)] * ck);
+                    (ri_c - self.b_vec[perm[i]]).abs() <= tol
+                })
+            }
+            AffineSpaceMatrix::Sparse { ldl, .. } => {
+                ldl.in_range(&self.b_vec, &mut vec![0.0; self.n_rows], tol)
+            }
+        }
+    }
+
+    /// Update the vector $b$
+    ///
+    /// The given vector is copied into the data of the affine space and, since the
+    /// matrix $A$ does not change, the factorisation of $A$ is not recomputed. No
+    /// memory is allocated if $A$ is dense; if $A$ is sparse, a vector of dimension
+    /// $m$ is allocated to check whether $Ax = b$ is consistent.
+    ///
+    /// ## Arguments
+    ///
+    /// - `b`: new vector $b$
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if `b` does not have the dimension of the current $b$,
+    /// or if the system $Ax = b$ is inconsistent
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// let mut affine_set = AffineSpace::new(vec![1.0, 1.0], vec![1.0]);
+    /// affine_set.set_b(&[3.0]);
+    /// let mut x = [0.0, 1.0];
+    /// affine_set.project(&mut x);
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn set_b(&mut self, b: &[f64]) {
+        assert!(b.len() == self.n_rows, "b has wrong dimension");
+        self.b_vec.copy_from_slice(b);
+        self.update_c();
+        assert!(self.is_consistent(), "the system Ax = b is inconsistent");
+    }
+
+    /// Update the matrix $A$
+    ///
+    /// The given matrix is copied into the data of the affine space and the
+    /// factorisation of $A$ is recomputed.
+    ///
+    /// ## Arguments
+    ///
+    /// - `a`: new matrix $A$, row-wise data
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if `a` does not have the dimensions of the current $A$,
+    /// if the system $Ax = b$ is inconsistent, or if the matrix $A$ is sparse
+    /// (see `set_a_values`)
+    ///
+    pub fn set_a(&mut self, a: &[f64]) {
+        assert!(
+            a.len() == self.n_rows * self.n_cols,
+            "A has wrong dimensions"
+        );
+        match &mut self.matrix {
+            AffineSpaceMatrix::Dense {
+                a_mat,
+                q_mat,
+                r_mat,
+                perm,
+                ..
+            } => {
+                a_mat
+                    .iter_mut()
+                    .zip(a.iter())
+                    .for_each(|(aij, &new_aij)| *aij = new_aij);
+                let (q, r, p) = AffineSpace::factorize(a_mat);
+                *q_mat = q;
+                *r_mat = r;
+                *perm = p;
+            }
+            AffineSpaceMatrix::Sparse { .. } => panic!("A is sparse (use set_a_values)"),
+        }
+        self.update_c();
+        assert!(self.is_consistent(), "the system Ax = b is inconsistent");
+    }
+
+    /// Update the values of the nonzero elements of a sparse matrix $A$
+    ///
+    /// The sparsity pattern of $A$ does not change, so the ordering of the rows of
+    /// $A$ is reused and only the numerical factorisation of $AA^\intercal$ is
+    /// recomputed. This is useful, e.g., when the dynamics of an optimal control
+    /// problem is linearised along a different trajectory.
+    ///
+    /// ## Arguments
+    ///
+    /// - `values`: new values of the nonzero elements of $A$ (in the order of
+    ///   the data given to `new_sparse`)
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if `values` does not have the number of nonzero elements
+    /// of $A$, if the system $Ax = b$ is inconsistent, or if the matrix $A$ is dense
+    /// (see `set_a`)
+    ///
+    pub fn set_a_values(&mut self, values: &[f64]) {
+        match &mut self.matrix {
+            AffineSpaceMatrix::Sparse { a_mat, ldl } => {
+                let a_values = a_mat.values_mut();
+                assert!(a_values.len() == values.len(), "values has wrong dimension");
+                a_values.copy_from_slice(values);
+                ldl.factorize(a_mat);
+            }
+            AffineSpaceMatrix::Dense { .. } => panic!("A is dense (use set_a)"),
+        }
+        assert!(self.is_consistent(), "the system Ax = b is inconsistent");
+    }
 }
 
 impl Constraint for AffineSpace {
     /// Projection onto the set $E = \\{x: Ax = b\\}$, which is computed by
     /// $$P_E(x) = x - A^\intercal z(x),$$
-    /// where $z$ is the solution of the linear system
+    /// where $z$ is a solution of the linear system
     /// $$(AA^\intercal)z = Ax - b,$$
-    /// which has a unique solution provided $A$ has full row rank. The linear system
-    /// is solved by computing the Cholesky factorisation of $AA^\intercal$, which is
-    /// done using [`modcholesky`](https://crates.io/crates/modcholesky).
+    /// which is consistent provided that $Ax = b$ is consistent.
+    ///
+    /// For dense matrices, the QR factorisation of $A^\intercal$ with column
+    /// pivoting, $A^\intercal P = QR$, is used, where $Q$ has $r$ columns, with $r$
+    /// being the rank of $A$; then, $A^\intercal z(x) = Q(Q^\intercal x - c)$.
+    ///
+    /// For sparse matrices, the linear system is solved using an $LDL^\intercal$
+    /// factorisation of $AA^\intercal$ (after reordering its rows and columns with
+    /// the reverse Cuthill-McKee algorithm, to reduce fill-in); the rows of $A$
+    /// which are linearly dependent on the other rows lead to zero pivots and the
+    /// corresponding elements of $z$ are set to zero.
+    ///
+    /// A vector of dimension $r$ (dense matrices) or $m$ (sparse matrices) is
+    /// allocated, where $m$ is the number of rows of $A$.
     ///
     /// ## Arguments
     ///
@@ -100,49 +584,129 @@ impl Constraint for AffineSpace {
     ///
     /// The result is stored in `x` and it can be verified that $Ax = b$.
     fn project(&self, x: &mut [f64]) {
-        let m = self.n_rows;
-        let n = self.n_cols;
-        let chol = &self.l;
-        let perm = &self.p;
-
-        assert!(x.len() == n, "x has wrong dimension");
-        let x_vec = x.to_vec();
-        let x_arr = Array1::from_shape_vec((n,), x_vec).unwrap();
-        let ax = self.a_mat.dot(&x_arr);
-        let err = ax - &self.b_vec;
-
-        // Step 1: Solve Ly = b(P)
-        // TODO: Make `y` into an attribute; however, to do this, we need to change
-        // &self to &mut self, which will require a mild refactoring
-        let mut y = vec![0.; m];
-        for i in 0..m {
-            y[i] = err[perm[i]];
-            for j in 0..i {
-                y[i] -= chol[(i, j)] * y[j];
-            }
-            y[i] /= chol[(i, i)];
-        }
+        assert!(x.len() == self.n_cols, "x has wrong dimension");
+        match &self.matrix {
+            AffineSpaceMatrix::Dense { q_mat, c, .. } => {
+                // Step 1: w = Q'x - c
+                let mut w = vec![0.0; c.len()];
+                w.iter_mut()
+                    .zip(q_mat.columns())
+                    .zip(c.iter())
+                    .for_each(|((wk, qk), ck)| {
+                        *wk = qk
+                            .iter()
+                            .zip(x.iter())
+                            .fold(-ck, |sum, (qik, xi)| sum + qik * xi)
+                    });
 
-        // Step 2: Solve L'z(P) = y
-        let mut z = vec![0.; m];
-        for i in 1..=m {
-            z[perm[m - i]] = y[m - i];
-            for j in 1..i {
-                z[perm[m - i]] -= chol[(m - j, m - i)] * z[perm[m - j]];
+                // Step 2: x <-- x - Qw
+                x.iter_mut().zip(q_mat.rows()).for_each(|(xi, qi)| {
+                    *xi -= qi
+                        .iter()
+                        .zip(w.iter())
+                        .fold(0.0, |sum, (qik, wk)| sum + qik * wk)
+                });
             }
-            z[perm[m - i]] /= chol[(m - i, m - i)];
-        }
+            AffineSpaceMatrix::Sparse { a_mat, ldl } => {
+                // Step 1: y = P(Ax - b)
+                let mut y = vec![0.0; self.n_rows];
+                y.iter_mut()
+                    .zip(ldl.perm().iter())
+                    .for_each(|(yi, &p)| *yi = a_mat.row_dot(p, x) - self.b_vec[p]);
 
-        // Step 3: Determine A' * z
-        let z_arr = Array1::from_shape_vec((self.n_rows,), z).unwrap();
-        let w = self.a_mat.t().dot(&z_arr);
+                // Step 2: Solve PAA'P' z = y
+                ldl.solve(&mut y);
 
-        // Step 4: x <-- x - A'(AA')\(Ax-b)
-        x.iter_mut().zip(w.iter()).for_each(|(xi, wi)| *xi -= wi);
+                // Step 3: x <-- x - A'P'z
+                y.iter().zip(ldl.perm().iter()).for_each(|(&zi, &p)| {
+                    a_mat.row(p).for_each(|(j, apj)| x[j] -= apj * zi);
+                });
+            }
+        }
     }
 
     /// Affine sets are convex.
     fn is_convex(&self) -> bool {
         true
     }
+
+    /// Checks whether $\Vert Ax - b \Vert \leq \epsilon$, which does not require
+    /// a projection (or the allocation of memory)
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        assert!(x.len() == self.n_cols, "x has wrong dimension");
+        let residual_squared = match &self.matrix {
+            AffineSpaceMatrix::Dense { a_mat, .. } => a_mat
+                .rows()
+                .into_iter()
+                .zip(self.b_vec.iter())
+                .fold(0.0, |sum, (ai, bi)| {
+                    let ri = ai
+                        .iter()
+                        .zip(x.iter())
+                        .fold(-bi, |dot, (aij, xj)| dot + aij * xj);
+                    sum + ri * ri
+                }),
+            AffineSpaceMatrix::Sparse { a_mat, .. } => {
+                self.b_vec.iter().enumerate().fold(0.0, |sum, (i, bi)| {
+                    let ri = a_mat.row_dot(i, x) - bi;
+                    sum + ri * ri
+                })
+            }
+        };
+        residual_squared.sqrt() <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.n_cols)
+    }
+}
+
+impl AffineSpace {
+    /// The element of $E$ with the smallest norm, $\Pi_E(0)$
+    fn min_norm_element(&self) -> Vec<f64> {
+        let mut x = vec![0.0; self.n_cols];
+        self.project(&mut x);
+        x
+    }
+
+    /// Projection of `y` on the null space of $A$, which is computed by
+    /// $\Pi_{\mathrm{null}(A)}(y) = \Pi_E(y) - \Pi_E(0)$
+    fn project_on_null_space(&self, x_min_norm: &[f64], y: &mut [f64]) {
+        self.project(y);
+        y.iter_mut()
+            .zip(x_min_norm.iter())
+            .for_each(|(yi, xi)| *yi -= xi);
+    }
+}
+
+impl ActiveSet for AffineSpace {
+    /// All equations, $Ax = b$, are active
+    fn active_constraints(&self, _x: &[f64], _tol: f64) -> Vec<ActiveConstraint> {
+        (0..self.n_rows).map(ActiveConstraint::Equality).collect()
+    }
+
+    /// The support function is equal to $\langle y, x\rangle$, for any $x\in E$,
+    /// if $y$ is in the range of $A^\intercal$, and $+\infty$ otherwise
+    fn support_function(&self, y: &[f64]) -> f64 {
+        let x_min_norm = self.min_norm_element();
+        let mut y_null = y.to_vec();
+        self.project_on_null_space(&x_min_norm, &mut y_null);
+        let scale =
+            crate::matrix_operations::norm2(y) + crate::matrix_operations::norm2(&x_min_norm);
+        if crate::matrix_operations::norm2(&y_null) > RANK_TOLERANCE * scale {
+            return f64::INFINITY;
+        }
+        crate::matrix_operations::inner_product(y, &x_min_norm)
+    }
+
+    /// The normal cone at any point of $E$ is the range of $A^\intercal$, which
+    /// is the orthogonal complement of the null space of $A$
+    fn project_on_normal_cone(&self, _x: &[f64], y: &mut [f64], _tol: f64) {
+        let x_min_norm = self.min_norm_element();
+        let mut y_null = y.to_vec();
+        self.project_on_null_space(&x_min_norm, &mut y_null);
+        y.iter_mut()
+            .zip(y_null.iter())
+            .for_each(|(yi, ni)| *yi -= ni);
+    }
 }
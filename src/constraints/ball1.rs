@@ -1,13 +1,16 @@
+use super::active_set::piecewise_linear_root;
+use super::simplex::simplex_threshold;
 use super::Constraint;
-use super::Simplex;
+use super::{ActiveConstraint, ActiveSet};
+use crate::ConstructionError;
+use std::borrow::Cow;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 /// A norm-1 ball, that is, a set given by $B_1^r = \\{x \in \mathbb{R}^n {}:{} \Vert{}x{}\Vert_1 \leq r\\}$
 /// or a ball-1 centered at a point $x_c$, that is, $B_1^{x_c, r} = \\{x \in \mathbb{R}^n {}:{} \Vert{}x-x_c{}\Vert_1 \leq r\\}$
 pub struct Ball1<'a> {
-    center: Option<&'a [f64]>,
+    center: Option<Cow<'a, [f64]>>,
     radius: f64,
-    simplex: Simplex,
 }
 
 impl<'a> Ball1<'a> {
@@ -15,26 +18,46 @@ impl<'a> Ball1<'a> {
     /// If no `center` is given, then it is assumed to be in the origin
     pub fn new(center: Option<&'a [f64]>, radius: f64) -> Self {
         assert!(radius > 0.0);
-        let simplex = Simplex::new(radius);
         Ball1 {
-            center,
+            center: center.map(Cow::Borrowed),
             radius,
-            simplex,
         }
     }
 
+    /// Construct a new ball with given center and radius, or return an error
+    /// if the radius is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `radius` is not positive
+    ///
+    pub fn try_new(center: Option<&'a [f64]>, radius: f64) -> Result<Self, ConstructionError> {
+        if radius > 0.0 {
+            Ok(Ball1::new(center, radius))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            })
+        }
+    }
+
+    /// Construct a new ball-1 which owns its center (see `new`)
+    pub fn new_owned(center: Option<Vec<f64>>, radius: f64) -> Self {
+        assert!(radius > 0.0);
+        Ball1 {
+            center: center.map(Cow::Owned),
+            radius,
+        }
+    }
+
+    /// Projects on the ball by soft thresholding, $x_i \leftarrow \mathrm{sgn}(x_i)\max\\{|x_i| - \tau, 0\\}$,
+    /// where $\tau$ is the threshold of the projection of $|x|$ on the simplex
     fn project_on_ball1_centered_at_origin(&self, x: &mut [f64]) {
         if crate::matrix_operations::norm1(x) > self.radius {
-            // u = |x| (copied)
-            let mut u = vec![0.0; x.len()];
-            u.iter_mut()
-                .zip(x.iter())
-                .for_each(|(ui, &xi)| *ui = f64::abs(xi));
-            // u = P_simplex(u)
-            self.simplex.project(&mut u);
+            let tau = simplex_threshold(x.len(), self.radius, |i| x[i].abs(), |_| 1.0);
             x.iter_mut()
-                .zip(u.iter())
-                .for_each(|(xi, &ui)| *xi = f64::signum(*xi) * ui);
+                .for_each(|xi| *xi = xi.signum() * f64::max(xi.abs() - tau, 0.0));
         }
     }
 }
@@ -57,4 +80,90 @@ impl<'a> Constraint for Ball1<'a> {
     fn is_convex(&self) -> bool {
         true
     }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let norm1_difference = match &self.center {
+            Some(center) => x
+                .iter()
+                .zip(center.iter())
+                .fold(0.0, |sum, (xi, ci)| sum + (xi - ci).abs()),
+            None => crate::matrix_operations::norm1(x),
+        };
+        norm1_difference <= self.radius || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.center.as_ref().map(|center| center.len())
+    }
+}
+
+impl<'a> Ball1<'a> {
+    /// The vector $x - x_c$
+    fn difference_from_center(&self, x: &[f64]) -> Vec<f64> {
+        match &self.center {
+            Some(center) => x
+                .iter()
+                .zip(center.iter())
+                .map(|(xi, ci)| xi - ci)
+                .collect(),
+            None => x.to_vec(),
+        }
+    }
+}
+
+impl<'a> ActiveSet for Ball1<'a> {
+    /// The constraint is active if $\Vert{}x-x_c{}\Vert_1 \geq r - \epsilon$
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let norm1_difference = crate::matrix_operations::norm1(&self.difference_from_center(x));
+        if norm1_difference >= self.radius - tol {
+            vec![ActiveConstraint::Boundary]
+        } else {
+            vec![]
+        }
+    }
+
+    /// The support function is $\sigma(y) = \langle y, x_c\rangle + r\Vert{}y{}\Vert_\infty$
+    fn support_function(&self, y: &[f64]) -> f64 {
+        let inner_product = self.center.as_ref().map_or(0.0, |center| {
+            crate::matrix_operations::inner_product(y, center)
+        });
+        inner_product + self.radius * crate::matrix_operations::norm_inf(y)
+    }
+
+    /// If the constraint is active, the normal cone is the cone generated by the
+    /// subdifferential of $\Vert{}\cdot{}-x_c\Vert_1$ at $x$, where elements
+    /// with $|x_i - x_{c, i}| \leq \epsilon$ are treated as zero; otherwise, it
+    /// is $\\{0\\}$
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        if self.active_constraints(x, tol).is_empty() {
+            y.iter_mut().for_each(|yi| *yi = 0.0);
+            return;
+        }
+        // the normal cone is {lambda g : lambda >= 0, g_i = sign(d_i) if |d_i| > tol,
+        // |g_i| <= 1 otherwise}, where d = x - x_c
+        let d = self.difference_from_center(x);
+        let count = d.iter().filter(|di| di.abs() > tol).count();
+        if count == 0 {
+            return;
+        }
+        let sum = d
+            .iter()
+            .zip(y.iter())
+            .filter(|(di, _)| di.abs() > tol)
+            .fold(0.0, |sum, (di, yi)| sum + di.signum() * yi);
+        let mut values: Vec<f64> = d
+            .iter()
+            .zip(y.iter())
+            .filter(|(di, _)| di.abs() <= tol)
+            .map(|(_, yi)| yi.abs())
+            .collect();
+        let lambda = piecewise_linear_root(count, sum, &mut values).max(0.0);
+        y.iter_mut().zip(d.iter()).for_each(|(yi, di)| {
+            if di.abs() > tol {
+                *yi = lambda * di.signum();
+            } else {
+                *yi = yi.max(-lambda).min(lambda);
+            }
+        });
+    }
 }
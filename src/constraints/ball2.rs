@@ -1,10 +1,12 @@
-use super::Constraint;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
+use std::borrow::Cow;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 /// A Euclidean ball, that is, a set given by $B_2^r = \\{x \in \mathbb{R}^n {}:{} \Vert{}x{}\Vert \leq r\\}$
 /// or a Euclidean ball centered at a point $x_c$, that is, $B_2^{x_c, r} = \\{x \in \mathbb{R}^n {}:{} \Vert{}x-x_c{}\Vert \leq r\\}$
 pub struct Ball2<'a> {
-    center: Option<&'a [f64]>,
+    center: Option<Cow<'a, [f64]>>,
     radius: f64,
 }
 
@@ -14,7 +16,73 @@ impl<'a> Ball2<'a> {
     pub fn new(center: Option<&'a [f64]>, radius: f64) -> Self {
         assert!(radius > 0.0);
 
-        Ball2 { center, radius }
+        Ball2 {
+            center: center.map(Cow::Borrowed),
+            radius,
+        }
+    }
+
+    /// Construct a new ball with given center and radius, or return an error
+    /// if the radius is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `radius` is not positive
+    ///
+    pub fn try_new(center: Option<&'a [f64]>, radius: f64) -> Result<Self, ConstructionError> {
+        if radius > 0.0 {
+            Ok(Ball2::new(center, radius))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            })
+        }
+    }
+
+    /// Construct a new Euclidean ball which owns its center (see `new`)
+    pub fn new_owned(center: Option<Vec<f64>>, radius: f64) -> Self {
+        assert!(radius > 0.0);
+
+        Ball2 {
+            center: center.map(Cow::Owned),
+            radius,
+        }
+    }
+
+    /// Update the center of the ball
+    ///
+    /// The given center is copied into the data of the ball, so, if the
+    /// ball owns its center (see `new_owned`), no memory is allocated. A borrowed
+    /// center (or no center) is replaced by an owned copy upon the first update.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `center` does not have the same dimension as the
+    /// current center
+    ///
+    pub fn set_center(&mut self, center: &[f64]) {
+        match &mut self.center {
+            Some(current_center) => {
+                assert!(
+                    current_center.len() == center.len(),
+                    "center has wrong dimension"
+                );
+                current_center.to_mut().copy_from_slice(center);
+            }
+            None => self.center = Some(Cow::Owned(center.to_vec())),
+        }
+    }
+
+    /// Update the radius of the ball
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `radius` is not positive
+    ///
+    pub fn set_radius(&mut self, radius: f64) {
+        assert!(radius > 0.0);
+        self.radius = radius;
     }
 }
 
@@ -46,4 +114,64 @@ impl<'a> Constraint for Ball2<'a> {
     fn is_convex(&self) -> bool {
         true
     }
+
+    /// Distance from the ball, $\max\\{0, \Vert{}x-x_c{}\Vert - r\\}$ (squared)
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let norm_difference = match &self.center {
+            Some(center) => crate::matrix_operations::norm2_squared_diff(x, center).sqrt(),
+            None => crate::matrix_operations::norm2(x),
+        };
+        let distance = (norm_difference - self.radius).max(0.0);
+        distance * distance
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.center.as_ref().map(|center| center.len())
+    }
+}
+
+impl<'a> ActiveSet for Ball2<'a> {
+    /// The constraint is active if $\Vert{}x-x_c{}\Vert \geq r - \epsilon$
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let norm_difference = match &self.center {
+            Some(center) => crate::matrix_operations::norm2_squared_diff(x, center).sqrt(),
+            None => crate::matrix_operations::norm2(x),
+        };
+        if norm_difference >= self.radius - tol {
+            vec![ActiveConstraint::Boundary]
+        } else {
+            vec![]
+        }
+    }
+
+    /// The support function is $\sigma(y) = \langle y, x_c\rangle + r\Vert{}y{}\Vert$
+    fn support_function(&self, y: &[f64]) -> f64 {
+        let inner_product = self.center.as_ref().map_or(0.0, |center| {
+            crate::matrix_operations::inner_product(y, center)
+        });
+        inner_product + self.radius * crate::matrix_operations::norm2(y)
+    }
+
+    /// If the constraint is active, the normal cone is the ray generated by
+    /// $x - x_c$; otherwise, it is $\\{0\\}$
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        if self.active_constraints(x, tol).is_empty() {
+            y.iter_mut().for_each(|yi| *yi = 0.0);
+            return;
+        }
+        let direction: Vec<f64> = match &self.center {
+            Some(center) => x
+                .iter()
+                .zip(center.iter())
+                .map(|(xi, ci)| xi - ci)
+                .collect(),
+            None => x.to_vec(),
+        };
+        let norm_direction_squared = crate::matrix_operations::norm2_squared(&direction);
+        let factor = crate::matrix_operations::inner_product(y, &direction).max(0.0)
+            / norm_direction_squared;
+        y.iter_mut()
+            .zip(direction.iter())
+            .for_each(|(yi, di)| *yi = factor * di);
+    }
 }
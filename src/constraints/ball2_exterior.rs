@@ -0,0 +1,115 @@
+use super::Constraint;
+use crate::ConstructionError;
+
+#[derive(Copy, Clone)]
+/// The exterior of a Euclidean ball, that is, a set given by
+/// $\\{x \in \mathbb{R}^n {}:{} \Vert{}x{}\Vert \geq r\\}$, or
+/// $\\{x \in \mathbb{R}^n {}:{} \Vert{}x-x_c{}\Vert \geq r\\}$ for a ball
+/// centered at a point $x_c$
+///
+/// This is a nonconvex set which can be used to model collision avoidance
+/// constraints (e.g., a circular obstacle).
+pub struct Ball2Exterior<'a> {
+    center: Option<&'a [f64]>,
+    radius: f64,
+}
+
+impl<'a> Ball2Exterior<'a> {
+    /// Construct the exterior of a Euclidean ball with given center and radius
+    /// If no `center` is given, then it is assumed to be in the origin
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Ball2Exterior, Constraint};
+    ///
+    /// let obstacle_center = [1.0, 1.0];
+    /// let exterior = Ball2Exterior::new(Some(&obstacle_center), 0.5);
+    /// let mut x = [1.2, 1.0];
+    /// exterior.project(&mut x);
+    /// assert!((x[0] - 1.5).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn new(center: Option<&'a [f64]>, radius: f64) -> Self {
+        assert!(radius > 0.0);
+        Ball2Exterior { center, radius }
+    }
+
+    /// Construct a new exterior of a ball with given center and radius, or return an
+    /// error if the radius is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `radius` is not positive
+    ///
+    pub fn try_new(center: Option<&'a [f64]>, radius: f64) -> Result<Self, ConstructionError> {
+        if radius > 0.0 {
+            Ok(Ball2Exterior::new(center, radius))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            })
+        }
+    }
+}
+
+impl<'a> Constraint for Ball2Exterior<'a> {
+    /// Projection onto the exterior of the ball
+    ///
+    /// Points in the interior of the ball are projected on its boundary,
+    /// $x_c + r(x-x_c)/\Vert{}x-x_c\Vert$. The projection of the center
+    /// is multi-valued; the point $x_c + re_1$ is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected, (out) projection on the set
+    ///
+    fn project(&self, x: &mut [f64]) {
+        let epsilon = 1e-12;
+        if let Some(center) = &self.center {
+            let norm_difference = crate::matrix_operations::norm2_squared_diff(x, center).sqrt();
+            if norm_difference >= self.radius {
+                return;
+            }
+            if norm_difference <= epsilon {
+                x.copy_from_slice(center);
+                x[0] += self.radius;
+                return;
+            }
+            x.iter_mut().zip(center.iter()).for_each(|(x, c)| {
+                *x = *c + self.radius * (*x - *c) / norm_difference;
+            });
+        } else {
+            let norm_x = crate::matrix_operations::norm2(x);
+            if norm_x >= self.radius {
+                return;
+            }
+            if norm_x <= epsilon {
+                x.iter_mut().for_each(|xi| *xi = 0.0);
+                x[0] = self.radius;
+                return;
+            }
+            x.iter_mut().for_each(|xi| *xi *= self.radius / norm_x);
+        }
+    }
+
+    /// The exterior of a ball is nonconvex
+    fn is_convex(&self) -> bool {
+        false
+    }
+
+    /// Distance from the exterior of the ball, $\max\\{0, r - \Vert{}x-x_c{}\Vert\\}$ (squared)
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let norm_difference = match &self.center {
+            Some(center) => crate::matrix_operations::norm2_squared_diff(x, center).sqrt(),
+            None => crate::matrix_operations::norm2(x),
+        };
+        let distance = (self.radius - norm_difference).max(0.0);
+        distance * distance
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.center.map(|center| center.len())
+    }
+}
@@ -1,12 +1,14 @@
-use super::Constraint;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
+use std::borrow::Cow;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 /// An infinity ball defined as $B_\infty^r = \\{x\in\mathbb{R}^n {}:{} \Vert{}x{}\Vert_{\infty} \leq r\\}$,
 /// where $\Vert{}\cdot{}\Vert_{\infty}$ is the infinity norm. The infinity ball centered at a point
 /// $x_c$ is defined as $B_\infty^{x_c,r} = \\{x\in\mathbb{R}^n {}:{} \Vert{}x-x_c{}\Vert_{\infty} \leq r\\}$.
 ///
 pub struct BallInf<'a> {
-    center: Option<&'a [f64]>,
+    center: Option<Cow<'a, [f64]>>,
     radius: f64,
 }
 
@@ -16,7 +18,89 @@ impl<'a> BallInf<'a> {
     ///   
     pub fn new(center: Option<&'a [f64]>, radius: f64) -> Self {
         assert!(radius > 0.0);
-        BallInf { center, radius }
+        BallInf {
+            center: center.map(Cow::Borrowed),
+            radius,
+        }
+    }
+
+    /// Construct a new ball with given center and radius, or return an error
+    /// if the radius is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `radius` is not positive
+    ///
+    pub fn try_new(center: Option<&'a [f64]>, radius: f64) -> Result<Self, ConstructionError> {
+        if radius > 0.0 {
+            Ok(BallInf::new(center, radius))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            })
+        }
+    }
+
+    /// Construct a new infinity-norm ball which owns its center (see `new`)
+    pub fn new_owned(center: Option<Vec<f64>>, radius: f64) -> Self {
+        assert!(radius > 0.0);
+        BallInf {
+            center: center.map(Cow::Owned),
+            radius,
+        }
+    }
+
+    /// Update the center of the ball
+    ///
+    /// The given center is copied into the data of the ball, so, if the
+    /// ball owns its center (see `new_owned`), no memory is allocated. A borrowed
+    /// center (or no center) is replaced by an owned copy upon the first update.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `center` does not have the same dimension as the
+    /// current center
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{BallInf, Constraint};
+    ///
+    /// let mut ball = BallInf::new_owned(Some(vec![0.0, 0.0]), 1.0);
+    /// let mut x = [3.0, -0.5];
+    /// ball.project(&mut x);
+    /// assert_eq!([1.0, -0.5], x);
+    ///
+    /// // move the ball (e.g., at the next sampling time)
+    /// ball.set_center(&[2.0, 0.0]);
+    /// let mut x = [3.0, -0.5];
+    /// ball.project(&mut x);
+    /// assert_eq!([3.0, -0.5], x);
+    /// ```
+    ///
+    pub fn set_center(&mut self, center: &[f64]) {
+        match &mut self.center {
+            Some(current_center) => {
+                assert!(
+                    current_center.len() == center.len(),
+                    "center has wrong dimension"
+                );
+                current_center.to_mut().copy_from_slice(center);
+            }
+            None => self.center = Some(Cow::Owned(center.to_vec())),
+        }
+    }
+
+    /// Update the radius of the ball
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `radius` is not positive
+    ///
+    pub fn set_radius(&mut self, radius: f64) {
+        assert!(radius > 0.0);
+        self.radius = radius;
     }
 }
 
@@ -58,4 +142,69 @@ impl<'a> Constraint for BallInf<'a> {
     fn is_convex(&self) -> bool {
         true
     }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let excess = |d: f64| (d.abs() - self.radius).max(0.0).powi(2);
+        match &self.center {
+            Some(center) => x
+                .iter()
+                .zip(center.iter())
+                .fold(0.0, |sum, (xi, ci)| sum + excess(xi - ci)),
+            None => x.iter().fold(0.0, |sum, &xi| sum + excess(xi)),
+        }
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.center.as_ref().map(|center| center.len())
+    }
+}
+
+impl<'a> BallInf<'a> {
+    /// The $i$-th element of the center (zero if there is no center)
+    fn center_element(&self, i: usize) -> f64 {
+        self.center.as_ref().map_or(0.0, |center| center[i])
+    }
+}
+
+impl<'a> ActiveSet for BallInf<'a> {
+    /// The bound $x_i \geq x_{c, i} - r$ is active if $x_i \leq x_{c, i} - r + \epsilon$
+    /// and $x_i \leq x_{c, i} + r$ is active if $x_i \geq x_{c, i} + r - \epsilon$
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let mut active = Vec::new();
+        x.iter().enumerate().for_each(|(i, &xi)| {
+            let ci = self.center_element(i);
+            if xi <= ci - self.radius + tol {
+                active.push(ActiveConstraint::LowerBound(i));
+            }
+            if xi >= ci + self.radius - tol {
+                active.push(ActiveConstraint::UpperBound(i));
+            }
+        });
+        active
+    }
+
+    /// The support function is $\sigma(y) = \langle y, x_c\rangle + r\Vert{}y{}\Vert_1$
+    fn support_function(&self, y: &[f64]) -> f64 {
+        y.iter().enumerate().fold(0.0, |sum, (i, &yi)| {
+            sum + yi * self.center_element(i) + self.radius * yi.abs()
+        })
+    }
+
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        y.iter_mut()
+            .zip(x.iter())
+            .enumerate()
+            .for_each(|(i, (yi, &xi))| {
+                let ci = self.center_element(i);
+                let lower_active = xi <= ci - self.radius + tol;
+                let upper_active = xi >= ci + self.radius - tol;
+                if !lower_active && !upper_active {
+                    *yi = 0.0;
+                } else if !upper_active {
+                    *yi = yi.min(0.0);
+                } else if !lower_active {
+                    *yi = yi.max(0.0);
+                }
+            });
+    }
 }
@@ -0,0 +1,157 @@
+use super::monotone_cone::pool_adjacent_violators;
+use super::Constraint;
+use crate::ConstructionError;
+
+#[derive(Clone, Copy)]
+///
+/// A set of bounded vectors with nondecreasing elements
+///
+/// A set of the form
+///
+/// $$
+/// X = \\{x \in \mathbb{R}^n {}:{} x_1 \leq x_2 \leq \ldots \leq x_n,
+/// x_{\min} \leq x \leq x_{\max}\\},
+/// $$
+///
+/// that is, the intersection of the monotone cone with a rectangle, where either
+/// of $x_{\min}$ and $x_{\max}$ can be equal to infinity.
+///
+/// Projections are computed exactly with a variant of the pool-adjacent-violators
+/// algorithm, in which the value of each block of elements is clipped to the
+/// bounds of its elements.
+///
+pub struct BoundedMonotone<'a> {
+    xmin: Option<&'a [f64]>,
+    xmax: Option<&'a [f64]>,
+}
+
+impl<'a> BoundedMonotone<'a> {
+    /// Construct a new set of bounded vectors with nondecreasing elements
+    ///
+    /// # Arguments
+    ///
+    /// - `xmin`: minimum value of `x`
+    /// - `xmax`: maximum value of `x`
+    ///
+    /// # Panics
+    ///
+    /// The method panics if:
+    ///
+    /// - Both `xmin` and `xmax` are `None` (use `MonotoneCone` instead)
+    /// - Both `xmin` and `xmax` have been provided, but they have incompatible
+    ///   dimensions
+    /// - The set is empty, that is, $\max_{j\leq i} x_{\min, j} > x_{\max, i}$
+    ///   for some $i$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{BoundedMonotone, Constraint};
+    ///
+    /// // 0 <= x[0] <= x[1] <= x[2] <= 1
+    /// let xmin = [0.0; 3];
+    /// let xmax = [1.0; 3];
+    /// let set = BoundedMonotone::new(Some(&xmin), Some(&xmax));
+    /// let mut x = [0.5, -1.0, 2.0];
+    /// set.project(&mut x);
+    /// assert_eq!([0.0, 0.0, 1.0], x);
+    /// ```
+    ///
+    pub fn new(xmin: Option<&'a [f64]>, xmax: Option<&'a [f64]>) -> Self {
+        assert!(xmin.is_some() || xmax.is_some()); // xmin or xmax must be Some
+        if let (Some(xmin), Some(xmax)) = (xmin, xmax) {
+            assert!(
+                xmin.len() == xmax.len(),
+                "incompatible dimensions of xmin and xmax"
+            );
+            let mut running_max = f64::NEG_INFINITY;
+            xmin.iter().zip(xmax.iter()).for_each(|(&lo, &hi)| {
+                running_max = running_max.max(lo);
+                assert!(running_max <= hi, "the set is empty");
+            });
+        }
+        BoundedMonotone { xmin, xmax }
+    }
+
+    /// Construct a new set of bounded vectors with nondecreasing elements, or
+    /// return an error if the bounds are invalid (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::MissingBounds` if both `xmin` and `xmax` are `None`
+    /// - `ConstructionError::DimensionMismatch` if `xmin` and `xmax` have
+    ///   incompatible dimensions
+    /// - `ConstructionError::EmptySet` if the set is empty
+    ///
+    pub fn try_new(
+        xmin: Option<&'a [f64]>,
+        xmax: Option<&'a [f64]>,
+    ) -> Result<Self, ConstructionError> {
+        match (xmin, xmax) {
+            (None, None) => return Err(ConstructionError::MissingBounds),
+            (Some(xmin), Some(xmax)) => {
+                if xmin.len() != xmax.len() {
+                    return Err(ConstructionError::DimensionMismatch {
+                        expected: xmin.len(),
+                        found: xmax.len(),
+                    });
+                }
+                let mut running_max = f64::NEG_INFINITY;
+                for (&lo, &hi) in xmin.iter().zip(xmax.iter()) {
+                    running_max = running_max.max(lo);
+                    if running_max > hi {
+                        return Err(ConstructionError::EmptySet);
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(BoundedMonotone::new(xmin, xmax))
+    }
+
+    /// Dimension of the set
+    fn vector_dimension(&self) -> usize {
+        self.xmin.or(self.xmax).unwrap().len()
+    }
+}
+
+impl<'a> Constraint for BoundedMonotone<'a> {
+    /// Project on the set
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected, (out) projection on the set
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to the dimension
+    /// of the set.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.vector_dimension(), "x has wrong dimension");
+        pool_adjacent_violators(
+            x,
+            |i| self.xmin.map_or(f64::NEG_INFINITY, |xmin| xmin[i]),
+            |i| self.xmax.map_or(f64::INFINITY, |xmax| xmax[i]),
+        );
+    }
+
+    /// The set is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let is_feasible = x.windows(2).all(|w| w[0] <= w[1])
+            && x.iter().enumerate().all(|(i, &xi)| {
+                let lower = self.xmin.map_or(f64::NEG_INFINITY, |xmin| xmin[i]);
+                let upper = self.xmax.map_or(f64::INFINITY, |xmax| xmax[i]);
+                lower <= xi && xi <= upper
+            });
+        is_feasible || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.vector_dimension())
+    }
+}
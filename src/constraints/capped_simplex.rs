@@ -0,0 +1,214 @@
+use super::Constraint;
+use crate::ConstructionError;
+use std::borrow::Cow;
+
+#[derive(Clone)]
+/// A capped simplex, that is, a set of the form
+///
+/// $$
+/// \\{x \in \mathbb{R}^n {}:{} \sum_i x_i = \alpha, 0 \leq x \leq u\\},
+/// $$
+///
+/// where $\alpha > 0$ and $u \geq 0$ is a vector of upper bounds (some of which
+/// may be infinite) with $\sum_i u_i \geq \alpha$, so that the set is nonempty.
+///
+/// The projection is $x_i \leftarrow \min\\{\max\\{x_i - \tau, 0\\}, u_i\\}$,
+/// where $\tau$ is the root of the decreasing, piecewise linear function
+/// $h(\tau) = \sum_i \min\\{\max\\{x_i - \tau, 0\\}, u_i\\} - \alpha$. The root is
+/// computed without allocating memory: each pass over the data evaluates $h$
+/// and its slope at the current estimate and finds the nearest breakpoints,
+/// $x_i$ and $x_i - u_i$; the root is returned as soon as it lies on the
+/// current linear piece, otherwise the bracket of the root is shrunk and the
+/// next estimate is the secant point of the bracket. This typically takes very
+/// few passes.
+pub struct CappedSimplex<'a> {
+    upper_bounds: Cow<'a, [f64]>,
+    alpha: f64,
+}
+
+impl<'a> CappedSimplex<'a> {
+    /// Construct a new capped simplex
+    ///
+    /// # Arguments
+    ///
+    /// - `upper_bounds`: the upper bounds, $u$
+    /// - `alpha`: the sum of the elements, $\alpha$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `alpha` is not positive, if any of the upper
+    /// bounds is negative, or if the set is empty, that is, $\sum_i u_i < \alpha$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{CappedSimplex, Constraint};
+    ///
+    /// let upper_bounds = [0.5, 0.5, 0.5];
+    /// let capped_simplex = CappedSimplex::new(&upper_bounds, 1.0);
+    /// let mut x = [2.0, 1.0, 0.0];
+    /// capped_simplex.project(&mut x);
+    /// assert_eq!([0.5, 0.5, 0.0], x);
+    /// ```
+    ///
+    pub fn new(upper_bounds: &'a [f64], alpha: f64) -> Self {
+        CappedSimplex::from_cow(Cow::Borrowed(upper_bounds), alpha)
+    }
+
+    /// Construct a new capped simplex, or return an error if the data is
+    /// invalid (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::InvalidParameter` if `alpha` is not positive, or if
+    ///   any of the upper bounds is negative
+    /// - `ConstructionError::EmptySet` if $\sum_i u_i < \alpha$
+    ///
+    pub fn try_new(upper_bounds: &'a [f64], alpha: f64) -> Result<Self, ConstructionError> {
+        if alpha.is_nan() || alpha <= 0.0 {
+            return Err(ConstructionError::InvalidParameter {
+                name: "alpha",
+                value: alpha,
+            });
+        }
+        if let Some(&ui) = upper_bounds.iter().find(|&&ui| ui.is_nan() || ui < 0.0) {
+            return Err(ConstructionError::InvalidParameter {
+                name: "upper_bounds",
+                value: ui,
+            });
+        }
+        if upper_bounds.iter().sum::<f64>() < alpha {
+            return Err(ConstructionError::EmptySet);
+        }
+        Ok(CappedSimplex::new(upper_bounds, alpha))
+    }
+
+    /// Construct a new capped simplex which owns its upper bounds (see `new`)
+    pub fn new_owned(upper_bounds: Vec<f64>, alpha: f64) -> Self {
+        CappedSimplex::from_cow(Cow::Owned(upper_bounds), alpha)
+    }
+
+    fn from_cow(upper_bounds: Cow<'a, [f64]>, alpha: f64) -> Self {
+        assert!(alpha > 0.0, "alpha must be positive");
+        assert!(
+            upper_bounds.iter().all(|&ui| ui >= 0.0),
+            "the upper bounds must be nonnegative"
+        );
+        assert!(
+            upper_bounds.iter().sum::<f64>() >= alpha,
+            "the set is empty"
+        );
+        CappedSimplex {
+            upper_bounds,
+            alpha,
+        }
+    }
+
+    /// Computes the root, $\tau$, of $h$
+    fn threshold(&self, x: &[f64]) -> f64 {
+        let u = &self.upper_bounds;
+        // bracket, lo < hi, with h(lo) >= 0 > h(hi)
+        let mut lo = x.iter().zip(u.iter()).fold(f64::INFINITY, |lo, (xi, ui)| {
+            lo.min(xi - ui.min(self.alpha))
+        });
+        let mut hi = x.iter().fold(f64::NEG_INFINITY, |hi, &xi| hi.max(xi));
+        let mut h_lo = f64::INFINITY;
+        let mut h_hi = -self.alpha;
+        let mut tau = lo;
+        for _ in 0..=2 * x.len() {
+            // h and slopes at tau, and the nearest breakpoints
+            let mut h = -self.alpha;
+            let mut count_left = 0.0;
+            let mut count_right = 0.0;
+            let mut breakpoint_below = f64::NEG_INFINITY;
+            let mut breakpoint_above = f64::INFINITY;
+            for (&xi, &ui) in x.iter().zip(u.iter()) {
+                h += (xi - tau).max(0.0).min(ui);
+                let lower_breakpoint = xi - ui;
+                if lower_breakpoint < tau && tau <= xi {
+                    count_left += 1.0;
+                }
+                if lower_breakpoint <= tau && tau < xi {
+                    count_right += 1.0;
+                }
+                for breakpoint in [lower_breakpoint, xi] {
+                    if breakpoint < tau {
+                        breakpoint_below = breakpoint_below.max(breakpoint);
+                    } else if breakpoint > tau {
+                        breakpoint_above = breakpoint_above.min(breakpoint);
+                    }
+                }
+            }
+            if h > 0.0 {
+                // the root is on the right
+                if count_right > 0.0 && tau + h / count_right <= breakpoint_above {
+                    return tau + h / count_right;
+                }
+                lo = breakpoint_above;
+                h_lo = h - count_right * (breakpoint_above - tau);
+            } else if h < 0.0 {
+                // the root is on the left
+                if count_left > 0.0 && tau + h / count_left >= breakpoint_below {
+                    return tau + h / count_left;
+                }
+                hi = breakpoint_below;
+                h_hi = h + count_left * (tau - breakpoint_below);
+            } else {
+                return tau;
+            }
+            tau = if h_lo.is_finite() {
+                lo + h_lo * (hi - lo) / (h_lo - h_hi)
+            } else {
+                lo
+            };
+        }
+        tau
+    }
+}
+
+impl<'a> Constraint for CappedSimplex<'a> {
+    /// Project on the capped simplex
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the number
+    /// of upper bounds
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.upper_bounds.len(), "x has wrong dimension");
+        let tau = self.threshold(x);
+        x.iter_mut()
+            .zip(self.upper_bounds.iter())
+            .for_each(|(xi, &ui)| *xi = (*xi - tau).max(0.0).min(ui));
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    /// Squared distance from the capped simplex, which is computed using the
+    /// threshold $\tau$ without allocating memory
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        assert!(x.len() == self.upper_bounds.len(), "x has wrong dimension");
+        let tau = self.threshold(x);
+        x.iter()
+            .zip(self.upper_bounds.iter())
+            .fold(0.0, |sum, (&xi, &ui)| {
+                sum + (xi - (xi - tau).max(0.0).min(ui)).powi(2)
+            })
+    }
+
+    /// Checks whether $-\epsilon \leq x_i \leq u_i + \epsilon$, for all $i$,
+    /// and $|\sum_i x_i - \alpha| \leq \epsilon$
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        assert!(x.len() == self.upper_bounds.len(), "x has wrong dimension");
+        x.iter()
+            .zip(self.upper_bounds.iter())
+            .all(|(&xi, &ui)| xi >= -tol && xi <= ui + tol)
+            && (x.iter().sum::<f64>() - self.alpha).abs() <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.upper_bounds.len())
+    }
+}
@@ -0,0 +1,262 @@
+use super::Constraint;
+use crate::{matrix_operations, ConstructionError};
+
+/// Additional (convex) constraint on the nonzero elements of a vector with
+/// bounded cardinality
+#[derive(Clone, Copy)]
+enum CardinalityBound<'a> {
+    /// No additional constraint
+    Unbounded,
+    /// Element-wise bounds, $x_{\min} \leq x \leq x_{\max}$
+    Box {
+        xmin: Option<&'a [f64]>,
+        xmax: Option<&'a [f64]>,
+    },
+    /// Euclidean ball centered at the origin, $\Vert x \Vert \leq r$
+    Ball2(f64),
+}
+
+#[derive(Clone, Copy)]
+///
+/// A set of vectors with at most $k$ nonzero elements
+///
+/// A set of the form
+///
+/// $$
+/// S_k = \\{x \in \mathbb{R}^n {}:{} \Vert x \Vert_0 \leq k\\},
+/// $$
+///
+/// where $\Vert x \Vert_0$ is the number of nonzero elements of $x$, possibly
+/// intersected with a box, $\\{x {}:{} x_{\min} \leq x \leq x_{\max}\\}$, which
+/// contains the origin, or with a Euclidean ball centered at the origin,
+/// $\\{x {}:{} \Vert x \Vert \leq r\\}$.
+///
+/// This is a nonconvex set. Projections on it are computed exactly by choosing
+/// the $k$ elements of $x$ which are kept (the others are set to zero) and then
+/// projecting on the box or the ball.
+///
+pub struct Cardinality<'a> {
+    k: usize,
+    bound: CardinalityBound<'a>,
+}
+
+impl<'a> Cardinality<'a> {
+    /// Construct a new set of vectors with at most `k` nonzero elements
+    ///
+    /// # Arguments
+    ///
+    /// - `k`: maximum number of nonzero elements
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Cardinality, Constraint};
+    ///
+    /// let cardinality = Cardinality::new(2);
+    /// let mut x = [1.0, -3.0, 0.5, 2.0];
+    /// cardinality.project(&mut x);
+    /// assert_eq!([0.0, -3.0, 0.0, 2.0], x);
+    /// ```
+    ///
+    pub fn new(k: usize) -> Self {
+        Cardinality {
+            k,
+            bound: CardinalityBound::Unbounded,
+        }
+    }
+
+    /// Intersect the set with the box $\\{x {}:{} x_{\min} \leq x \leq x_{\max}\\}$
+    ///
+    /// # Arguments
+    ///
+    /// - `xmin`: minimum value of `x` (if `None`, it is taken to be $-\infty$)
+    /// - `xmax`: maximum value of `x` (if `None`, it is taken to be $+\infty$)
+    ///
+    /// # Panics
+    ///
+    /// The method panics if:
+    ///
+    /// - Both `xmin` and `xmax` are `None`
+    /// - Both `xmin` and `xmax` have been provided, but they have incompatible
+    ///   dimensions
+    /// - The box does not contain the origin, that is, some element of `xmin`
+    ///   is positive, or some element of `xmax` is negative
+    ///
+    pub fn with_bounds(mut self, xmin: Option<&'a [f64]>, xmax: Option<&'a [f64]>) -> Self {
+        assert!(
+            xmin.is_some() || xmax.is_some(),
+            "xmin or xmax must be provided"
+        );
+        assert!(
+            xmin.is_none() || xmax.is_none() || xmin.unwrap().len() == xmax.unwrap().len(),
+            "incompatible dimensions of xmin and xmax"
+        );
+        assert!(
+            xmin.unwrap_or(&[]).iter().all(|&xi| xi <= 0.0)
+                && xmax.unwrap_or(&[]).iter().all(|&xi| xi >= 0.0),
+            "the box must contain the origin"
+        );
+        self.bound = CardinalityBound::Box { xmin, xmax };
+        self
+    }
+
+    /// Intersect the set with a box, or return an error if the bounds are
+    /// invalid (see `with_bounds`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::MissingBounds` if both `xmin` and `xmax` are `None`
+    /// - `ConstructionError::DimensionMismatch` if `xmin` and `xmax` have
+    ///   incompatible dimensions
+    /// - `ConstructionError::InvalidParameter` if some element of `xmin` is
+    ///   positive, or some element of `xmax` is negative
+    ///
+    pub fn try_with_bounds(
+        self,
+        xmin: Option<&'a [f64]>,
+        xmax: Option<&'a [f64]>,
+    ) -> Result<Self, ConstructionError> {
+        match (xmin, xmax) {
+            (None, None) => return Err(ConstructionError::MissingBounds),
+            (Some(xmin_), Some(xmax_)) if xmin_.len() != xmax_.len() => {
+                return Err(ConstructionError::DimensionMismatch {
+                    expected: xmin_.len(),
+                    found: xmax_.len(),
+                });
+            }
+            _ => {}
+        }
+        if let Some(&xi) = xmin
+            .unwrap_or(&[])
+            .iter()
+            .find(|&&xi| xi.is_nan() || xi > 0.0)
+        {
+            return Err(ConstructionError::InvalidParameter {
+                name: "xmin",
+                value: xi,
+            });
+        }
+        if let Some(&xi) = xmax
+            .unwrap_or(&[])
+            .iter()
+            .find(|&&xi| xi.is_nan() || xi < 0.0)
+        {
+            return Err(ConstructionError::InvalidParameter {
+                name: "xmax",
+                value: xi,
+            });
+        }
+        Ok(self.with_bounds(xmin, xmax))
+    }
+
+    /// Intersect the set with the Euclidean ball $\\{x {}:{} \Vert x \Vert \leq r\\}$
+    ///
+    /// # Arguments
+    ///
+    /// - `radius`: radius of the ball, $r$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `radius` is not positive
+    ///
+    pub fn with_ball2(mut self, radius: f64) -> Self {
+        assert!(radius > 0.0, "radius must be positive");
+        self.bound = CardinalityBound::Ball2(radius);
+        self
+    }
+
+    /// Intersect the set with a Euclidean ball, or return an error if the
+    /// radius is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `radius` is not positive
+    ///
+    pub fn try_with_ball2(self, radius: f64) -> Result<Self, ConstructionError> {
+        if radius > 0.0 {
+            Ok(self.with_ball2(radius))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            })
+        }
+    }
+
+    /// Clips `xi` (the i-th element of a vector) to the box, if any
+    fn clip(&self, i: usize, xi: f64) -> f64 {
+        match self.bound {
+            CardinalityBound::Box { xmin, xmax } => {
+                let lower = xmin.map_or(f64::NEG_INFINITY, |xmin| xmin[i]);
+                let upper = xmax.map_or(f64::INFINITY, |xmax| xmax[i]);
+                xi.max(lower).min(upper)
+            }
+            _ => xi,
+        }
+    }
+}
+
+impl<'a> Constraint for Cardinality<'a> {
+    /// Project on the set of vectors with at most $k$ nonzero elements
+    ///
+    /// The elements that are kept are the ones for which the decrease in the
+    /// squared distance, compared to setting them to zero, is largest; that is,
+    /// the $k$ elements of largest magnitude, or, if there is a box constraint,
+    /// the $k$ largest values of $x_i^2 - (x_i - \Pi_{[x_{\min, i}, x_{\max, i}]}(x_i))^2$.
+    /// These elements are then projected on the box or the ball.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected, (out) projection on the set
+    ///
+    /// # Panics
+    ///
+    /// The method panics if there is a box constraint and the dimension of `x`
+    /// is not compatible with it.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        if let CardinalityBound::Box { xmin, xmax } = self.bound {
+            let n = xmin.or(xmax).unwrap().len();
+            assert!(x.len() == n, "x has wrong dimension");
+        }
+        if self.k < x.len() {
+            // benefit of keeping each element (instead of setting it to zero)
+            let benefit: Vec<f64> = x
+                .iter()
+                .enumerate()
+                .map(|(i, &xi)| {
+                    let xi_clipped = self.clip(i, xi);
+                    xi * xi - (xi - xi_clipped) * (xi - xi_clipped)
+                })
+                .collect();
+            let mut idx: Vec<usize> = (0..x.len()).collect();
+            if self.k > 0 {
+                idx.select_nth_unstable_by(self.k - 1, |&i, &j| {
+                    benefit[j].partial_cmp(&benefit[i]).unwrap()
+                });
+            }
+            idx.iter().skip(self.k).for_each(|&i| x[i] = 0.0);
+        }
+        x.iter_mut()
+            .enumerate()
+            .for_each(|(i, xi)| *xi = self.clip(i, *xi));
+        if let CardinalityBound::Ball2(radius) = self.bound {
+            let norm_x = matrix_operations::norm2(x);
+            if norm_x > radius {
+                x.iter_mut().for_each(|xi| *xi *= radius / norm_x);
+            }
+        }
+    }
+
+    /// Sets of vectors with bounded cardinality are nonconvex
+    fn is_convex(&self) -> bool {
+        false
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        match self.bound {
+            CardinalityBound::Box { xmin, xmax } => xmin.or(xmax).map(|bound| bound.len()),
+            _ => None,
+        }
+    }
+}
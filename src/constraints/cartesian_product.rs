@@ -1,4 +1,43 @@
-use super::Constraint;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
+
+/// A set of a Cartesian product, which may or may not implement `ActiveSet`
+enum Segment<'a> {
+    Plain(Box<dyn Constraint + 'a>),
+    WithActiveSet(Box<dyn ActiveSet + 'a>),
+}
+
+impl<'a> Segment<'a> {
+    fn project(&self, x: &mut [f64]) {
+        match self {
+            Segment::Plain(c) => c.project(x),
+            Segment::WithActiveSet(c) => c.project(x),
+        }
+    }
+
+    fn is_convex(&self) -> bool {
+        match self {
+            Segment::Plain(c) => c.is_convex(),
+            Segment::WithActiveSet(c) => c.is_convex(),
+        }
+    }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        match self {
+            Segment::Plain(c) => c.distance_squared(x),
+            Segment::WithActiveSet(c) => c.distance_squared(x),
+        }
+    }
+
+    fn active_set(&self) -> &(dyn ActiveSet + 'a) {
+        match self {
+            Segment::Plain(_) => panic!(
+                "the set was not added with add_active_set_constraint, so it does not implement ActiveSet"
+            ),
+            Segment::WithActiveSet(c) => c.as_ref(),
+        }
+    }
+}
 
 /// Cartesian product of constraints
 ///
@@ -21,10 +60,14 @@ use super::Constraint;
 /// The constraint $x \in C$ is interpreted as $x_i \in C_i$
 /// for all $i=0,\ldots, n-1$.
 ///
+/// The Cartesian product implements `ActiveSet` provided that all sets
+/// are added with `add_active_set_constraint`; the methods of `ActiveSet`
+/// panic if any of the sets was added with `add_constraint`.
+///
 #[derive(Default)]
 pub struct CartesianProduct<'a> {
     idx: Vec<usize>,
-    constraints: Vec<Box<dyn Constraint + 'a>>,
+    constraints: Vec<Segment<'a>>,
 }
 
 impl<'a> CartesianProduct<'a> {
@@ -122,9 +165,132 @@ impl<'a> CartesianProduct<'a> {
             "provided index is smaller than or equal to previous index, or zero"
         );
         self.idx.push(ni);
-        self.constraints.push(Box::new(constraint));
+        self.constraints.push(Segment::Plain(Box::new(constraint)));
+        self
+    }
+
+    /// Add a constraint $x_{[j..i]}\in C$, or return an error if the given
+    /// index is invalid or incompatible with the dimension of $C$ (see
+    /// `add_constraint`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::IndexNotIncreasing` if `ni` is less than or equal
+    ///   to the previous dimension of the Cartesian product
+    /// - `ConstructionError::DimensionMismatch` if the dimension of `constraint`
+    ///   (see `Constraint::dimension`) is known and it is not equal to the length
+    ///   of the segment, `ni` minus the previous dimension
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// let cart_prod = CartesianProduct::new()
+    ///     .try_add_constraint(2, Ball2::new(None, 1.0))
+    ///     .and_then(|cart_prod| cart_prod.try_add_constraint(5, Zero::new()));
+    /// assert!(cart_prod.is_ok());
+    /// ```
+    ///
+    pub fn try_add_constraint(
+        self,
+        ni: usize,
+        constraint: impl Constraint + 'a,
+    ) -> Result<Self, ConstructionError> {
+        self.check_segment(ni, constraint.dimension())?;
+        Ok(self.add_constraint(ni, constraint))
+    }
+
+    /// Add a constraint $x_{[j..i]}\in C$, where $C$ implements `ActiveSet`
+    /// (see `add_constraint`)
+    ///
+    /// The Cartesian product implements `ActiveSet` provided that all sets
+    /// are added using this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// let xmin = [-1.0, -1.0];
+    /// let xmax = [1.0, 1.0];
+    /// let cart_prod = CartesianProduct::new()
+    ///     .add_active_set_constraint(2, Rectangle::new(Some(&xmin), Some(&xmax)))
+    ///     .add_active_set_constraint(5, Ball2::new(None, 1.0));
+    /// let mut x = [3.0, 0.0, 2.0, 0.0, 0.0];
+    /// cart_prod.project(&mut x);
+    /// assert_eq!(
+    ///     vec![
+    ///         ActiveConstraint::UpperBound(0),
+    ///         ActiveConstraint::Segment(1, Box::new(ActiveConstraint::Boundary)),
+    ///     ],
+    ///     cart_prod.active_constraints(&x, 1e-8)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `ni` is less than or equal to the previous
+    /// dimension of the Cartesian product
+    ///
+    pub fn add_active_set_constraint(mut self, ni: usize, constraint: impl ActiveSet + 'a) -> Self {
+        assert!(
+            self.dimension() < ni,
+            "provided index is smaller than or equal to previous index, or zero"
+        );
+        self.idx.push(ni);
+        self.constraints
+            .push(Segment::WithActiveSet(Box::new(constraint)));
         self
     }
+
+    /// Add a constraint $x_{[j..i]}\in C$, where $C$ implements `ActiveSet`, or
+    /// return an error if the given index is invalid or incompatible with the
+    /// dimension of $C$ (see `add_active_set_constraint`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::IndexNotIncreasing` if `ni` is less than or equal
+    ///   to the previous dimension of the Cartesian product
+    /// - `ConstructionError::DimensionMismatch` if the dimension of `constraint`
+    ///   (see `Constraint::dimension`) is known and it is not equal to the length
+    ///   of the segment, `ni` minus the previous dimension
+    ///
+    pub fn try_add_active_set_constraint(
+        self,
+        ni: usize,
+        constraint: impl ActiveSet + 'a,
+    ) -> Result<Self, ConstructionError> {
+        self.check_segment(ni, constraint.dimension())?;
+        Ok(self.add_active_set_constraint(ni, constraint))
+    }
+
+    /// Checks that `ni` is larger than the current dimension and that the
+    /// dimension of the constraint (if known) is equal to the length of the
+    /// segment
+    fn check_segment(&self, ni: usize, dimension: Option<usize>) -> Result<(), ConstructionError> {
+        let previous = CartesianProduct::dimension(self);
+        if ni <= previous {
+            return Err(ConstructionError::IndexNotIncreasing {
+                index: ni,
+                previous,
+            });
+        }
+        match dimension {
+            Some(found) if found != ni - previous => Err(ConstructionError::DimensionMismatch {
+                expected: ni - previous,
+                found,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Start and end indices of the segments
+    fn segments(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        std::iter::once(0)
+            .chain(self.idx.iter().cloned())
+            .zip(self.idx.iter().cloned())
+    }
 }
 
 impl<'a> Constraint for CartesianProduct<'a> {
@@ -154,4 +320,70 @@ impl<'a> Constraint for CartesianProduct<'a> {
             flag
         })
     }
+
+    /// The squared distance from a Cartesian product is the sum of the squared
+    /// distances of the segments of `x` from the corresponding sets
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        assert!(x.len() == self.dimension(), "x has wrong size");
+        let mut j = 0;
+        self.idx
+            .iter()
+            .zip(self.constraints.iter())
+            .fold(0.0, |sum, (&i, c)| {
+                let distance_squared = c.distance_squared(&x[j..i]);
+                j = i;
+                sum + distance_squared
+            })
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(CartesianProduct::dimension(self))
+    }
+}
+
+impl<'a> ActiveSet for CartesianProduct<'a> {
+    /// The active constraints of all segments; bounds are indexed by the
+    /// elements of `x`, while the other constraints of the $k$-th segment are
+    /// reported as `ActiveConstraint::Segment(k, constraint)`
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        assert!(x.len() == self.dimension(), "x has wrong size");
+        let mut active = Vec::new();
+        for (k, ((j, i), c)) in self.segments().zip(self.constraints.iter()).enumerate() {
+            active.extend(
+                c.active_set()
+                    .active_constraints(&x[j..i], tol)
+                    .into_iter()
+                    .map(|constraint| match constraint {
+                        ActiveConstraint::LowerBound(l) => ActiveConstraint::LowerBound(l + j),
+                        ActiveConstraint::UpperBound(l) => ActiveConstraint::UpperBound(l + j),
+                        other => ActiveConstraint::Segment(k, Box::new(other)),
+                    }),
+            );
+        }
+        active
+    }
+
+    /// The support function of a Cartesian product is the sum of the support
+    /// functions of the sets at the corresponding segments of `y`
+    fn support_function(&self, y: &[f64]) -> f64 {
+        assert!(y.len() == self.dimension(), "y has wrong size");
+        self.segments()
+            .zip(self.constraints.iter())
+            .fold(0.0, |sum, ((j, i), c)| {
+                sum + c.active_set().support_function(&y[j..i])
+            })
+    }
+
+    /// The normal cone of a Cartesian product is the Cartesian product of the
+    /// normal cones of the sets
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        assert!(x.len() == self.dimension(), "x has wrong size");
+        assert!(y.len() == self.dimension(), "y has wrong size");
+        self.segments()
+            .zip(self.constraints.iter())
+            .for_each(|((j, i), c)| {
+                c.active_set()
+                    .project_on_normal_cone(&x[j..i], &mut y[j..i], tol)
+            });
+    }
 }
@@ -0,0 +1,496 @@
+use super::*;
+use crate::ConstructionError;
+use serde::{Deserialize, Serialize};
+
+/// Serializable description of a constraint
+///
+/// This is a declarative description of any of the sets of this module, which
+/// can be read from (or written to) any format supported by `serde`, e.g., JSON
+/// or TOML, so that the data of the constraints (e.g., bounds) can be changed
+/// without recompiling. The kind of set is given by the field `type`, which is
+/// the name of the corresponding structure. Optional fields may be omitted.
+///
+/// A constraint is constructed from its description using [`build`].
+///
+/// This is only available if OpEn is compiled with the feature `serde`.
+///
+/// # Example
+///
+/// ```
+/// use optimization_engine::constraints::*;
+///
+/// let json = r#"{
+///     "type": "CartesianProduct",
+///     "segments": [2, 3],
+///     "constraints": [
+///         { "type": "Ball2", "radius": 1.0 },
+///         { "type": "Rectangle", "xmin": [-1.0], "xmax": [1.0] }
+///     ]
+/// }"#;
+/// let spec: ConstraintSpec = serde_json::from_str(json).unwrap();
+/// let constraint = spec.build().unwrap();
+/// let mut x = [3.0, 4.0, 5.0];
+/// constraint.project(&mut x);
+/// assert_eq!([0.6, 0.8, 1.0], x);
+/// ```
+///
+/// [`build`]: enum.ConstraintSpec.html#method.build
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ConstraintSpec {
+    /// An `AffineSpace`, $\\{x {}:{} Ax = b\\}$
+    AffineSpace {
+        /// matrix $A$, row-wise data
+        a: Vec<f64>,
+        /// vector $b$
+        b: Vec<f64>,
+    },
+    /// A `Ball1`
+    Ball1 {
+        /// center of the ball (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// radius of the ball
+        radius: f64,
+    },
+    /// A `Ball2`
+    Ball2 {
+        /// center of the ball (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// radius of the ball
+        radius: f64,
+    },
+    /// A `Ball2Exterior`
+    Ball2Exterior {
+        /// center of the ball (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// radius of the ball
+        radius: f64,
+    },
+    /// A `BallInf`
+    BallInf {
+        /// center of the ball (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// radius of the ball
+        radius: f64,
+    },
+    /// A `BoundedMonotone` set
+    BoundedMonotone {
+        /// lower bound (if omitted, it is taken to be $-\infty$)
+        #[serde(default)]
+        xmin: Option<Vec<f64>>,
+        /// upper bound (if omitted, it is taken to be $+\infty$)
+        #[serde(default)]
+        xmax: Option<Vec<f64>>,
+    },
+    /// A `CappedSimplex`
+    CappedSimplex {
+        /// upper bounds, $u$
+        upper_bounds: Vec<f64>,
+        /// sum of the elements, $\alpha$
+        alpha: f64,
+    },
+    /// A `Cardinality` constraint
+    Cardinality {
+        /// maximum number of nonzero elements
+        k: usize,
+        /// lower bound, if the set is intersected with a box
+        #[serde(default)]
+        xmin: Option<Vec<f64>>,
+        /// upper bound, if the set is intersected with a box
+        #[serde(default)]
+        xmax: Option<Vec<f64>>,
+        /// radius, if the set is intersected with a Euclidean ball
+        #[serde(default)]
+        radius: Option<f64>,
+    },
+    /// A `CartesianProduct`
+    CartesianProduct {
+        /// indices which are passed to `add_constraint`, e.g., `[2, 5]` for
+        /// two sets of dimensions 2 and 3
+        segments: Vec<usize>,
+        /// constraints, one for each segment
+        constraints: Vec<ConstraintSpec>,
+    },
+    /// A `ConvexHull`
+    ConvexHull {
+        /// points whose convex hull is taken
+        data: Vec<Vec<f64>>,
+    },
+    /// An `Ellipsoid`
+    Ellipsoid {
+        /// matrix $Q$, row-wise data
+        q: Vec<f64>,
+        /// center of the ellipsoid (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// parameter $r$
+        r: f64,
+    },
+    /// An `EpigraphNormInf`
+    EpigraphNormInf,
+    /// An `EpigraphNormL1`
+    EpigraphNormL1,
+    /// An `EpigraphSquaredNorm`
+    EpigraphSquaredNorm,
+    /// An `ExponentialCone`
+    ExponentialCone,
+    /// A `FiniteSet`
+    FiniteSet {
+        /// elements of the set
+        data: Vec<Vec<f64>>,
+        /// whether the elements are stored in a kd-tree (default: `false`)
+        #[serde(default)]
+        kd_tree: bool,
+    },
+    /// A `Halfspace`
+    Halfspace {
+        /// normal vector, $c$
+        normal_vector: Vec<f64>,
+        /// offset, $b$
+        offset: f64,
+    },
+    /// A `Hyperplane`
+    Hyperplane {
+        /// normal vector, $c$
+        normal_vector: Vec<f64>,
+        /// offset, $b$
+        offset: f64,
+    },
+    /// An `Intersection`
+    Intersection {
+        /// dimension of the sets
+        dimension: usize,
+        /// sets to be intersected
+        constraints: Vec<ConstraintSpec>,
+        /// maximum number of iterations of Dykstra's algorithm
+        #[serde(default)]
+        max_iterations: Option<usize>,
+        /// tolerance of Dykstra's algorithm
+        #[serde(default)]
+        tolerance: Option<f64>,
+    },
+    /// A `MonotoneCone`
+    MonotoneCone,
+    /// No constraints
+    NoConstraints,
+    /// A `Permuted` set
+    Permuted {
+        /// the set $C$
+        set: Box<ConstraintSpec>,
+        /// the permutation, $p$, so that $(Px)_i = x_{p_i}$
+        permutation: Vec<usize>,
+    },
+    /// A `Polyhedron`
+    Polyhedron {
+        /// matrix $A$, row-wise data
+        a: Vec<f64>,
+        /// vector $b$
+        b: Vec<f64>,
+        /// matrix $C$ of the equality constraints, row-wise data
+        #[serde(default)]
+        c: Option<Vec<f64>>,
+        /// vector $d$ of the equality constraints
+        #[serde(default)]
+        d: Option<Vec<f64>>,
+    },
+    /// A `PowerCone`
+    PowerCone {
+        /// parameter $\alpha$
+        alpha: f64,
+    },
+    /// A `PsdCone`
+    PsdCone {
+        /// number of rows (and columns) of the matrix
+        n: usize,
+        /// storage format of the matrix
+        storage: SymmetricStorage,
+    },
+    /// A `Rectangle`
+    Rectangle {
+        /// lower bound (if omitted, it is taken to be $-\infty$)
+        #[serde(default)]
+        xmin: Option<Vec<f64>>,
+        /// upper bound (if omitted, it is taken to be $+\infty$)
+        #[serde(default)]
+        xmax: Option<Vec<f64>>,
+    },
+    /// A `RectangleExterior`
+    RectangleExterior {
+        /// lower bound (if omitted, it is taken to be $-\infty$)
+        #[serde(default)]
+        xmin: Option<Vec<f64>>,
+        /// upper bound (if omitted, it is taken to be $+\infty$)
+        #[serde(default)]
+        xmax: Option<Vec<f64>>,
+    },
+    /// A `RotatedSecondOrderCone`
+    RotatedSecondOrderCone,
+    /// A `Scaled` set
+    Scaled {
+        /// the set $C$
+        set: Box<ConstraintSpec>,
+        /// diagonal elements of $D$
+        diagonal: Vec<f64>,
+        /// maximum number of iterations of ADMM
+        #[serde(default)]
+        max_iterations: Option<usize>,
+        /// tolerance of ADMM
+        #[serde(default)]
+        tolerance: Option<f64>,
+    },
+    /// A `SecondOrderCone`
+    SecondOrderCone {
+        /// parameter $\alpha$
+        alpha: f64,
+        /// index of the scalar component (the last one, if omitted)
+        #[serde(default)]
+        scalar_index: Option<usize>,
+    },
+    /// A `Simplex`
+    Simplex {
+        /// size of the simplex, $\alpha$
+        alpha: f64,
+    },
+    /// A `SpecialOrthogonal` group
+    SpecialOrthogonal {
+        /// number of rows (and columns) of the matrices
+        n: usize,
+    },
+    /// A `Sphere2`
+    Sphere2 {
+        /// center of the sphere (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// radius of the sphere
+        radius: f64,
+    },
+    /// A `Stiefel` manifold
+    Stiefel {
+        /// number of rows of the matrices
+        n_rows: usize,
+        /// number of columns of the matrices
+        n_cols: usize,
+    },
+    /// A `Translated` set
+    Translated {
+        /// the set $C$
+        set: Box<ConstraintSpec>,
+        /// the translation vector, $c$
+        translation: Vec<f64>,
+    },
+    /// A `Union`
+    Union {
+        /// sets of the union
+        constraints: Vec<ConstraintSpec>,
+    },
+    /// A `WeightedBall1`
+    WeightedBall1 {
+        /// weights, $w$
+        weights: Vec<f64>,
+        /// center of the ball (the origin, if omitted)
+        #[serde(default)]
+        center: Option<Vec<f64>>,
+        /// radius of the ball
+        radius: f64,
+    },
+    /// The set $\\{0\\}$
+    Zero,
+}
+
+impl ConstraintSpec {
+    /// Construct the constraint which is described by this specification
+    ///
+    /// The constraint borrows its data (e.g., bounds) from the specification.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ConstructionError` if a constraint (or any nested constraint)
+    /// cannot be constructed (see the `try_new` constructors of the sets), e.g.,
+    /// if a radius is not positive, if there are incompatible dimensions, if the
+    /// indices of a Cartesian product are not increasing, or if a union has no
+    /// sets. The dimensions of nested constraints (see `Constraint::dimension`)
+    /// are checked against the segments of Cartesian products, the dimension of
+    /// intersections, the vectors of `Translated`, `Scaled` and `Permuted`, and
+    /// the other sets of unions.
+    ///
+    pub fn build(&self) -> Result<Box<dyn Constraint + '_>, ConstructionError> {
+        let constraint: Box<dyn Constraint + '_> = match self {
+            ConstraintSpec::AffineSpace { a, b } => {
+                Box::new(AffineSpace::try_new(a.clone(), b.clone())?)
+            }
+            ConstraintSpec::Ball1 { center, radius } => {
+                Box::new(Ball1::try_new(center.as_deref(), *radius)?)
+            }
+            ConstraintSpec::Ball2 { center, radius } => {
+                Box::new(Ball2::try_new(center.as_deref(), *radius)?)
+            }
+            ConstraintSpec::Ball2Exterior { center, radius } => {
+                Box::new(Ball2Exterior::try_new(center.as_deref(), *radius)?)
+            }
+            ConstraintSpec::BallInf { center, radius } => {
+                Box::new(BallInf::try_new(center.as_deref(), *radius)?)
+            }
+            ConstraintSpec::BoundedMonotone { xmin, xmax } => {
+                Box::new(BoundedMonotone::try_new(xmin.as_deref(), xmax.as_deref())?)
+            }
+            ConstraintSpec::CappedSimplex {
+                upper_bounds,
+                alpha,
+            } => Box::new(CappedSimplex::try_new(upper_bounds, *alpha)?),
+            ConstraintSpec::Cardinality {
+                k,
+                xmin,
+                xmax,
+                radius,
+            } => {
+                let mut cardinality = Cardinality::new(*k);
+                if xmin.is_some() || xmax.is_some() {
+                    cardinality = cardinality.try_with_bounds(xmin.as_deref(), xmax.as_deref())?;
+                }
+                if let Some(radius) = radius {
+                    cardinality = cardinality.try_with_ball2(*radius)?;
+                }
+                Box::new(cardinality)
+            }
+            ConstraintSpec::CartesianProduct {
+                segments,
+                constraints,
+            } => {
+                check_dimension(segments.len(), constraints.len())?;
+                let mut cartesian_product = CartesianProduct::new_with_capacity(segments.len());
+                for (&ni, constraint) in segments.iter().zip(constraints.iter()) {
+                    cartesian_product =
+                        cartesian_product.try_add_constraint(ni, constraint.build()?)?;
+                }
+                Box::new(cartesian_product)
+            }
+            ConstraintSpec::ConvexHull { data } => {
+                Box::new(FiniteSet::try_new_owned(data.clone())?.convex_hull())
+            }
+            ConstraintSpec::Ellipsoid { q, center, r } => {
+                Box::new(Ellipsoid::try_new(q.clone(), center.clone(), *r)?)
+            }
+            ConstraintSpec::EpigraphNormInf => Box::new(EpigraphNormInf::new()),
+            ConstraintSpec::EpigraphNormL1 => Box::new(EpigraphNormL1::new()),
+            ConstraintSpec::EpigraphSquaredNorm => Box::new(EpigraphSquaredNorm::new()),
+            ConstraintSpec::ExponentialCone => Box::new(ExponentialCone::new()),
+            ConstraintSpec::FiniteSet { data, kd_tree } => {
+                if *kd_tree {
+                    Box::new(FiniteSet::try_new_with_kd_tree(data.clone())?)
+                } else {
+                    Box::new(FiniteSet::try_new_owned(data.clone())?)
+                }
+            }
+            ConstraintSpec::Halfspace {
+                normal_vector,
+                offset,
+            } => Box::new(Halfspace::try_new(normal_vector, *offset)?),
+            ConstraintSpec::Hyperplane {
+                normal_vector,
+                offset,
+            } => Box::new(Hyperplane::try_new(normal_vector, *offset)?),
+            ConstraintSpec::Intersection {
+                dimension,
+                constraints,
+                max_iterations,
+                tolerance,
+            } => {
+                let mut intersection = Intersection::new(*dimension);
+                for constraint in constraints.iter() {
+                    intersection = intersection.try_add_constraint(constraint.build()?)?;
+                }
+                if let Some(max_iterations) = max_iterations {
+                    intersection = intersection.try_with_max_iterations(*max_iterations)?;
+                }
+                if let Some(tolerance) = tolerance {
+                    intersection = intersection.try_with_tolerance(*tolerance)?;
+                }
+                Box::new(intersection)
+            }
+            ConstraintSpec::MonotoneCone => Box::new(MonotoneCone::new()),
+            ConstraintSpec::NoConstraints => Box::new(NoConstraints::new()),
+            ConstraintSpec::Permuted { set, permutation } => {
+                Box::new(Permuted::try_new(set.build()?, permutation.clone())?)
+            }
+            ConstraintSpec::Polyhedron { a, b, c, d } => {
+                let mut polyhedron = Polyhedron::try_new(a.clone(), b.clone())?;
+                if c.is_some() || d.is_some() {
+                    let c = c.clone().unwrap_or_default();
+                    let d = d.clone().unwrap_or_default();
+                    polyhedron = polyhedron.try_with_equality_constraints(c, d)?;
+                }
+                Box::new(polyhedron)
+            }
+            ConstraintSpec::PowerCone { alpha } => Box::new(PowerCone::try_new(*alpha)?),
+            ConstraintSpec::PsdCone { n, storage } => Box::new(PsdCone::try_new(*n, *storage)?),
+            ConstraintSpec::Rectangle { xmin, xmax } => {
+                Box::new(Rectangle::try_new(xmin.as_deref(), xmax.as_deref())?)
+            }
+            ConstraintSpec::RectangleExterior { xmin, xmax } => Box::new(
+                RectangleExterior::try_new(xmin.as_deref(), xmax.as_deref())?,
+            ),
+            ConstraintSpec::RotatedSecondOrderCone => Box::new(RotatedSecondOrderCone::new()),
+            ConstraintSpec::Scaled {
+                set,
+                diagonal,
+                max_iterations,
+                tolerance,
+            } => {
+                let mut scaled = Scaled::try_new(set.build()?, diagonal.clone())?;
+                if let Some(max_iterations) = max_iterations {
+                    scaled = scaled.try_with_max_iterations(*max_iterations)?;
+                }
+                if let Some(tolerance) = tolerance {
+                    scaled = scaled.try_with_tolerance(*tolerance)?;
+                }
+                Box::new(scaled)
+            }
+            ConstraintSpec::SecondOrderCone {
+                alpha,
+                scalar_index,
+            } => {
+                let soc = SecondOrderCone::try_new(*alpha)?;
+                match scalar_index {
+                    Some(index) => Box::new(soc.with_scalar_index(*index)),
+                    None => Box::new(soc),
+                }
+            }
+            ConstraintSpec::Simplex { alpha } => Box::new(Simplex::try_new(*alpha)?),
+            ConstraintSpec::SpecialOrthogonal { n } => Box::new(SpecialOrthogonal::try_new(*n)?),
+            ConstraintSpec::Sphere2 { center, radius } => {
+                Box::new(Sphere2::try_new(center.as_deref(), *radius)?)
+            }
+            ConstraintSpec::Stiefel { n_rows, n_cols } => {
+                Box::new(Stiefel::try_new(*n_rows, *n_cols)?)
+            }
+            ConstraintSpec::Translated { set, translation } => {
+                Box::new(Translated::try_new(set.build()?, translation.clone())?)
+            }
+            ConstraintSpec::Union { constraints } => Box::new(Union::try_new(
+                constraints
+                    .iter()
+                    .map(|constraint| constraint.build())
+                    .collect::<Result<_, _>>()?,
+            )?),
+            ConstraintSpec::WeightedBall1 {
+                weights,
+                center,
+                radius,
+            } => Box::new(WeightedBall1::try_new(weights, center.as_deref(), *radius)?),
+            ConstraintSpec::Zero => Box::new(Zero::new()),
+        };
+        Ok(constraint)
+    }
+}
+
+/// Returns an error if `found` is not equal to `expected`
+fn check_dimension(expected: usize, found: usize) -> Result<(), ConstructionError> {
+    if expected != found {
+        return Err(ConstructionError::DimensionMismatch { expected, found });
+    }
+    Ok(())
+}
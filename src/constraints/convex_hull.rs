@@ -0,0 +1,293 @@
+use super::{Constraint, FiniteSet};
+use crate::matrix_operations::{cholesky_factor, cholesky_solve};
+use ndarray::Array2;
+
+/// Default maximum number of (major) iterations of Wolfe's algorithm
+const DEFAULT_MAX_ITERATIONS: usize = 1000;
+
+/// Default tolerance of Wolfe's algorithm
+const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+#[derive(Clone)]
+/// The convex hull of a finite set of points, $p_0, \ldots, p_{N-1}$, that is,
+///
+/// $$
+/// \mathrm{conv}\\{p_0, \ldots, p_{N-1}\\} = \\{\textstyle\sum_i \lambda_i p_i {}:{}
+/// \lambda \geq 0, \sum_i \lambda_i = 1\\}.
+/// $$
+///
+/// Projections are computed with Wolfe's minimum-norm-point algorithm, which
+/// maintains a small set of affinely independent points (the "corral"), so that
+/// each iteration only involves a linear system whose size is at most the
+/// dimension of the space plus one. The algorithm terminates when Wolfe's
+/// optimality criterion is satisfied up to a given tolerance, or when the
+/// maximum number of iterations is reached.
+///
+/// A convex hull can also be obtained from a `FiniteSet` using
+/// `FiniteSet::convex_hull`.
+pub struct ConvexHull<'a> {
+    /// the points whose convex hull is taken
+    points: FiniteSet<'a>,
+    /// maximum number of (major) iterations of Wolfe's algorithm
+    max_iterations: usize,
+    /// tolerance of Wolfe's algorithm
+    tolerance: f64,
+}
+
+impl<'a> ConvexHull<'a> {
+    /// Construct the convex hull of the given points
+    ///
+    /// # Arguments
+    ///
+    /// - `data`: the points (see `FiniteSet::new`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, ConvexHull};
+    ///
+    /// let data: &[&[f64]] = &[&[0.0, 0.0], &[1.0, 0.0], &[0.0, 1.0], &[1.0, 1.0]];
+    /// let convex_hull = ConvexHull::new(data);
+    /// let mut x = [2.0, 0.5];
+    /// convex_hull.project(&mut x);
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 0.5).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if (i) the given vector of data is empty
+    /// and (ii) if the given vectors have unequal dimensions.
+    ///
+    pub fn new(data: &'a [&'a [f64]]) -> Self {
+        ConvexHull::from_finite_set(FiniteSet::new(data))
+    }
+
+    /// Construct the convex hull of the given points, which it owns (see `new`)
+    pub fn new_owned(data: Vec<Vec<f64>>) -> Self {
+        ConvexHull::from_finite_set(FiniteSet::new_owned(data))
+    }
+
+    pub(super) fn from_finite_set(points: FiniteSet<'a>) -> Self {
+        ConvexHull {
+            points,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Set the maximum number of (major) iterations of Wolfe's algorithm
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `max_iterations` is zero
+    ///
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        assert!(max_iterations > 0, "max_iterations must be positive");
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the tolerance of Wolfe's algorithm
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `tolerance` is not positive
+    ///
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Inner product $\langle p_i - x, y\rangle$
+    fn inner_product(&self, i: usize, x: &[f64], y: &[f64]) -> f64 {
+        self.points
+            .element(i)
+            .iter()
+            .zip(x.iter())
+            .zip(y.iter())
+            .fold(0.0, |sum, ((pi, xi), yi)| sum + (pi - xi) * yi)
+    }
+
+    /// Computes the point of the affine hull of the points of the corral,
+    /// $\sum_s \alpha_s (p_s - x)$ with $\sum_s \alpha_s = 1$, which has the
+    /// smallest norm and stores the coefficients $\alpha_s$ in `alpha`; returns
+    /// `false` if the points of the corral are (numerically) affinely dependent
+    fn affine_minimizer(&self, x: &[f64], corral: &[usize], alpha: &mut Vec<f64>) -> bool {
+        // with d_a = p_a - p_0, the minimizer is p_0 - x + sum_a beta_a d_a,
+        // where (D'D) beta = -D'(p_0 - x)
+        let m = corral.len() - 1;
+        let p0 = self.points.element(corral[0]);
+        let mut gram = Array2::<f64>::zeros((m, m));
+        let mut rhs = vec![0.0; m];
+        for a in 0..m {
+            let pa = self.points.element(corral[a + 1]);
+            for b in 0..=a {
+                let pb = self.points.element(corral[b + 1]);
+                let entry = pa
+                    .iter()
+                    .zip(pb.iter())
+                    .zip(p0.iter())
+                    .fold(0.0, |sum, ((pai, pbi), p0i)| {
+                        sum + (pai - p0i) * (pbi - p0i)
+                    });
+                gram[(a, b)] = entry;
+                gram[(b, a)] = entry;
+            }
+            rhs[a] = -pa
+                .iter()
+                .zip(p0.iter())
+                .zip(x.iter())
+                .fold(0.0, |sum, ((pai, p0i), xi)| sum + (pai - p0i) * (p0i - xi));
+        }
+        let chol = match cholesky_factor(&gram) {
+            Some(chol) => chol,
+            None => return false,
+        };
+        let mut beta = vec![0.0; m];
+        cholesky_solve(&chol, &rhs, &mut beta);
+        if beta.iter().any(|b| !b.is_finite()) {
+            return false;
+        }
+        alpha.clear();
+        alpha.push(1.0 - beta.iter().sum::<f64>());
+        alpha.extend_from_slice(&beta);
+        true
+    }
+}
+
+/// Removes the points of the corral whose weights are not positive
+fn remove_zero_weights(corral: &mut Vec<usize>, lambda: &mut Vec<f64>) {
+    let mut s = 0;
+    while s < corral.len() {
+        if lambda[s] <= 0.0 {
+            corral.swap_remove(s);
+            lambda.swap_remove(s);
+        } else {
+            s += 1;
+        }
+    }
+}
+
+impl<'a> Constraint for ConvexHull<'a> {
+    /// Project onto the convex hull using Wolfe's minimum-norm-point algorithm
+    ///
+    /// The projection of $x$ is $x + y^\star$, where $y^\star$ is the point of
+    /// minimum norm in the convex hull of $q_i = p_i - x$. Starting from the
+    /// point $q_i$ which is closest to the origin, each (major) iteration
+    /// computes $j = \mathrm{argmin}_i \langle y, q_i\rangle$ and terminates if
+    ///
+    /// $$
+    /// \Vert y \Vert^2 - \langle y, q_j\rangle \leq \epsilon \max_i \Vert q_i\Vert^2;
+    /// $$
+    ///
+    /// otherwise, $q_j$ is added to the corral and the (minor) iterations move
+    /// $y$ towards the point of minimum norm in the affine hull of the corral,
+    /// removing the points whose weights become zero.
+    ///
+    /// See: Philip Wolfe. Finding the nearest point in a polytope.
+    /// <em>Mathematical Programming</em> 11, pp.128-149, 1976.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the dimension
+    /// of the points
+    ///
+    fn project(&self, x: &mut [f64]) {
+        let n = self.points.element(0).len();
+        assert!(x.len() == n, "x has wrong dimension");
+        let num_points = self.points.num_elements();
+        let (mut j0, mut min_distance, mut max_distance) = (0, f64::INFINITY, 0.0_f64);
+        for i in 0..num_points {
+            let distance = crate::matrix_operations::norm2_squared_diff(self.points.element(i), x);
+            if distance < min_distance {
+                j0 = i;
+                min_distance = distance;
+            }
+            max_distance = max_distance.max(distance);
+        }
+
+        let mut corral = vec![j0];
+        let mut lambda = vec![1.0];
+        let mut alpha = Vec::with_capacity(n + 1);
+        let mut y: Vec<f64> = self
+            .points
+            .element(j0)
+            .iter()
+            .zip(x.iter())
+            .map(|(pi, xi)| pi - xi)
+            .collect();
+        'major: for _ in 0..self.max_iterations {
+            let (j, min_inner_product) = (0..num_points)
+                .map(|i| (i, self.inner_product(i, x, &y)))
+                .fold(
+                    (0, f64::INFINITY),
+                    |best, (i, ip)| {
+                        if ip < best.1 {
+                            (i, ip)
+                        } else {
+                            best
+                        }
+                    },
+                );
+            let norm_y_squared = crate::matrix_operations::norm2_squared(&y);
+            if norm_y_squared - min_inner_product <= self.tolerance * max_distance
+                || corral.contains(&j)
+            {
+                break;
+            }
+            corral.push(j);
+            lambda.push(0.0);
+
+            // minor iterations
+            loop {
+                if !self.affine_minimizer(x, &corral, &mut alpha) {
+                    break 'major;
+                }
+                if alpha.iter().all(|&a| a >= 0.0) {
+                    lambda.copy_from_slice(&alpha);
+                    break;
+                }
+                let (s_min, theta) = lambda
+                    .iter()
+                    .zip(alpha.iter())
+                    .enumerate()
+                    .filter(|(_, (_, &a))| a < 0.0)
+                    .map(|(s, (&l, &a))| (s, l / (l - a)))
+                    .fold(
+                        (0, f64::INFINITY),
+                        |best, (s, t)| {
+                            if t < best.1 {
+                                (s, t)
+                            } else {
+                                best
+                            }
+                        },
+                    );
+                lambda
+                    .iter_mut()
+                    .zip(alpha.iter())
+                    .for_each(|(l, &a)| *l = theta * a + (1.0 - theta) * *l);
+                lambda[s_min] = 0.0;
+                remove_zero_weights(&mut corral, &mut lambda);
+            }
+            remove_zero_weights(&mut corral, &mut lambda);
+
+            y.iter_mut().zip(x.iter()).for_each(|(yi, xi)| *yi = -xi);
+            for (&s, &l) in corral.iter().zip(lambda.iter()) {
+                y.iter_mut()
+                    .zip(self.points.element(s).iter())
+                    .for_each(|(yi, pi)| *yi += l * pi);
+            }
+        }
+        x.iter_mut().zip(y.iter()).for_each(|(xi, yi)| *xi += yi);
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.points.element(0).len())
+    }
+}
@@ -0,0 +1,331 @@
+use super::Constraint;
+use crate::{matrix_operations, ConstructionError};
+
+/// Maximum number of Newton iterations for the computation of the projection
+const MAX_NEWTON_ITERATIONS: usize = 100;
+
+/// Relative tolerance on the secular equation
+const NEWTON_TOLERANCE: f64 = 1e-12;
+
+/// Tolerance used to check whether the given matrix $Q$ is symmetric
+const SYMMETRY_TOLERANCE: f64 = 1e-10;
+
+#[derive(Clone)]
+/// An ellipsoid is a set given by $E = \\{x\in\mathbb{R}^n {}:{} (x-x_c)^\intercal Q (x-x_c) \leq r\\}$,
+/// where $Q\in\mathbb{R}^{n\times n}$ is a symmetric positive definite matrix, $x_c$ is
+/// the center of the ellipsoid and $r > 0$.
+///
+/// The eigenvalue decomposition $Q = V\Lambda V^\intercal$ is computed once, upon
+/// construction.
+pub struct Ellipsoid {
+    /// eigenvalues of $Q$
+    eigenvalues: Vec<f64>,
+    /// eigenvectors of $Q$ (matrix $V$, row-wise data)
+    eigenvectors: Vec<f64>,
+    center: Option<Vec<f64>>,
+    r: f64,
+    n: usize,
+}
+
+impl Ellipsoid {
+    /// Construct a new ellipsoid given the matrix $Q$, the center $x_c$
+    /// and the parameter $r$
+    ///
+    /// ## Arguments
+    ///
+    /// - `q`: matrix $Q$, row-wise data; it must be symmetric and positive definite
+    /// - `center`: center of the ellipsoid; if `None`, the ellipsoid is centered
+    ///   at the origin
+    /// - `r`: positive parameter $r$
+    ///
+    /// ## Returns
+    ///
+    /// New instance of `Ellipsoid`
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if
+    ///
+    /// - `q` is not a square matrix, or if its dimensions are incompatible with
+    ///   those of `center`
+    /// - `q` is not symmetric or not positive definite
+    /// - `r` is not positive
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// let q = vec![2.0, 0.5, 0.5, 1.0];
+    /// let center = vec![1.0, -1.0];
+    /// let ellipsoid = Ellipsoid::new(q, Some(center), 0.5);
+    /// let mut x = [3.0, 2.0];
+    /// ellipsoid.project(&mut x);
+    /// ```
+    ///
+    pub fn new(q: Vec<f64>, center: Option<Vec<f64>>, r: f64) -> Self {
+        assert!(r > 0.0, "r must be positive");
+        let n = (q.len() as f64).sqrt().round() as usize;
+        assert!(n * n == q.len() && n > 0, "Q must be a square matrix");
+        if let Some(c) = &center {
+            assert!(c.len() == n, "Q and center have incompatible dimensions");
+        }
+        assert!(
+            Ellipsoid::asymmetric_element(&q, n).is_none(),
+            "Q must be symmetric"
+        );
+        let (eigenvalues, eigenvectors) = Ellipsoid::eigen_decomposition(q, n);
+        assert!(
+            eigenvalues.iter().all(|&lambda| lambda > 0.0),
+            "Q must be positive definite"
+        );
+        Ellipsoid {
+            eigenvalues,
+            eigenvectors,
+            center,
+            r,
+            n,
+        }
+    }
+
+    /// Construct a new ellipsoid, or return an error if the data is invalid
+    /// (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::InvalidParameter` if `r` is not positive, if `q` is
+    ///   not symmetric (the value is an element of $Q$ which differs from its
+    ///   transposed element), or if `q` is not positive definite (the value is the
+    ///   smallest eigenvalue of $Q$)
+    /// - `ConstructionError::EmptyData` if `q` is empty
+    /// - `ConstructionError::DimensionMismatch` if `q` is not a square matrix, or
+    ///   if its dimensions are incompatible with those of `center`
+    ///
+    pub fn try_new(
+        q: Vec<f64>,
+        center: Option<Vec<f64>>,
+        r: f64,
+    ) -> Result<Self, ConstructionError> {
+        if r.is_nan() || r <= 0.0 {
+            return Err(ConstructionError::InvalidParameter {
+                name: "r",
+                value: r,
+            });
+        }
+        if q.is_empty() {
+            return Err(ConstructionError::EmptyData);
+        }
+        let n = (q.len() as f64).sqrt().round() as usize;
+        if n * n != q.len() {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: n * n,
+                found: q.len(),
+            });
+        }
+        if let Some(c) = &center {
+            if c.len() != n {
+                return Err(ConstructionError::DimensionMismatch {
+                    expected: n,
+                    found: c.len(),
+                });
+            }
+        }
+        if let Some(q_ij) = Ellipsoid::asymmetric_element(&q, n) {
+            return Err(ConstructionError::InvalidParameter {
+                name: "q",
+                value: q_ij,
+            });
+        }
+        let (eigenvalues, eigenvectors) = Ellipsoid::eigen_decomposition(q, n);
+        let min_eigenvalue = eigenvalues.iter().fold(f64::INFINITY, |m, &l| m.min(l));
+        if min_eigenvalue.is_nan() || min_eigenvalue <= 0.0 {
+            return Err(ConstructionError::InvalidParameter {
+                name: "q",
+                value: min_eigenvalue,
+            });
+        }
+        Ok(Ellipsoid {
+            eigenvalues,
+            eigenvectors,
+            center,
+            r,
+            n,
+        })
+    }
+
+    /// Returns an element $Q_{ij}$ which is not equal to $Q_{ji}$ (up to a
+    /// tolerance), if any
+    fn asymmetric_element(q: &[f64], n: usize) -> Option<f64> {
+        (0..n)
+            .flat_map(|i| (0..i).map(move |j| (i, j)))
+            .map(|(i, j)| (q[i * n + j], q[j * n + i]))
+            .find(|&(q_ij, q_ji)| (q_ij - q_ji).abs() > SYMMETRY_TOLERANCE * (1.0 + q_ij.abs()))
+            .map(|(q_ij, _)| q_ij)
+    }
+
+    /// Eigenvalues and eigenvectors (row-wise) of the symmetric matrix $Q$
+    fn eigen_decomposition(mut q: Vec<f64>, n: usize) -> (Vec<f64>, Vec<f64>) {
+        let mut eigenvalues = vec![0.0; n];
+        let mut eigenvectors = vec![0.0; n * n];
+        matrix_operations::symmetric_eigen(&mut q, &mut eigenvalues, &mut eigenvectors);
+        (eigenvalues, eigenvectors)
+    }
+
+    /// Computes $(y(\mu)^\intercal Q y(\mu), \tfrac{d}{d\mu}y(\mu)^\intercal Q y(\mu))$,
+    /// where $y(\mu) = V(I + \mu \Lambda)^{-1}\hat{w}$, in $O(n)$ operations
+    fn secular_function(&self, mu: f64, w_hat: &[f64]) -> (f64, f64) {
+        self.eigenvalues.iter().zip(w_hat.iter()).fold(
+            (0.0, 0.0),
+            |(value, derivative), (&lambda, &wi)| {
+                let denominator = 1.0 + mu * lambda;
+                let ratio = lambda * wi * wi / (denominator * denominator);
+                (
+                    value + ratio,
+                    derivative - 2.0 * lambda * ratio / denominator,
+                )
+            },
+        )
+    }
+}
+
+impl Constraint for Ellipsoid {
+    /// Projection onto the ellipsoid $E$
+    ///
+    /// If $x\notin E$, the projection is $x^\star = x_c + y(\mu)$, where
+    /// $y(\mu) = (I + \mu Q)^{-1}(x - x_c)$ and $\mu > 0$ is the root of the
+    /// secular equation
+    ///
+    /// $$
+    /// \phi(\mu) = y(\mu)^\intercal Q y(\mu) - r
+    /// = \sum_{i} \frac{\lambda_i \hat{w}_i^2}{(1 + \mu\lambda_i)^2} - r = 0,
+    /// $$
+    ///
+    /// where $\hat{w} = V^\intercal(x - x_c)$ and $Q = V\Lambda V^\intercal$.
+    ///
+    /// Function $\phi$ is decreasing on $[0, \infty)$ and its root is computed
+    /// by applying Newton's method, starting at $\mu=0$, to the equivalent
+    /// equation $(\phi(\mu) + r)^{-1/2} - r^{-1/2} = 0$, safeguarded by
+    /// bisection. Every Newton iteration requires $O(n)$ operations, while the
+    /// change of coordinates requires $O(n^2)$ operations.
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: The given vector $x$ is updated with the projection on the set
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the dimension
+    /// of the ellipsoid
+    ///
+    fn project(&self, x: &mut [f64]) {
+        let n = self.n;
+        assert!(x.len() == n, "x has wrong dimension");
+
+        // x ← x - x_c
+        if let Some(center) = &self.center {
+            x.iter_mut()
+                .zip(center.iter())
+                .for_each(|(xi, &ci)| *xi -= ci);
+        }
+
+        // w_hat ← V'(x - x_c)
+        let mut w_hat = vec![0.0; n];
+        for (k, &xk) in x.iter().enumerate() {
+            w_hat
+                .iter_mut()
+                .zip(self.eigenvectors[k * n..(k + 1) * n].iter())
+                .for_each(|(wi, &v_ki)| *wi += v_ki * xk);
+        }
+
+        let (quad_form, _) = self.secular_function(0.0, &w_hat);
+        if quad_form > self.r {
+            let mut mu = 0.0;
+            let mut mu_low = 0.0;
+            let mut mu_high = f64::INFINITY;
+            let mut quad_form_y = quad_form;
+            for _ in 0..MAX_NEWTON_ITERATIONS {
+                let (value, dphi) = self.secular_function(mu, &w_hat);
+                quad_form_y = value;
+                let phi = quad_form_y - self.r;
+                if phi.abs() <= NEWTON_TOLERANCE * self.r {
+                    break;
+                }
+                if phi > 0.0 {
+                    mu_low = mu;
+                } else {
+                    mu_high = mu;
+                }
+                // Newton's method is applied to psi(mu) = 1/sqrt(phi(mu) + r) - 1/sqrt(r),
+                // which is almost linear in mu
+                let psi = 1.0 / quad_form_y.sqrt() - 1.0 / self.r.sqrt();
+                let dpsi = -0.5 * dphi / quad_form_y.powf(1.5);
+                let mut mu_next = mu - psi / dpsi;
+                if !mu_next.is_finite() || mu_next <= mu_low || mu_next >= mu_high {
+                    mu_next = if mu_high.is_finite() {
+                        0.5 * (mu_low + mu_high)
+                    } else {
+                        2.0 * f64::max(mu, 1.0)
+                    };
+                }
+                mu = mu_next;
+            }
+
+            // Scale y so that it lies on the boundary of the ellipsoid
+            // (this guarantees feasibility against round-off errors)
+            let scaling = if quad_form_y > self.r {
+                (self.r / quad_form_y).sqrt()
+            } else {
+                1.0
+            };
+
+            // x ← V (I + mu Lambda)^{-1} w_hat, that is, x ← y(mu)
+            w_hat
+                .iter_mut()
+                .zip(self.eigenvalues.iter())
+                .for_each(|(wi, &lambda)| *wi *= scaling / (1.0 + mu * lambda));
+            x.iter_mut().enumerate().for_each(|(k, xk)| {
+                *xk =
+                    matrix_operations::inner_product(&self.eigenvectors[k * n..(k + 1) * n], &w_hat)
+            });
+        }
+
+        // x ← x_c + y
+        if let Some(center) = &self.center {
+            x.iter_mut()
+                .zip(center.iter())
+                .for_each(|(xi, &ci)| *xi += ci);
+        }
+    }
+
+    /// Ellipsoids are convex sets
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    /// Checks whether $(x-x_c)^\intercal Q (x-x_c) \leq r$, which is computed
+    /// as $\sum_i \lambda_i \hat{w}_i^2$ without allocating memory; otherwise,
+    /// whether $\mathrm{dist}_E(x) \leq \epsilon$
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let n = self.n;
+        assert!(x.len() == n, "x has wrong dimension");
+        let quad_form = self
+            .eigenvalues
+            .iter()
+            .enumerate()
+            .fold(0.0, |sum, (i, &lambda)| {
+                let wi = (0..n).fold(0.0, |wi, k| {
+                    let xk = match &self.center {
+                        Some(center) => x[k] - center[k],
+                        None => x[k],
+                    };
+                    wi + self.eigenvectors[k * n + i] * xk
+                });
+                sum + lambda * wi * wi
+            });
+        quad_form <= self.r || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.n)
+    }
+}
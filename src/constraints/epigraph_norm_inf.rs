@@ -0,0 +1,69 @@
+use super::epigraph_norm_l1::project_on_epigraph_norm_l1;
+use super::Constraint;
+
+#[derive(Clone, Copy, Default)]
+///
+/// The epigraph of the $\ell_\infty$ norm
+///
+/// A set of the form
+///
+/// $$
+/// X = \\{x = (z, t) \in \mathbb{R}^{n+1} {}:{} \Vert z \Vert_\infty \leq t\\},
+/// $$
+///
+/// where $t$ is the last element of $x$.
+///
+pub struct EpigraphNormInf {}
+
+impl EpigraphNormInf {
+    /// Construct a new instance of the epigraph of the $\ell_\infty$ norm
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, EpigraphNormInf};
+    ///
+    /// let epi = EpigraphNormInf::new();
+    /// let mut x = [1.0, -2.0, 0.5];
+    /// epi.project(&mut x);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        EpigraphNormInf {}
+    }
+}
+
+impl Constraint for EpigraphNormInf {
+    /// Project on the epigraph of the $\ell_\infty$ norm
+    ///
+    /// The polar cone of the epigraph of the $\ell_\infty$ norm is
+    /// $\\{(y, s) {}:{} \Vert y \Vert_1 \leq -s\\}$, that is, the negative of
+    /// the epigraph of the $\ell_1$ norm, so, by Moreau's decomposition theorem,
+    /// the projection of $x$ is $x + \Pi_{\mathrm{epi}\Vert\cdot\Vert_1}(-x)$.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the epigraph,
+    ///   (out) projection on the epigraph
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is less than 2.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() >= 2, "x must be of dimension at least 2");
+        let mut y: Vec<f64> = x.iter().map(|xi| -xi).collect();
+        project_on_epigraph_norm_l1(&mut y);
+        x.iter_mut().zip(y.iter()).for_each(|(xi, yi)| *xi += yi);
+    }
+
+    /// The epigraph of the $\ell_\infty$ norm is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let n = x.len();
+        crate::matrix_operations::norm_inf(&x[..n - 1]) <= x[n - 1] || self.distance(x) <= tol
+    }
+}
@@ -0,0 +1,96 @@
+use super::Constraint;
+
+#[derive(Clone, Copy, Default)]
+///
+/// The epigraph of the $\ell_1$ norm
+///
+/// A set of the form
+///
+/// $$
+/// X = \\{x = (z, t) \in \mathbb{R}^{n+1} {}:{} \Vert z \Vert_1 \leq t\\},
+/// $$
+///
+/// where $t$ is the last element of $x$.
+///
+pub struct EpigraphNormL1 {}
+
+impl EpigraphNormL1 {
+    /// Construct a new instance of the epigraph of the $\ell_1$ norm
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, EpigraphNormL1};
+    ///
+    /// let epi = EpigraphNormL1::new();
+    /// let mut x = [1.0, -2.0, 0.5];
+    /// epi.project(&mut x);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        EpigraphNormL1 {}
+    }
+}
+
+/// Projects `x = (z, t)` on the epigraph of the $\ell_1$ norm
+pub(super) fn project_on_epigraph_norm_l1(x: &mut [f64]) {
+    let n = x.len();
+    let t0 = x[n - 1];
+    let z = &mut x[..n - 1];
+    if z.iter().fold(0.0, |sum, zi| sum + zi.abs()) <= t0 {
+        return;
+    }
+
+    // Find lambda >= 0 such that sum_i max(|z_i| - lambda, 0) = t0 + lambda
+    let mut abs_z: Vec<f64> = z.iter().map(|zi| zi.abs()).collect();
+    abs_z.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap());
+    let mut lambda = f64::max(-t0, 0.0);
+    let mut cumulative_sum = 0.0;
+    for (k, &a_k) in abs_z.iter().enumerate() {
+        cumulative_sum += a_k;
+        let lambda_k = (cumulative_sum - t0) / (k as f64 + 2.0);
+        let a_next = abs_z.get(k + 1).copied().unwrap_or(0.0);
+        if lambda_k < a_k && lambda_k >= a_next {
+            lambda = lambda_k;
+            break;
+        }
+    }
+
+    z.iter_mut()
+        .for_each(|zi| *zi = zi.signum() * f64::max(zi.abs() - lambda, 0.0));
+    x[n - 1] = t0 + lambda;
+}
+
+impl Constraint for EpigraphNormL1 {
+    /// Project on the epigraph of the $\ell_1$ norm
+    ///
+    /// If $(z_0, t_0)$ is not in the epigraph, its projection is
+    /// $(\mathrm{sgn}(z_0)\max\\{|z_0| - \lambda, 0\\}, t_0 + \lambda)$, where
+    /// $\lambda \geq 0$ solves the piecewise linear equation
+    /// $\sum_i \max\\{|z_{0,i}| - \lambda, 0\\} = t_0 + \lambda$. This is
+    /// solved exactly after sorting the elements of $|z_0|$.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the epigraph,
+    ///   (out) projection on the epigraph
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is less than 2.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() >= 2, "x must be of dimension at least 2");
+        project_on_epigraph_norm_l1(x);
+    }
+
+    /// The epigraph of the $\ell_1$ norm is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let n = x.len();
+        crate::matrix_operations::norm1(&x[..n - 1]) <= x[n - 1] || self.distance(x) <= tol
+    }
+}
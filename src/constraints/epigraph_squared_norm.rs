@@ -0,0 +1,114 @@
+use super::Constraint;
+use crate::matrix_operations;
+
+/// Maximum number of Newton iterations
+const MAX_NEWTON_ITERATIONS: usize = 100;
+
+#[derive(Clone, Copy, Default)]
+///
+/// The epigraph of the squared Euclidean norm
+///
+/// A set of the form
+///
+/// $$
+/// X = \\{x = (z, t) \in \mathbb{R}^{n+1} {}:{} \Vert z \Vert^2 \leq t\\},
+/// $$
+///
+/// where $t$ is the last element of $x$.
+///
+/// The epigraph of the squared norm can be used to move a quadratic term of
+/// the cost function into the constraints (epigraph reformulation).
+///
+pub struct EpigraphSquaredNorm {}
+
+impl EpigraphSquaredNorm {
+    /// Construct a new instance of the epigraph of the squared Euclidean norm
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, EpigraphSquaredNorm};
+    ///
+    /// let epi = EpigraphSquaredNorm::new();
+    /// let mut x = [1.0, 2.0, 0.5];
+    /// epi.project(&mut x);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        EpigraphSquaredNorm {}
+    }
+}
+
+impl Constraint for EpigraphSquaredNorm {
+    /// Project on the epigraph of the squared Euclidean norm
+    ///
+    /// If $(z_0, t_0)$ is not in the epigraph, its projection is
+    /// $(z_0 / s, t_0 + (s - 1)/2)$, where $s > 1$ is the unique positive
+    /// root of the cubic equation
+    ///
+    /// $$
+    /// s^3 + (2t_0 - 1)s^2 - 2\Vert z_0 \Vert^2 = 0,
+    /// $$
+    ///
+    /// which is computed using Newton's method safeguarded by bisection.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the epigraph,
+    ///   (out) projection on the epigraph
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is less than 2.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        let n = x.len();
+        assert!(n >= 2, "x must be of dimension at least 2");
+        let t0 = x[n - 1];
+        let norm_z_sq = matrix_operations::norm2_squared(&x[..n - 1]);
+        if norm_z_sq <= t0 {
+            return;
+        }
+
+        // f(s) = s^3 + (2t0 - 1)s^2 - 2||z0||^2 with f(lower) < 0 < f(upper)
+        let b = 2.0 * t0 - 1.0;
+        let f = |s: f64| ((s + b) * s * s - 2.0 * norm_z_sq, (3.0 * s + 2.0 * b) * s);
+        let mut lower = 1.0;
+        let mut upper = f64::max(-b, 0.0) + (2.0 * norm_z_sq).cbrt() + 1.0;
+        let mut s = upper;
+        for _ in 0..MAX_NEWTON_ITERATIONS {
+            let (f_s, df_s) = f(s);
+            if f_s == 0.0 {
+                break;
+            } else if f_s < 0.0 {
+                lower = s;
+            } else {
+                upper = s;
+            }
+            let s_newton = s - f_s / df_s;
+            let s_next = if s_newton > lower && s_newton < upper {
+                s_newton
+            } else {
+                0.5 * (lower + upper)
+            };
+            if (s_next - s).abs() <= f64::EPSILON * s {
+                s = s_next;
+                break;
+            }
+            s = s_next;
+        }
+
+        x[..n - 1].iter_mut().for_each(|zi| *zi /= s);
+        x[n - 1] = t0 + 0.5 * (s - 1.0);
+    }
+
+    /// The epigraph of the squared norm is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let n = x.len();
+        crate::matrix_operations::norm2_squared(&x[..n - 1]) <= x[n - 1] || self.distance(x) <= tol
+    }
+}
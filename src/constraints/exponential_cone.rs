@@ -0,0 +1,205 @@
+use super::Constraint;
+
+/// Maximum number of (safeguarded) Newton iterations
+const MAX_ITERATIONS: usize = 200;
+
+/// Bound on the parameter $\rho$ to avoid overflows
+const MAX_ABS_RHO: f64 = 300.0;
+
+#[derive(Clone, Copy, Default)]
+///
+/// The exponential cone
+///
+/// The (three-dimensional) exponential cone is the set
+///
+/// $$
+/// K_{\exp} = \mathrm{cl}\\{(x, y, z) \in \mathbb{R}^3 {}:{} y > 0, y e^{x/y} \leq z\\},
+/// $$
+///
+/// that is, $K_{\exp} = \\{(x, y, z) {}:{} y > 0, y e^{x/y} \leq z\\}
+/// \cup \\{(x, 0, z) {}:{} x \leq 0, z \geq 0\\}$.
+///
+/// Projections on the exponential cone are computed as in H. A. Friberg,
+/// Projection onto the exponential cone: a univariate root-finding problem,
+/// Optimization Methods and Software, 2021, that is, by solving an equation
+/// in one variable.
+///
+pub struct ExponentialCone {}
+
+impl ExponentialCone {
+    /// Construct a new instance of the exponential cone
+    pub fn new() -> Self {
+        ExponentialCone {}
+    }
+}
+
+/// Whether `v` is in the exponential cone
+fn is_in_exp_cone(v: &[f64]) -> bool {
+    let (x, y, z) = (v[0], v[1], v[2]);
+    (y > 0.0 && y * (x / y).exp() <= z) || (y == 0.0 && x <= 0.0 && z >= 0.0)
+}
+
+/// Whether `v` is in the polar of the exponential cone, that is,
+/// $-v$ is in the dual cone, $K_{\exp}^* = \mathrm{cl}\\{(u, v, w) {}:{} u < 0, -u e^{v/u} \leq e w\\}$
+fn is_in_exp_polar_cone(v: &[f64]) -> bool {
+    let (x, y, z) = (v[0], v[1], v[2]);
+    (x > 0.0 && x * (y / x).exp() <= -std::f64::consts::E * z) || (x == 0.0 && y <= 0.0 && z <= 0.0)
+}
+
+/// Squared distance between `a` and `b`
+fn dist_squared(a: &[f64; 3], b: &[f64]) -> f64 {
+    crate::matrix_operations::norm2_squared_diff(a, b)
+}
+
+impl ExponentialCone {
+    /// Computes the function $\psi(\rho) = \alpha(\rho)e^\rho - \beta(\rho)e^{-\rho} - z_0$
+    /// and its derivative, where $v_0 = \alpha(\rho)(\rho, 1, e^\rho) + \beta(\rho)(1, 1-\rho, -e^{-\rho})$
+    /// (Moreau decomposition) for the first two coordinates
+    fn psi(rho: f64, v: &[f64]) -> (f64, f64) {
+        let (r0, s0, t0) = (v[0], v[1], v[2]);
+        let d = rho * rho - rho + 1.0;
+        let alpha = ((rho - 1.0) * r0 + s0) / d;
+        let beta = (r0 - rho * s0) / d;
+        let d_alpha = (r0 * d - ((rho - 1.0) * r0 + s0) * (2.0 * rho - 1.0)) / (d * d);
+        let d_beta = (-s0 * d - (r0 - rho * s0) * (2.0 * rho - 1.0)) / (d * d);
+        let exp_rho = rho.exp();
+        let exp_minus_rho = (-rho).exp();
+        let psi = alpha * exp_rho - beta * exp_minus_rho - t0;
+        let d_psi = (d_alpha + alpha) * exp_rho + (beta - d_beta) * exp_minus_rho;
+        (psi, d_psi)
+    }
+
+    /// Computes the projection on the part of the boundary of the cone where
+    /// $y > 0$ by solving $\psi(\rho) = 0$; returns `None` if a root cannot be
+    /// bracketed
+    fn project_on_smooth_boundary(v: &[f64]) -> Option<[f64; 3]> {
+        let (r0, s0) = (v[0], v[1]);
+        // We need alpha(rho) > 0 and beta(rho) > 0, which defines an interval
+        let mut lower = -MAX_ABS_RHO;
+        let mut upper = MAX_ABS_RHO;
+        if r0 > 0.0 {
+            lower = lower.max(1.0 - s0 / r0);
+        } else if r0 < 0.0 {
+            upper = upper.min(1.0 - s0 / r0);
+        } else if s0 <= 0.0 {
+            return None;
+        }
+        if s0 > 0.0 {
+            upper = upper.min(r0 / s0);
+        } else if s0 < 0.0 {
+            lower = lower.max(r0 / s0);
+        } else if r0 <= 0.0 {
+            return None;
+        }
+        if lower >= upper {
+            return None;
+        }
+
+        let (psi_lower, _) = ExponentialCone::psi(lower, v);
+        let (psi_upper, _) = ExponentialCone::psi(upper, v);
+        if !psi_lower.is_finite() || !psi_upper.is_finite() || psi_lower * psi_upper > 0.0 {
+            return None;
+        }
+        let increasing = psi_lower < psi_upper;
+
+        // Newton's method safeguarded by bisection
+        let mut rho = 0.5 * (lower + upper);
+        for _ in 0..MAX_ITERATIONS {
+            let (psi, d_psi) = ExponentialCone::psi(rho, v);
+            if psi == 0.0 {
+                break;
+            }
+            if (psi < 0.0) == increasing {
+                lower = rho;
+            } else {
+                upper = rho;
+            }
+            if upper - lower <= f64::EPSILON * (1.0 + rho.abs()) {
+                break;
+            }
+            let rho_newton = rho - psi / d_psi;
+            rho = if rho_newton.is_finite() && rho_newton > lower && rho_newton < upper {
+                rho_newton
+            } else {
+                0.5 * (lower + upper)
+            };
+        }
+
+        // Project v on the ray {alpha (rho, 1, exp(rho)), alpha >= 0}; this
+        // is better conditioned than computing alpha(rho) directly
+        let ray = [rho, 1.0, rho.exp()];
+        let alpha = f64::max(
+            crate::matrix_operations::inner_product(&ray, v)
+                / crate::matrix_operations::norm2_squared(&ray),
+            0.0,
+        );
+        Some([alpha * ray[0], alpha * ray[1], alpha * ray[2]])
+    }
+}
+
+impl Constraint for ExponentialCone {
+    /// Project on the exponential cone
+    ///
+    /// If $v_0=(x_0, y_0, z_0)$ is neither in the cone nor in its polar, and
+    /// $x_0 > 0$ or $y_0 > 0$, then the projection is of the form
+    /// $\alpha(\rho, 1, e^{\rho})$, where $\rho$ solves a scalar equation,
+    /// which is solved using Newton's method safeguarded by bisection; the
+    /// result is compared to the projection on $\\{(x, 0, z) {}:{} x \leq 0, z \geq 0\\}$
+    /// and the closest point to $v_0$ is returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the exponential cone,
+    ///   (out) projection on the exponential cone
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to 3.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == 3, "x must be of dimension 3");
+        if is_in_exp_cone(x) {
+            return;
+        }
+        if is_in_exp_polar_cone(x) {
+            x.iter_mut().for_each(|xi| *xi = 0.0);
+            return;
+        }
+        if x[0] <= 0.0 && x[1] <= 0.0 {
+            x[1] = 0.0;
+            x[2] = f64::max(x[2], 0.0);
+            return;
+        }
+
+        // Projection on {(x, 0, z) : x <= 0, z >= 0}; this is a (feasible)
+        // candidate in case the projection on the smooth boundary fails
+        let mut best = [f64::min(x[0], 0.0), 0.0, f64::max(x[2], 0.0)];
+        let mut update_best = |candidate: [f64; 3]| {
+            if dist_squared(&candidate, x) < dist_squared(&best, x) {
+                best = candidate;
+            }
+        };
+        // If y0 > 0, moving z0 up to the boundary yields another feasible
+        // candidate; this is accurate when rho = x0/y0 is very negative
+        if x[1] > 0.0 {
+            update_best([x[0], x[1], f64::max(x[2], x[1] * (x[0] / x[1]).exp())]);
+        }
+        if let Some(candidate) = ExponentialCone::project_on_smooth_boundary(x) {
+            update_best(candidate);
+        }
+        x.copy_from_slice(&best);
+    }
+
+    /// The exponential cone is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        is_in_exp_cone(x) || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(3)
+    }
+}
@@ -1,14 +1,25 @@
-use super::Constraint;
+use super::kd_tree::KdTree;
+use super::{Constraint, ConvexHull};
+use crate::ConstructionError;
+
+/// Data of a finite set, which is either borrowed or owned, or is stored in
+/// a kd-tree
+#[derive(Clone)]
+enum FiniteSetData<'a> {
+    Borrowed(&'a [&'a [f64]]),
+    Owned(Vec<Vec<f64>>),
+    KdTree(KdTree),
+}
 
 ///
 /// A finite set, $X = \\{x_1, x_2, \ldots, x_n\\}\subseteq\mathbb{R}^n$, given vectors
 /// $x_i\in\mathbb{R}^n$
 ///
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub struct FiniteSet<'a> {
     /// The data is stored in a Vec-of-Vec datatype, that is, a vector
-    /// of vectors
-    data: &'a [&'a [f64]],
+    /// of vectors (either borrowed or owned)
+    data: FiniteSetData<'a>,
 }
 
 impl<'a> FiniteSet<'a> {
@@ -48,7 +59,129 @@ impl<'a> FiniteSet<'a> {
         for v in data.iter() {
             assert!(n == v.len(), "inconsistent dimensions");
         }
-        FiniteSet { data }
+        FiniteSet {
+            data: FiniteSetData::Borrowed(data),
+        }
+    }
+
+    /// Construct a finite set, or return an error if the data is invalid
+    /// (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `data` is empty
+    /// - `ConstructionError::DimensionMismatch` if the given vectors have
+    ///   unequal dimensions
+    ///
+    pub fn try_new(data: &'a [&'a [f64]]) -> Result<Self, ConstructionError> {
+        FiniteSet::check_data(data.iter().map(|v| v.len()))?;
+        Ok(FiniteSet::new(data))
+    }
+
+    /// Construct a finite set which owns its data, or return an error if the
+    /// data is invalid (see `try_new`)
+    pub fn try_new_owned(data: Vec<Vec<f64>>) -> Result<Self, ConstructionError> {
+        FiniteSet::check_data(data.iter().map(|v| v.len()))?;
+        Ok(FiniteSet::new_owned(data))
+    }
+
+    /// Checks that there is at least one vector and that all vectors have the
+    /// same dimension, given their dimensions
+    fn check_data(mut dimensions: impl Iterator<Item = usize>) -> Result<(), ConstructionError> {
+        let n = dimensions.next().ok_or(ConstructionError::EmptyData)?;
+        match dimensions.find(|&ni| ni != n) {
+            Some(ni) => Err(ConstructionError::DimensionMismatch {
+                expected: n,
+                found: ni,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Construct a finite set which owns its data (see `new`)
+    pub fn new_owned(data: Vec<Vec<f64>>) -> Self {
+        assert!(!data.is_empty(), "empty data not allowed");
+        let n = data[0].len();
+        for v in data.iter() {
+            assert!(n == v.len(), "inconsistent dimensions");
+        }
+        FiniteSet {
+            data: FiniteSetData::Owned(data),
+        }
+    }
+
+    /// Construct a finite set which owns its data and stores it in a kd-tree
+    ///
+    /// The kd-tree is built once, upon construction, and allows to compute
+    /// projections (nearest elements) exactly in sub-linear time (for sets
+    /// with many elements of low dimension), without allocating memory. This is
+    /// useful for large sets, e.g., libraries of motion primitives.
+    ///
+    /// The elements of the set are reordered; in case of ties, the projection
+    /// is any of the closest elements.
+    ///
+    /// # Arguments
+    ///
+    /// - data: vector of vectors (see `new_owned`)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, FiniteSet};
+    ///
+    /// let data = (0..1000)
+    ///     .map(|i| vec![(i % 10) as f64, (i / 10) as f64])
+    ///     .collect();
+    /// let finite_set = FiniteSet::new_with_kd_tree(data);
+    /// let mut x = [3.2, 41.7];
+    /// finite_set.project(&mut x);
+    /// assert_eq!([3.0, 42.0], x);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if (i) the given vector of data is empty
+    /// and (ii) if the given vectors have unequal dimensions.
+    ///
+    pub fn new_with_kd_tree(data: Vec<Vec<f64>>) -> Self {
+        assert!(!data.is_empty(), "empty data not allowed");
+        let n = data[0].len();
+        for v in data.iter() {
+            assert!(n == v.len(), "inconsistent dimensions");
+        }
+        FiniteSet {
+            data: FiniteSetData::KdTree(KdTree::new(data)),
+        }
+    }
+
+    /// Construct a finite set which stores its data in a kd-tree, or return an
+    /// error if the data is invalid (see `try_new` and `new_with_kd_tree`)
+    pub fn try_new_with_kd_tree(data: Vec<Vec<f64>>) -> Result<Self, ConstructionError> {
+        FiniteSet::check_data(data.iter().map(|v| v.len()))?;
+        Ok(FiniteSet::new_with_kd_tree(data))
+    }
+
+    /// The convex hull of the set (see `ConvexHull`)
+    pub fn convex_hull(self) -> ConvexHull<'a> {
+        ConvexHull::from_finite_set(self)
+    }
+
+    /// Number of elements of the set
+    pub(super) fn num_elements(&self) -> usize {
+        match &self.data {
+            FiniteSetData::Borrowed(data) => data.len(),
+            FiniteSetData::Owned(data) => data.len(),
+            FiniteSetData::KdTree(kd_tree) => kd_tree.len(),
+        }
+    }
+
+    /// The `i`-th element of the set
+    pub(super) fn element(&self, i: usize) -> &[f64] {
+        match &self.data {
+            FiniteSetData::Borrowed(data) => data[i],
+            FiniteSetData::Owned(data) => &data[i],
+            FiniteSetData::KdTree(kd_tree) => kd_tree.point(i),
+        }
     }
 }
 
@@ -58,7 +191,8 @@ impl<'a> Constraint for FiniteSet<'a> {
     ///
     /// Traverses the elements of the vector, computes norm-2 distances
     /// to each element, and updates the given vector `x` with the closest
-    /// element from the finite set.
+    /// element from the finite set. If the set is stored in a kd-tree (see
+    /// `new_with_kd_tree`), the closest element is found by searching the tree.
     ///
     ///
     /// # Parameters
@@ -85,19 +219,37 @@ impl<'a> Constraint for FiniteSet<'a> {
     /// Does not panic
     ///
     fn project(&self, x: &mut [f64]) {
+        if let FiniteSetData::KdTree(kd_tree) = &self.data {
+            let (idx, _) = kd_tree.nearest(x);
+            x.copy_from_slice(kd_tree.point(idx));
+            return;
+        }
         let mut idx: usize = 0;
         let mut best_distance: f64 = num::Float::infinity();
-        for (i, v) in self.data.iter().enumerate() {
-            let dist = crate::matrix_operations::norm2_squared_diff(v, x);
+        for i in 0..self.num_elements() {
+            let dist = crate::matrix_operations::norm2_squared_diff(self.element(i), x);
             if dist < best_distance {
                 idx = i;
                 best_distance = dist;
             }
         }
-        x.copy_from_slice(self.data[idx]);
+        x.copy_from_slice(self.element(idx));
     }
 
     fn is_convex(&self) -> bool {
-        self.data.len() == 1 && !self.data[0].is_empty()
+        self.num_elements() == 1 && !self.element(0).is_empty()
+    }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        if let FiniteSetData::KdTree(kd_tree) = &self.data {
+            return kd_tree.nearest(x).1;
+        }
+        (0..self.num_elements())
+            .map(|i| crate::matrix_operations::norm2_squared_diff(self.element(i), x))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.element(0).len())
     }
 }
@@ -1,11 +1,13 @@
-use super::Constraint;
-use crate::matrix_operations;
+use super::active_set::multiple_of;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::{matrix_operations, ConstructionError};
+use std::borrow::Cow;
 
 #[derive(Clone)]
 /// A halfspace is a set given by $H = \\{x \in \mathbb{R}^n {}:{} \langle c, x\rangle \leq b\\}$.
 pub struct Halfspace<'a> {
     /// normal vector
-    normal_vector: &'a [f64],
+    normal_vector: Cow<'a, [f64]>,
     /// offset
     offset: f64,
     /// squared Euclidean norm of the normal vector (computed once upon construction)
@@ -30,8 +32,7 @@ impl<'a> Halfspace<'a> {
     ///
     /// # Panics
     ///
-    /// Does not panic. Note: it does not panic if you provide an empty slice as `normal_vector`,
-    /// but you should avoid doing that.
+    /// The method panics if `normal_vector` is empty or zero
     ///
     /// # Example
     ///
@@ -47,12 +48,95 @@ impl<'a> Halfspace<'a> {
     ///
     pub fn new(normal_vector: &'a [f64], offset: f64) -> Self {
         let normal_vector_squared_norm = matrix_operations::norm2_squared(normal_vector);
+        assert!(
+            normal_vector_squared_norm > 0.0,
+            "normal_vector must be nonempty and nonzero"
+        );
         Halfspace {
-            normal_vector,
+            normal_vector: Cow::Borrowed(normal_vector),
             offset,
             normal_vector_squared_norm,
         }
     }
+
+    /// Constructs a new instance of `Halfspace` which owns its normal vector (see `new`)
+    pub fn new_owned(normal_vector: Vec<f64>, offset: f64) -> Self {
+        let normal_vector_squared_norm = matrix_operations::norm2_squared(&normal_vector);
+        assert!(
+            normal_vector_squared_norm > 0.0,
+            "normal_vector must be nonempty and nonzero"
+        );
+        Halfspace {
+            normal_vector: Cow::Owned(normal_vector),
+            offset,
+            normal_vector_squared_norm,
+        }
+    }
+
+    /// Construct a new instance of `Halfspace`, or return an error if the normal
+    /// vector is empty or zero
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `normal_vector` is empty
+    /// - `ConstructionError::InvalidParameter` if `normal_vector` is zero
+    ///
+    pub fn try_new(normal_vector: &'a [f64], offset: f64) -> Result<Self, ConstructionError> {
+        Halfspace::check_normal_vector(normal_vector)?;
+        Ok(Halfspace::new(normal_vector, offset))
+    }
+
+    /// Checks that the normal vector is nonempty and nonzero
+    fn check_normal_vector(normal_vector: &[f64]) -> Result<(), ConstructionError> {
+        if normal_vector.is_empty() {
+            Err(ConstructionError::EmptyData)
+        } else if normal_vector.iter().all(|&ci| ci == 0.0) {
+            Err(ConstructionError::InvalidParameter {
+                name: "normal_vector",
+                value: 0.0,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Update the normal vector of the halfspace, $c$
+    ///
+    /// The given vector is copied into the data of the halfspace, so, if the
+    /// halfspace owns its normal vector (see `new_owned`), no memory is allocated.
+    /// A borrowed normal vector is replaced by an owned copy upon the first update.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `normal_vector` does not have the same dimension
+    /// as the current normal vector
+    ///
+    pub fn set_normal_vector(&mut self, normal_vector: &[f64]) {
+        assert!(
+            self.normal_vector.len() == normal_vector.len(),
+            "normal_vector has wrong dimension"
+        );
+        self.normal_vector.to_mut().copy_from_slice(normal_vector);
+        self.normal_vector_squared_norm = matrix_operations::norm2_squared(normal_vector);
+    }
+
+    /// Update the offset of the halfspace, $b$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, Halfspace};
+    ///
+    /// let mut halfspace = Halfspace::new_owned(vec![1.0, 1.0], 1.0);
+    /// halfspace.set_offset(0.0);
+    /// let mut x = [1.0, 1.0];
+    /// halfspace.project(&mut x);
+    /// assert_eq!([0.0, 0.0], x);
+    /// ```
+    ///
+    pub fn set_offset(&mut self, offset: f64) {
+        self.offset = offset;
+    }
 }
 
 impl<'a> Constraint for Halfspace<'a> {
@@ -80,7 +164,7 @@ impl<'a> Constraint for Halfspace<'a> {
     /// of the halfspace.
     ///
     fn project(&self, x: &mut [f64]) {
-        let inner_product = matrix_operations::inner_product(x, self.normal_vector);
+        let inner_product = matrix_operations::inner_product(x, &self.normal_vector);
         if inner_product > self.offset {
             let factor = (inner_product - self.offset) / self.normal_vector_squared_norm;
             x.iter_mut()
@@ -97,4 +181,51 @@ impl<'a> Constraint for Halfspace<'a> {
     fn is_convex(&self) -> bool {
         true
     }
+
+    /// Distance from the halfspace, $\max\\{0, \langle c, x\rangle - b\\}/\Vert{}c{}\Vert$ (squared)
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let violation =
+            (matrix_operations::inner_product(x, &self.normal_vector) - self.offset).max(0.0);
+        violation * violation / self.normal_vector_squared_norm
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.normal_vector.len())
+    }
+}
+
+impl<'a> ActiveSet for Halfspace<'a> {
+    /// The constraint is active if the distance of $x$ from the hyperplane
+    /// $\langle c, x\rangle = b$ is at most $\epsilon$
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let violation = matrix_operations::inner_product(x, &self.normal_vector) - self.offset;
+        if violation >= -tol * self.normal_vector_squared_norm.sqrt() {
+            vec![ActiveConstraint::Boundary]
+        } else {
+            vec![]
+        }
+    }
+
+    /// The support function is $\sigma(y) = \lambda b$ if $y = \lambda c$ for
+    /// some $\lambda \geq 0$, and $+\infty$ otherwise
+    fn support_function(&self, y: &[f64]) -> f64 {
+        match multiple_of(y, &self.normal_vector, self.normal_vector_squared_norm) {
+            Some(lambda) if lambda >= 0.0 => lambda * self.offset,
+            _ => f64::INFINITY,
+        }
+    }
+
+    /// If the constraint is active, the normal cone is the ray generated by $c$;
+    /// otherwise, it is $\\{0\\}$
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        let factor = if self.active_constraints(x, tol).is_empty() {
+            0.0
+        } else {
+            matrix_operations::inner_product(y, &self.normal_vector).max(0.0)
+                / self.normal_vector_squared_norm
+        };
+        y.iter_mut()
+            .zip(self.normal_vector.iter())
+            .for_each(|(yi, ci)| *yi = factor * ci);
+    }
 }
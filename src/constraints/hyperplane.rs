@@ -1,11 +1,13 @@
-use super::Constraint;
-use crate::matrix_operations;
+use super::active_set::multiple_of;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::{matrix_operations, ConstructionError};
+use std::borrow::Cow;
 
 #[derive(Clone)]
 /// A hyperplane is a set given by $H = \\{x \in \mathbb{R}^n {}:{} \langle c, x\rangle = b\\}$.
 pub struct Hyperplane<'a> {
     /// normal vector
-    normal_vector: &'a [f64],
+    normal_vector: Cow<'a, [f64]>,
     /// offset
     offset: f64,
     /// squared Euclidean norm of the normal vector (computed once upon construction)
@@ -30,8 +32,7 @@ impl<'a> Hyperplane<'a> {
     ///
     /// # Panics
     ///
-    /// Does not panic. Note: it does not panic if you provide an empty slice as `normal_vector`,
-    /// but you should avoid doing that.
+    /// The method panics if `normal_vector` is empty or zero
     ///
     /// # Example
     ///
@@ -47,12 +48,57 @@ impl<'a> Hyperplane<'a> {
     ///
     pub fn new(normal_vector: &'a [f64], offset: f64) -> Self {
         let normal_vector_squared_norm = matrix_operations::norm2_squared(normal_vector);
+        assert!(
+            normal_vector_squared_norm > 0.0,
+            "normal_vector must be nonempty and nonzero"
+        );
         Hyperplane {
-            normal_vector,
+            normal_vector: Cow::Borrowed(normal_vector),
             offset,
             normal_vector_squared_norm,
         }
     }
+
+    /// Constructs a new instance of `Hyperplane` which owns its normal vector (see `new`)
+    pub fn new_owned(normal_vector: Vec<f64>, offset: f64) -> Self {
+        let normal_vector_squared_norm = matrix_operations::norm2_squared(&normal_vector);
+        assert!(
+            normal_vector_squared_norm > 0.0,
+            "normal_vector must be nonempty and nonzero"
+        );
+        Hyperplane {
+            normal_vector: Cow::Owned(normal_vector),
+            offset,
+            normal_vector_squared_norm,
+        }
+    }
+
+    /// Construct a new instance of `Hyperplane`, or return an error if the normal
+    /// vector is empty or zero
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `normal_vector` is empty
+    /// - `ConstructionError::InvalidParameter` if `normal_vector` is zero
+    ///
+    pub fn try_new(normal_vector: &'a [f64], offset: f64) -> Result<Self, ConstructionError> {
+        Hyperplane::check_normal_vector(normal_vector)?;
+        Ok(Hyperplane::new(normal_vector, offset))
+    }
+
+    /// Checks that the normal vector is nonempty and nonzero
+    fn check_normal_vector(normal_vector: &[f64]) -> Result<(), ConstructionError> {
+        if normal_vector.is_empty() {
+            Err(ConstructionError::EmptyData)
+        } else if normal_vector.iter().all(|&ci| ci == 0.0) {
+            Err(ConstructionError::InvalidParameter {
+                name: "normal_vector",
+                value: 0.0,
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<'a> Constraint for Hyperplane<'a> {
@@ -77,7 +123,7 @@ impl<'a> Constraint for Hyperplane<'a> {
     /// of the hyperplane.
     ///
     fn project(&self, x: &mut [f64]) {
-        let inner_product = matrix_operations::inner_product(x, self.normal_vector);
+        let inner_product = matrix_operations::inner_product(x, &self.normal_vector);
         let factor = (inner_product - self.offset) / self.normal_vector_squared_norm;
         x.iter_mut()
             .zip(self.normal_vector.iter())
@@ -92,4 +138,39 @@ impl<'a> Constraint for Hyperplane<'a> {
     fn is_convex(&self) -> bool {
         true
     }
+
+    /// Distance from the hyperplane, $|\langle c, x\rangle - b|/\Vert{}c{}\Vert$ (squared)
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let violation = matrix_operations::inner_product(x, &self.normal_vector) - self.offset;
+        violation * violation / self.normal_vector_squared_norm
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.normal_vector.len())
+    }
+}
+
+impl<'a> ActiveSet for Hyperplane<'a> {
+    /// The (equality) constraint is always active
+    fn active_constraints(&self, _x: &[f64], _tol: f64) -> Vec<ActiveConstraint> {
+        vec![ActiveConstraint::Equality(0)]
+    }
+
+    /// The support function is $\sigma(y) = \lambda b$ if $y = \lambda c$,
+    /// and $+\infty$ otherwise
+    fn support_function(&self, y: &[f64]) -> f64 {
+        match multiple_of(y, &self.normal_vector, self.normal_vector_squared_norm) {
+            Some(lambda) => lambda * self.offset,
+            None => f64::INFINITY,
+        }
+    }
+
+    /// The normal cone is the line spanned by $c$
+    fn project_on_normal_cone(&self, _x: &[f64], y: &mut [f64], _tol: f64) {
+        let factor = matrix_operations::inner_product(y, &self.normal_vector)
+            / self.normal_vector_squared_norm;
+        y.iter_mut()
+            .zip(self.normal_vector.iter())
+            .for_each(|(yi, ci)| *yi = factor * ci);
+    }
 }
@@ -0,0 +1,265 @@
+use super::Constraint;
+use crate::{matrix_operations, ConstructionError};
+use std::cell::RefCell;
+
+/// Default maximum number of iterations of Dykstra's algorithm
+const DEFAULT_MAX_ITERATIONS: usize = 1000;
+
+/// Default tolerance of Dykstra's algorithm
+const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+/// Workspace of Dykstra's algorithm
+struct IntersectionWorkspace {
+    /// increments of Dykstra's algorithm, one for each set (stored one after the other)
+    increments: Vec<f64>,
+    /// previous iterate
+    x_previous: Vec<f64>,
+    /// point to be projected on each set, $y = x + p_i$
+    y: Vec<f64>,
+}
+
+/// Intersection of constraints
+///
+/// Intersection of closed convex sets, $C_0, C_1, \ldots, C_{k-1}$, of the same
+/// dimension, that is,
+///
+/// $$
+/// C = C_0 \cap C_1 \cap \ldots \cap C_{k-1}.
+/// $$
+///
+/// Sets $C_i$ are structures which implement the trait `Constraint`.
+///
+/// Projections on $C$ are computed using Dykstra's alternating projections
+/// algorithm, which only requires the projections on the individual sets $C_i$.
+/// The algorithm terminates when the infinity norms of the differences of two
+/// successive iterates and of two successive values of the increments of the
+/// algorithm do not exceed a given tolerance, or when the maximum number of
+/// iterations is reached.
+///
+/// The memory that Dykstra's algorithm needs is allocated when the sets are
+/// added to the intersection. This preallocated workspace is stored in a `RefCell`,
+/// so `Intersection` is not `Sync` (neither are the boxed sets it contains); to
+/// project in parallel, construct one intersection per thread.
+///
+pub struct Intersection<'a> {
+    dimension: usize,
+    constraints: Vec<Box<dyn Constraint + 'a>>,
+    max_iterations: usize,
+    tolerance: f64,
+    workspace: RefCell<IntersectionWorkspace>,
+}
+
+impl<'a> Intersection<'a> {
+    /// Construct a new (empty) intersection of sets of given dimension
+    ///
+    /// # Arguments
+    ///
+    /// - `dimension`: dimension of the sets
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // Intersection of the Euclidean unit ball with the halfspace x[0] + x[1] <= 0.5
+    /// let normal_vector = [1.0, 1.0];
+    /// let intersection = Intersection::new(2)
+    ///     .add_constraint(Ball2::new(None, 1.0))
+    ///     .add_constraint(Halfspace::new(&normal_vector, 0.5))
+    ///     .with_max_iterations(500)
+    ///     .with_tolerance(1e-8);
+    /// let mut x = [2.0, 1.5];
+    /// intersection.project(&mut x);
+    /// ```
+    ///
+    pub fn new(dimension: usize) -> Self {
+        Intersection {
+            dimension,
+            constraints: Vec::new(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            tolerance: DEFAULT_TOLERANCE,
+            workspace: RefCell::new(IntersectionWorkspace {
+                increments: Vec::new(),
+                x_previous: vec![0.0; dimension],
+                y: vec![0.0; dimension],
+            }),
+        }
+    }
+
+    /// Add a set to the intersection
+    ///
+    /// # Arguments
+    ///
+    /// - `constraint`: set to be added; it must have the same dimension as
+    ///   the intersection
+    ///
+    /// # Returns
+    ///
+    /// Returns the current updated instance of the provided object
+    ///
+    pub fn add_constraint(mut self, constraint: impl Constraint + 'a) -> Self {
+        self.constraints.push(Box::new(constraint));
+        self.workspace
+            .get_mut()
+            .increments
+            .resize(self.constraints.len() * self.dimension, 0.0);
+        self
+    }
+
+    /// Add a set to the intersection, or return an error if its dimension is
+    /// incompatible with the dimension of the intersection (see `add_constraint`)
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::DimensionMismatch` if the dimension of `constraint`
+    /// (see `Constraint::dimension`) is known and it is not equal to the
+    /// dimension of the intersection
+    ///
+    pub fn try_add_constraint(
+        self,
+        constraint: impl Constraint + 'a,
+    ) -> Result<Self, ConstructionError> {
+        match constraint.dimension() {
+            Some(found) if found != self.dimension => Err(ConstructionError::DimensionMismatch {
+                expected: self.dimension,
+                found,
+            }),
+            _ => Ok(self.add_constraint(constraint)),
+        }
+    }
+
+    /// Set the maximum number of iterations of Dykstra's algorithm
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `max_iterations` is zero
+    ///
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        assert!(max_iterations > 0, "max_iterations must be positive");
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the maximum number of iterations of Dykstra's algorithm, or return
+    /// an error if it is zero
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `max_iterations` is zero
+    ///
+    pub fn try_with_max_iterations(self, max_iterations: usize) -> Result<Self, ConstructionError> {
+        if max_iterations > 0 {
+            Ok(self.with_max_iterations(max_iterations))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "max_iterations",
+                value: 0.0,
+            })
+        }
+    }
+
+    /// Set the tolerance of Dykstra's algorithm
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `tolerance` is not positive
+    ///
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the tolerance of Dykstra's algorithm, or return an error if it is
+    /// not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `tolerance` is not positive
+    ///
+    pub fn try_with_tolerance(self, tolerance: f64) -> Result<Self, ConstructionError> {
+        if tolerance > 0.0 {
+            Ok(self.with_tolerance(tolerance))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "tolerance",
+                value: tolerance,
+            })
+        }
+    }
+}
+
+impl<'a> Constraint for Intersection<'a> {
+    /// Project onto the intersection of sets using Dykstra's algorithm
+    ///
+    /// Starting from $x^0 = v$ and $p_i^0 = 0$, each iteration of the algorithm
+    /// updates, for $i = 0, \ldots, k-1$,
+    ///
+    /// $$
+    /// y = x + p_i,\quad x \leftarrow \Pi_{C_i}(y),\quad p_i \leftarrow y - x.
+    /// $$
+    ///
+    /// The given vector `x` is updated with the (approximate) projection on the set.
+    /// If there is a single set, its projection is used directly.
+    ///
+    /// # Panics
+    ///
+    /// The method will panic if the dimension of `x` is not equal to the
+    /// dimension of the intersection, or if any of the associated projections panics.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.dimension, "x has wrong size");
+        if self.constraints.len() == 1 {
+            self.constraints[0].project(x);
+            return;
+        }
+        let n = self.dimension;
+        let workspace = &mut *self.workspace.borrow_mut();
+        workspace.increments.iter_mut().for_each(|p| *p = 0.0);
+        for _ in 0..self.max_iterations {
+            workspace.x_previous.copy_from_slice(x);
+            let mut increment_change: f64 = 0.0;
+            for (c, p) in self
+                .constraints
+                .iter()
+                .zip(workspace.increments.chunks_exact_mut(n))
+            {
+                // y = x + p, x = proj(y), p = y - x
+                workspace
+                    .y
+                    .iter_mut()
+                    .zip(x.iter().zip(p.iter()))
+                    .for_each(|(yi, (xi, pi))| *yi = xi + pi);
+                x.copy_from_slice(&workspace.y);
+                c.project(x);
+                p.iter_mut()
+                    .zip(workspace.y.iter().zip(x.iter()))
+                    .for_each(|(pi, (yi, xi))| {
+                        let pi_new = yi - xi;
+                        increment_change = increment_change.max((pi_new - *pi).abs());
+                        *pi = pi_new;
+                    });
+            }
+            if increment_change <= self.tolerance
+                && matrix_operations::norm_inf_diff(x, &workspace.x_previous) <= self.tolerance
+            {
+                break;
+            }
+        }
+    }
+
+    fn is_convex(&self) -> bool {
+        self.constraints.iter().all(|c| c.is_convex())
+    }
+
+    /// Checks whether `x` is in all sets $C_i$ (without tolerance), which does
+    /// not require Dykstra's algorithm; otherwise, whether $\mathrm{dist}_C(x) \leq \epsilon$
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        assert!(x.len() == self.dimension, "x has wrong size");
+        self.constraints.iter().all(|c| c.contains(x, 0.0)) || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.dimension)
+    }
+}
@@ -0,0 +1,120 @@
+/// A kd-tree for exact nearest-neighbour queries on a finite set of points
+///
+/// The tree is balanced and is stored implicitly: the points are reordered so
+/// that the root of the subtree which contains the points at positions
+/// `lo..hi` is the (median) point at position `lo + (hi - lo) / 2`; the points
+/// on its left have a smaller (or equal) coordinate along the splitting
+/// dimension of the node, and the points on its right a larger (or equal) one.
+/// The splitting dimension of each node is the one in which the points of the
+/// subtree have the largest spread.
+///
+/// The points are stored one after the other, in tree order, so that queries
+/// do not allocate memory and access the data in a cache-friendly way.
+#[derive(Clone)]
+pub(super) struct KdTree {
+    /// dimension of the points
+    dim: usize,
+    /// coordinates of the points (in tree order)
+    points: Vec<f64>,
+    /// splitting dimension of each node (in tree order)
+    split_dims: Vec<usize>,
+}
+
+impl KdTree {
+    /// Builds a kd-tree of the given points, which must have the same dimension
+    /// (this takes $O(N \log^2 N)$ operations for $N$ points)
+    pub(super) fn new(data: Vec<Vec<f64>>) -> Self {
+        let dim = data[0].len();
+        let mut order: Vec<usize> = (0..data.len()).collect();
+        let mut split_dims = vec![0; data.len()];
+        KdTree::build(&data, &mut order, &mut split_dims);
+        let points = order
+            .iter()
+            .flat_map(|&i| data[i].iter().copied())
+            .collect();
+        KdTree {
+            dim,
+            points,
+            split_dims,
+        }
+    }
+
+    /// Orders the given (indices of) points so that they form a subtree
+    fn build(data: &[Vec<f64>], order: &mut [usize], split_dims: &mut [usize]) {
+        if order.len() <= 1 {
+            return;
+        }
+        // splitting dimension: the one with the largest spread
+        let split_dim = (0..data[0].len())
+            .map(|k| {
+                let (min, max) = order
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &i| {
+                        (min.min(data[i][k]), max.max(data[i][k]))
+                    });
+                max - min
+            })
+            .enumerate()
+            .fold((0, f64::NEG_INFINITY), |best, (k, spread)| {
+                if spread > best.1 {
+                    (k, spread)
+                } else {
+                    best
+                }
+            })
+            .0;
+        let mid = order.len() / 2;
+        order.select_nth_unstable_by(mid, |&i, &j| {
+            data[i][split_dim].total_cmp(&data[j][split_dim])
+        });
+        split_dims[mid] = split_dim;
+        let (order_left, order_right) = order.split_at_mut(mid);
+        let (split_dims_left, split_dims_right) = split_dims.split_at_mut(mid);
+        KdTree::build(data, order_left, split_dims_left);
+        KdTree::build(data, &mut order_right[1..], &mut split_dims_right[1..]);
+    }
+
+    /// Number of points
+    pub(super) fn len(&self) -> usize {
+        self.split_dims.len()
+    }
+
+    /// The point at position `i` (in tree order)
+    pub(super) fn point(&self, i: usize) -> &[f64] {
+        &self.points[i * self.dim..(i + 1) * self.dim]
+    }
+
+    /// Position of the point which is closest to `x` and the squared distance
+    /// of `x` from it
+    pub(super) fn nearest(&self, x: &[f64]) -> (usize, f64) {
+        let mut best = (0, f64::INFINITY);
+        self.search(x, 0, self.len(), &mut best);
+        best
+    }
+
+    /// Searches the subtree of the points at positions `lo..hi`, visiting first
+    /// the side of the splitting plane on which `x` lies, and the other side
+    /// only if the plane is closer to `x` than the best point found so far
+    fn search(&self, x: &[f64], lo: usize, hi: usize, best: &mut (usize, f64)) {
+        if lo >= hi {
+            return;
+        }
+        let mid = lo + (hi - lo) / 2;
+        let point = self.point(mid);
+        let distance = crate::matrix_operations::norm2_squared_diff(point, x);
+        if distance < best.1 {
+            *best = (mid, distance);
+        }
+        let split_dim = self.split_dims[mid];
+        let difference = x[split_dim] - point[split_dim];
+        let ((near_lo, near_hi), (far_lo, far_hi)) = if difference < 0.0 {
+            ((lo, mid), (mid + 1, hi))
+        } else {
+            ((mid + 1, hi), (lo, mid))
+        };
+        self.search(x, near_lo, near_hi, best);
+        if difference * difference < best.1 {
+            self.search(x, far_lo, far_hi, best);
+        }
+    }
+}
@@ -3,41 +3,117 @@
 //!
 //! This module defines the trait [`Constraint`], which specifies an abstract
 //! projection method, and a collection of simple sets, such as norm-balls,
-//! finite sets, second-order cones and their Cartesian products.
+//! finite sets, polyhedra, (rotated) second-order cones, cones of positive semidefinite matrices
+//! and their Cartesian products, intersections and unions.
 //!
+//! # Owned and borrowed data
+//!
+//! Sets which are defined by vectors (e.g., the center of a ball or the bounds of
+//! a rectangle) borrow them when they are constructed with `new`. Many of them can
+//! also be constructed with `new_owned`, which takes ownership of the data instead;
+//! this is useful when the data is only known at runtime (e.g., it is loaded from a
+//! configuration file), or when the set needs to be moved into a long-lived structure.
+//!
+//! ```
+//! use optimization_engine::constraints::{Ball2, Constraint};
+//!
+//! let center = vec![1.0, 1.0];
+//! let ball: Ball2<'static> = Ball2::new_owned(Some(center), 0.5);
+//! let mut x = [3.0, 1.0];
+//! ball.project(&mut x);
+//! assert!((x[0] - 1.5).abs() < 1e-12);
+//! ```
 //!
 //! [`Constraint`]: trait.Constraint.html
 
+mod active_set;
 mod affine_space;
 mod ball1;
 mod ball2;
+mod ball2_exterior;
 mod ballinf;
+mod bounded_monotone;
+mod capped_simplex;
+mod cardinality;
 mod cartesian_product;
+#[cfg(feature = "serde")]
+mod constraint_spec;
+mod convex_hull;
+mod ellipsoid;
+mod epigraph_norm_inf;
+mod epigraph_norm_l1;
+mod epigraph_squared_norm;
+mod exponential_cone;
 mod finite;
 mod halfspace;
 mod hyperplane;
+mod intersection;
+mod kd_tree;
+mod monotone_cone;
 mod no_constraints;
+mod permuted;
+mod polyhedron;
+mod power_cone;
+mod psd_cone;
 mod rectangle;
+mod rectangle_exterior;
+mod rotated_soc;
+mod scaled;
 mod simplex;
 mod soc;
+mod sparse_ldl;
+mod special_orthogonal;
 mod sphere2;
+mod stiefel;
+mod translated;
+mod union;
+mod weighted_ball1;
 mod zero;
 
+pub use active_set::{ActiveConstraint, ActiveSet};
 pub use affine_space::AffineSpace;
 pub use ball1::Ball1;
 pub use ball2::Ball2;
+pub use ball2_exterior::Ball2Exterior;
 pub use ballinf::BallInf;
+pub use bounded_monotone::BoundedMonotone;
+pub use capped_simplex::CappedSimplex;
+pub use cardinality::Cardinality;
 pub use cartesian_product::CartesianProduct;
+#[cfg(feature = "serde")]
+pub use constraint_spec::ConstraintSpec;
+pub use convex_hull::ConvexHull;
+pub use ellipsoid::Ellipsoid;
+pub use epigraph_norm_inf::EpigraphNormInf;
+pub use epigraph_norm_l1::EpigraphNormL1;
+pub use epigraph_squared_norm::EpigraphSquaredNorm;
+pub use exponential_cone::ExponentialCone;
 pub use finite::FiniteSet;
 pub use halfspace::Halfspace;
 pub use hyperplane::Hyperplane;
+pub use intersection::Intersection;
+pub use monotone_cone::MonotoneCone;
 pub use no_constraints::NoConstraints;
+pub use permuted::Permuted;
+pub use polyhedron::Polyhedron;
+pub use power_cone::PowerCone;
+pub use psd_cone::{PsdCone, SymmetricStorage};
 pub use rectangle::Rectangle;
+pub use rectangle_exterior::RectangleExterior;
+pub use rotated_soc::RotatedSecondOrderCone;
+pub use scaled::Scaled;
 pub use simplex::Simplex;
 pub use soc::SecondOrderCone;
+pub use special_orthogonal::SpecialOrthogonal;
 pub use sphere2::Sphere2;
+pub use stiefel::Stiefel;
+pub use translated::Translated;
+pub use union::Union;
+pub use weighted_ball1::WeightedBall1;
 pub use zero::Zero;
 
+pub(crate) use simplex::simplex_threshold;
+
 /// A set which can be used as a constraint
 ///
 /// This trait defines an abstract function that allows to compute projections
@@ -57,6 +133,87 @@ pub trait Constraint {
 
     /// Returns true if and only if the set is convex
     fn is_convex(&self) -> bool;
+
+    /// Squared Euclidean distance of `x` from the set, that is,
+    ///
+    /// $$
+    /// \mathrm{dist}_C^2(x) = \Vert{}x - \Pi_C(x){}\Vert^2
+    /// $$
+    ///
+    /// The default implementation copies `x` and projects it on the set; the
+    /// sets of this module for which this can be done more efficiently (e.g.,
+    /// without allocating memory) override it.
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: the given vector
+    ///
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let mut projection = x.to_vec();
+        self.project(&mut projection);
+        crate::matrix_operations::norm2_squared_diff(x, &projection)
+    }
+
+    /// Euclidean distance of `x` from the set, $\mathrm{dist}_C(x)$
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: the given vector
+    ///
+    fn distance(&self, x: &[f64]) -> f64 {
+        self.distance_squared(x).sqrt()
+    }
+
+    /// Returns true if and only if `x` is in the set up to a tolerance, that is,
+    /// if $\mathrm{dist}_C(x) \leq \epsilon$
+    ///
+    /// Sets which are described by equalities and inequalities (e.g.,
+    /// polyhedra and affine spaces) may instead check whether the violation
+    /// of these constraints is at most $\epsilon$, which does not require a
+    /// projection.
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: the given vector
+    /// - `tol`: tolerance, $\epsilon$
+    ///
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        self.distance(x) <= tol
+    }
+
+    /// Dimension of the set, if it is fixed upon construction, or `None` if
+    /// the set can be used with vectors of any dimension
+    fn dimension(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A boxed constraint (e.g., a `Box<dyn Constraint>`) is a constraint, so that
+/// it can be used in `CartesianProduct`, `Translated`, etc
+impl<C: Constraint + ?Sized> Constraint for Box<C> {
+    fn project(&self, x: &mut [f64]) {
+        (**self).project(x)
+    }
+
+    fn is_convex(&self) -> bool {
+        (**self).is_convex()
+    }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        (**self).distance_squared(x)
+    }
+
+    fn distance(&self, x: &[f64]) -> f64 {
+        (**self).distance(x)
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        (**self).contains(x, tol)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        (**self).dimension()
+    }
 }
 
 /* ---------------------------------------------------------------------------- */
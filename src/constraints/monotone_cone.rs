@@ -0,0 +1,110 @@
+use super::Constraint;
+
+#[derive(Clone, Copy, Default)]
+///
+/// The monotone cone
+///
+/// The set of vectors with nondecreasing elements, that is,
+///
+/// $$
+/// K = \\{x \in \mathbb{R}^n {}:{} x_1 \leq x_2 \leq \ldots \leq x_n\\}.
+/// $$
+///
+/// Projections on the monotone cone (isotonic regression) are computed with
+/// the pool-adjacent-violators algorithm in $O(n)$ operations.
+///
+pub struct MonotoneCone {}
+
+impl MonotoneCone {
+    /// Construct a new instance of the monotone cone
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, MonotoneCone};
+    ///
+    /// let monotone_cone = MonotoneCone::new();
+    /// let mut x = [1.0, 3.0, 2.0, 4.0];
+    /// monotone_cone.project(&mut x);
+    /// assert_eq!([1.0, 2.5, 2.5, 4.0], x);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        MonotoneCone {}
+    }
+}
+
+/// A block of consecutive elements which share the same value
+struct Block {
+    len: usize,
+    sum: f64,
+    lower: f64,
+    upper: f64,
+    value: f64,
+}
+
+/// Pool-adjacent-violators algorithm for the projection on
+/// $\\{x {}:{} x_1 \leq \ldots \leq x_n, l_i \leq x_i \leq u_i\\}$, where the
+/// bounds, $l_i$ and $u_i$, are given by `lower` and `upper`
+///
+/// The value of each block is the projection of the average of its elements
+/// on the intersection of the intervals $[l_i, u_i]$ of its elements, which is
+/// assumed to be nonempty.
+pub(super) fn pool_adjacent_violators(
+    x: &mut [f64],
+    lower: impl Fn(usize) -> f64,
+    upper: impl Fn(usize) -> f64,
+) {
+    let mut blocks: Vec<Block> = Vec::with_capacity(x.len());
+    for (i, &xi) in x.iter().enumerate() {
+        let (lower_i, upper_i) = (lower(i), upper(i));
+        blocks.push(Block {
+            len: 1,
+            sum: xi,
+            lower: lower_i,
+            upper: upper_i,
+            value: xi.max(lower_i).min(upper_i),
+        });
+        // merge the last two blocks as long as they violate monotonicity
+        while blocks.len() >= 2 && blocks[blocks.len() - 2].value > blocks[blocks.len() - 1].value {
+            let last = blocks.pop().unwrap();
+            let previous = blocks.last_mut().unwrap();
+            previous.len += last.len;
+            previous.sum += last.sum;
+            previous.lower = previous.lower.max(last.lower);
+            previous.upper = previous.upper.min(last.upper);
+            previous.value = (previous.sum / previous.len as f64)
+                .max(previous.lower)
+                .min(previous.upper);
+        }
+    }
+    let mut start = 0;
+    for block in blocks.iter() {
+        x[start..start + block.len]
+            .iter_mut()
+            .for_each(|xi| *xi = block.value);
+        start += block.len;
+    }
+}
+
+impl Constraint for MonotoneCone {
+    /// Project on the monotone cone using the pool-adjacent-violators algorithm
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the monotone cone,
+    ///   (out) projection on the monotone cone
+    ///
+    fn project(&self, x: &mut [f64]) {
+        pool_adjacent_violators(x, |_| f64::NEG_INFINITY, |_| f64::INFINITY);
+    }
+
+    /// The monotone cone is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        x.windows(2).all(|w| w[0] <= w[1]) || self.distance(x) <= tol
+    }
+}
@@ -1,4 +1,4 @@
-use super::Constraint;
+use super::{ActiveConstraint, ActiveSet, Constraint};
 
 /// The whole space, no constraints
 #[derive(Default, Clone, Copy)]
@@ -18,4 +18,28 @@ impl Constraint for NoConstraints {
     fn is_convex(&self) -> bool {
         true
     }
+
+    fn distance_squared(&self, _x: &[f64]) -> f64 {
+        0.0
+    }
+}
+
+impl ActiveSet for NoConstraints {
+    fn active_constraints(&self, _x: &[f64], _tol: f64) -> Vec<ActiveConstraint> {
+        vec![]
+    }
+
+    /// The support function is zero at $y = 0$ and $+\infty$ otherwise
+    fn support_function(&self, y: &[f64]) -> f64 {
+        if y.iter().all(|&yi| yi == 0.0) {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// The normal cone is $\\{0\\}$
+    fn project_on_normal_cone(&self, _x: &[f64], y: &mut [f64], _tol: f64) {
+        y.iter_mut().for_each(|yi| *yi = 0.0);
+    }
 }
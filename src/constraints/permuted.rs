@@ -0,0 +1,138 @@
+use super::Constraint;
+use crate::ConstructionError;
+
+/// A permuted set
+///
+/// Given a set $C$ and a permutation matrix $P$, this is the set
+///
+/// $$
+/// \\{x \in \mathbb{R}^n {}:{} Px \in C\\}.
+/// $$
+///
+/// Since $P$ is orthogonal, the projection on this set is $P^\intercal\Pi_C(Px)$.
+/// The permutation is given as a vector of indices, $p$, so that $(Px)_i = x_{p_i}$.
+/// This can be used to impose constraints on variables which are not stored
+/// in the order the set expects (e.g., the scalar part of a second-order cone).
+///
+#[derive(Clone)]
+pub struct Permuted<C: Constraint> {
+    set: C,
+    permutation: Vec<usize>,
+}
+
+impl<C: Constraint> Permuted<C> {
+    /// Construct a new permuted set
+    ///
+    /// # Arguments
+    ///
+    /// - `set`: the set $C$
+    /// - `permutation`: the permutation, $p$, so that $(Px)_i = x_{p_i}$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `permutation` is not a permutation of $0, \ldots, n-1$,
+    /// or if the dimension of $C$ (see `Constraint::dimension`) is known and it is
+    /// not equal to $n$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // ||(x[0], x[2])|| <= x[1]
+    /// let soc = Permuted::new(SecondOrderCone::new(1.0), vec![0, 2, 1]);
+    /// let mut x = [3.0, 1.0, 4.0];
+    /// soc.project(&mut x);
+    /// assert!((x[1] - 3.0).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn new(set: C, permutation: Vec<usize>) -> Self {
+        let n = permutation.len();
+        let mut is_used = vec![false; n];
+        for &i in permutation.iter() {
+            assert!(i < n && !is_used[i], "invalid permutation");
+            is_used[i] = true;
+        }
+        assert!(
+            set.dimension().unwrap_or(n) == n,
+            "the set and the permutation have incompatible dimensions"
+        );
+        Permuted { set, permutation }
+    }
+
+    /// Construct a new permuted set, or return an error if the data is invalid
+    /// (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::InvalidParameter` if `permutation` is not a
+    ///   permutation of $0, \ldots, n-1$ (the value is the first invalid index)
+    /// - `ConstructionError::DimensionMismatch` if the dimension of $C$ is known
+    ///   and it is not equal to $n$
+    ///
+    pub fn try_new(set: C, permutation: Vec<usize>) -> Result<Self, ConstructionError> {
+        let n = permutation.len();
+        let mut is_used = vec![false; n];
+        for &i in permutation.iter() {
+            if i >= n || is_used[i] {
+                return Err(ConstructionError::InvalidParameter {
+                    name: "permutation",
+                    value: i as f64,
+                });
+            }
+            is_used[i] = true;
+        }
+        match set.dimension() {
+            Some(expected) if expected != n => {
+                Err(ConstructionError::DimensionMismatch { expected, found: n })
+            }
+            _ => Ok(Permuted::new(set, permutation)),
+        }
+    }
+
+    /// The vector $Px$
+    fn permute(&self, x: &[f64]) -> Vec<f64> {
+        assert!(x.len() == self.permutation.len(), "x has wrong dimension");
+        self.permutation.iter().map(|&pi| x[pi]).collect()
+    }
+}
+
+impl<C: Constraint> Constraint for Permuted<C> {
+    /// Projection on the permuted set
+    ///
+    /// A vector of dimension $n$ is allocated, where $Px$ is stored.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the length of
+    /// the permutation, or if the projection on $C$ panics.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.permutation.len(), "x has wrong dimension");
+        let mut y = self.permute(x);
+        self.set.project(&mut y);
+        y.iter()
+            .zip(self.permutation.iter())
+            .for_each(|(&yi, &pi)| x[pi] = yi);
+    }
+
+    /// A permuted set is convex if and only if $C$ is convex
+    fn is_convex(&self) -> bool {
+        self.set.is_convex()
+    }
+
+    /// Since $P$ is orthogonal, the distance from the permuted set is
+    /// $\mathrm{dist}_C(Px)$
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        self.set.distance_squared(&self.permute(x))
+    }
+
+    /// Checks whether $Px$ is in $C$, up to the given tolerance
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        self.set.contains(&self.permute(x), tol)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.permutation.len())
+    }
+}
@@ -0,0 +1,711 @@
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::{matrix_operations, ConstructionError};
+use std::cell::RefCell;
+
+extern crate ndarray;
+
+use ndarray::{Array2, ArrayBase, Dim, OwnedRepr};
+
+type OpenMat<T> = ArrayBase<OwnedRepr<T>, Dim<[usize; 2]>>;
+
+/// Maximum number of iterations of the dual active-set method
+const MAX_ACTIVE_SET_ITERATIONS: usize = 10_000;
+
+/// Tolerance on the violation of the constraints (relative to the norm of the
+/// projected vector)
+const FEASIBILITY_TOLERANCE: f64 = 1e-10;
+
+/// Tolerance used to decide whether a constraint is linearly dependent on
+/// the constraints of the working set
+const DEPENDENCE_TOLERANCE: f64 = 1e-12;
+
+/// Maximum number of proximal point iterations in the computation of the
+/// support function
+const MAX_SUPPORT_ITERATIONS: usize = 100;
+
+#[derive(Clone)]
+/// A polyhedron is a set given by $P = \\{x\in\mathbb{R}^n {}:{} Ax \leq b, Cx = d\\}$,
+/// where $A\in\mathbb{R}^{m\times n}$, $b\in\mathbb{R}^m$, $C\in\mathbb{R}^{p\times n}$
+/// and $d\in\mathbb{R}^p$.
+///
+/// Projections are computed exactly, in a finite number of steps, with the dual
+/// active-set method of D. Goldfarb and A. Idnani, A numerically stable dual
+/// method for solving strictly convex quadratic programs, Mathematical Programming,
+/// 27 (1983), pp. 1-33. The active set of the last projection is stored and it is
+/// used to warm-start the next one, which is beneficial when projecting vectors that
+/// are close to one another (e.g., in successive iterations of an algorithm).
+/// Since the projection is unique, the warm start only affects the number of
+/// iterations (and round-off errors), not the result. Note that, because the active
+/// set is stored in a `RefCell`, `Polyhedron` is not `Sync`; every thread should use
+/// its own clone.
+///
+/// The constructors check that the polyhedron is nonempty.
+pub struct Polyhedron {
+    /// matrix A (row-wise)
+    a_mat: Vec<f64>,
+    /// vector b
+    b_vec: Vec<f64>,
+    /// matrix C (row-wise)
+    c_mat: Vec<f64>,
+    /// vector d
+    d_vec: Vec<f64>,
+    /// dimension, n
+    n: usize,
+    /// Euclidean norms of the rows of A and C
+    row_norms: Vec<f64>,
+    /// Gram matrix of the rows of A and C, that is [A; C][A; C]'
+    gram: OpenMat<f64>,
+    /// active inequalities at the last projection (used for warm starting)
+    active_set: RefCell<Vec<usize>>,
+}
+
+/// Cholesky factor, $L$, of the Gram matrix of the rows in the working set, that is,
+/// $N_W^\intercal N_W = LL^\intercal$, which is updated (rather than recomputed) when
+/// a constraint is added to or removed from the working set
+///
+/// The rows of $L$ are stored separately, with the $i$-th row having $i+1$ elements.
+#[derive(Clone)]
+struct WorkingSetFactor {
+    rows: Vec<Vec<f64>>,
+}
+
+impl WorkingSetFactor {
+    fn new() -> Self {
+        WorkingSetFactor { rows: Vec::new() }
+    }
+
+    /// Solves $Ly = b$
+    fn forward_solve(&self, b: &[f64], y: &mut [f64]) {
+        for (i, row) in self.rows.iter().enumerate() {
+            y[i] = (b[i] - matrix_operations::inner_product(&row[..i], &y[..i])) / row[i];
+        }
+    }
+
+    /// Solves $L^\intercal x = y$ in place
+    fn backward_solve(&self, x: &mut [f64]) {
+        for i in (0..self.rows.len()).rev() {
+            x[i] /= self.rows[i][i];
+            let x_i = x[i];
+            x.iter_mut()
+                .zip(self.rows[i].iter())
+                .take(i)
+                .for_each(|(xj, l_ij)| *xj -= l_ij * x_i);
+        }
+    }
+
+    /// Solves $LL^\intercal x = b$
+    fn solve(&self, b: &[f64], x: &mut [f64]) {
+        self.forward_solve(b, x);
+        self.backward_solve(x);
+    }
+
+    /// Adds a row to the working set, given $l = L^{-1}N_W^\intercal a$ and the
+    /// squared norm of the projection of $a$ on the null space of $N_W^\intercal$,
+    /// which is equal to $\Vert a \Vert^2 - \Vert l \Vert^2$
+    fn append(&mut self, l: &[f64], norm_sq_projected: f64) {
+        let mut row = l.to_vec();
+        row.push(norm_sq_projected.sqrt());
+        self.rows.push(row);
+    }
+
+    /// Removes the `idx`-th row from the working set
+    ///
+    /// Removing the `idx`-th row of $L$ leaves a matrix whose rows below `idx`
+    /// have one nonzero element above the diagonal; these are eliminated with
+    /// Givens rotations of consecutive columns
+    fn remove(&mut self, idx: usize) {
+        self.rows.remove(idx);
+        for c in idx..self.rows.len() {
+            let (a, b) = (self.rows[c][c], self.rows[c][c + 1]);
+            let rho = a.hypot(b);
+            let (cos, sin) = (a / rho, b / rho);
+            for row in self.rows[c..].iter_mut() {
+                let (x, y) = (row[c], row[c + 1]);
+                row[c] = cos * x + sin * y;
+                row[c + 1] = cos * y - sin * x;
+            }
+            self.rows[c].pop();
+        }
+    }
+}
+
+impl Polyhedron {
+    /// Construct a new polyhedron of the form $\\{x\in\mathbb{R}^n {}:{} Ax \leq b\\}$
+    ///
+    /// Equality constraints, $Cx = d$, can be added using
+    /// [`with_equality_constraints`](#method.with_equality_constraints).
+    ///
+    /// # Arguments
+    ///
+    /// - `a`: matrix $A$, row-wise data
+    /// - `b`: vector $b$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `b` is empty, if `a` and `b` have incompatible
+    /// dimensions, or if the polyhedron is empty
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, Polyhedron};
+    ///
+    /// // x1 + x2 <= 1, x1 >= 0, x2 >= 0
+    /// let a = vec![1.0, 1.0, -1.0, 0.0, 0.0, -1.0];
+    /// let b = vec![1.0, 0.0, 0.0];
+    /// let polyhedron = Polyhedron::new(a, b);
+    /// let mut x = [2.0, 0.5];
+    /// polyhedron.project(&mut x);
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && x[1].abs() < 1e-12);
+    /// ```
+    ///
+    pub fn new(a: Vec<f64>, b: Vec<f64>) -> Self {
+        let n_rows = b.len();
+        assert!(n_rows > 0, "b must be nonempty");
+        let n = a.len() / n_rows;
+        assert!(
+            n > 0 && n * n_rows == a.len(),
+            "A and b have incompatible dimensions"
+        );
+        let polyhedron = Polyhedron::from_data(a, b, n);
+        assert!(polyhedron.is_nonempty(), "the polyhedron is empty");
+        polyhedron
+    }
+
+    /// Construct a new polyhedron of the form $\\{x\in\mathbb{R}^n {}:{} Ax \leq b\\}$,
+    /// or return an error if the data is invalid (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `b` is empty
+    /// - `ConstructionError::DimensionMismatch` if `a` and `b` have incompatible
+    ///   dimensions
+    /// - `ConstructionError::EmptySet` if the polyhedron is empty
+    ///
+    pub fn try_new(a: Vec<f64>, b: Vec<f64>) -> Result<Self, ConstructionError> {
+        let n_rows = b.len();
+        if n_rows == 0 {
+            return Err(ConstructionError::EmptyData);
+        }
+        let n = a.len() / n_rows;
+        if n == 0 || n * n_rows != a.len() {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: n.max(1) * n_rows,
+                found: a.len(),
+            });
+        }
+        let polyhedron = Polyhedron::from_data(a, b, n);
+        if polyhedron.is_nonempty() {
+            Ok(polyhedron)
+        } else {
+            Err(ConstructionError::EmptySet)
+        }
+    }
+
+    fn from_data(a: Vec<f64>, b: Vec<f64>, n: usize) -> Self {
+        let mut polyhedron = Polyhedron {
+            a_mat: a,
+            b_vec: b,
+            c_mat: Vec::new(),
+            d_vec: Vec::new(),
+            n,
+            row_norms: Vec::new(),
+            gram: Array2::zeros((0, 0)),
+            active_set: RefCell::new(Vec::new()),
+        };
+        polyhedron.compute_gram_matrix();
+        polyhedron
+    }
+
+    /// Add equality constraints, $Cx = d$, to the polyhedron
+    ///
+    /// # Arguments
+    ///
+    /// - `c`: matrix $C$, row-wise data
+    /// - `d`: vector $d$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `c` and `d` have incompatible dimensions, if the
+    /// number of columns of $C$ is not equal to the number of columns of $A$,
+    /// or if the resulting polyhedron is empty
+    ///
+    pub fn with_equality_constraints(self, c: Vec<f64>, d: Vec<f64>) -> Self {
+        assert!(
+            c.len() == d.len() * self.n,
+            "C and d have incompatible dimensions"
+        );
+        let polyhedron = self.set_equality_constraints(c, d);
+        assert!(polyhedron.is_nonempty(), "the polyhedron is empty");
+        polyhedron
+    }
+
+    /// Add equality constraints, $Cx = d$, to the polyhedron, or return an error
+    /// if the data is invalid (see `with_equality_constraints`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::DimensionMismatch` if `c` and `d` have incompatible
+    ///   dimensions
+    /// - `ConstructionError::EmptySet` if the resulting polyhedron is empty
+    ///
+    pub fn try_with_equality_constraints(
+        self,
+        c: Vec<f64>,
+        d: Vec<f64>,
+    ) -> Result<Self, ConstructionError> {
+        if c.len() != d.len() * self.n {
+            return Err(ConstructionError::DimensionMismatch {
+                expected: d.len() * self.n,
+                found: c.len(),
+            });
+        }
+        let polyhedron = self.set_equality_constraints(c, d);
+        if polyhedron.is_nonempty() {
+            Ok(polyhedron)
+        } else {
+            Err(ConstructionError::EmptySet)
+        }
+    }
+
+    fn set_equality_constraints(mut self, c: Vec<f64>, d: Vec<f64>) -> Self {
+        self.c_mat = c;
+        self.d_vec = d;
+        self.compute_gram_matrix();
+        self.active_set.borrow_mut().clear();
+        self
+    }
+
+    /// Checks whether the polyhedron is nonempty by projecting the origin on it
+    /// (the dual active-set method detects infeasibility)
+    fn is_nonempty(&self) -> bool {
+        let mut x = vec![0.0; self.n];
+        self.solve(&mut x).is_some()
+    }
+
+    /// Number of inequality constraints
+    fn num_inequalities(&self) -> usize {
+        self.b_vec.len()
+    }
+
+    /// Total number of constraints (inequalities and equalities)
+    fn num_constraints(&self) -> usize {
+        self.b_vec.len() + self.d_vec.len()
+    }
+
+    /// The i-th row of [A; C]
+    fn row(&self, i: usize) -> &[f64] {
+        let m = self.num_inequalities();
+        if i < m {
+            &self.a_mat[i * self.n..(i + 1) * self.n]
+        } else {
+            &self.c_mat[(i - m) * self.n..(i - m + 1) * self.n]
+        }
+    }
+
+    /// The i-th element of [b; d]
+    fn rhs(&self, i: usize) -> f64 {
+        let m = self.num_inequalities();
+        if i < m {
+            self.b_vec[i]
+        } else {
+            self.d_vec[i - m]
+        }
+    }
+
+    fn compute_gram_matrix(&mut self) {
+        let n_constraints = self.num_constraints();
+        self.row_norms = (0..n_constraints)
+            .map(|i| matrix_operations::norm2(self.row(i)))
+            .collect();
+        let mut gram = Array2::zeros((n_constraints, n_constraints));
+        for i in 0..n_constraints {
+            for j in 0..=i {
+                let g_ij = matrix_operations::inner_product(self.row(i), self.row(j));
+                gram[(i, j)] = g_ij;
+                gram[(j, i)] = g_ij;
+            }
+        }
+        self.gram = gram;
+    }
+
+    /// Tolerance on the violation of the constraints at `z`
+    fn tolerance(z: &[f64]) -> f64 {
+        FEASIBILITY_TOLERANCE * (1.0 + matrix_operations::norm_inf(z))
+    }
+
+    /// Inner products of the rows in the working set with the p-th row, that is, $N_W^\intercal a_p$
+    fn gram_column(&self, working_set: &[usize], p: usize) -> Vec<f64> {
+        working_set.iter().map(|&i| self.gram[(i, p)]).collect()
+    }
+
+    /// Computes $z = x - N_W u$, where the columns of $N_W$ are the rows in the working set
+    fn primal_from_multipliers(&self, x: &[f64], working_set: &[usize], u: &[f64], z: &mut [f64]) {
+        z.copy_from_slice(x);
+        for (&wi, &ui) in working_set.iter().zip(u.iter()) {
+            z.iter_mut()
+                .zip(self.row(wi).iter())
+                .for_each(|(zj, aj)| *zj -= ui * aj);
+        }
+    }
+
+    /// Warm start: computes the projection on $\\{z: a_i'z = b_i, i \in W\\}$,
+    /// where $W$ contains the equality constraints and the previously active
+    /// inequalities, and removes from $W$ the inequalities with negative multipliers
+    ///
+    /// Returns `false` (and leaves `working_set` unchanged) if warm starting fails
+    fn warm_start(
+        &self,
+        x: &[f64],
+        working_set: &mut Vec<usize>,
+        u: &mut Vec<f64>,
+        z: &mut [f64],
+        factor: &mut WorkingSetFactor,
+    ) -> bool {
+        let m = self.num_inequalities();
+        let previous: Vec<usize> = self
+            .active_set
+            .borrow()
+            .iter()
+            .cloned()
+            .filter(|&i| i < m)
+            .collect();
+        if previous.is_empty() {
+            return false;
+        }
+        let mut candidate = working_set.clone();
+        let mut candidate_factor = factor.clone();
+        for i in previous {
+            let mut l = vec![0.0; candidate.len()];
+            candidate_factor.forward_solve(&self.gram_column(&candidate, i), &mut l);
+            let norm_sq_projected = self.gram[(i, i)] - matrix_operations::norm2_squared(&l);
+            if norm_sq_projected <= DEPENDENCE_TOLERANCE * self.row_norms[i].powi(2) {
+                return false;
+            }
+            candidate.push(i);
+            candidate_factor.append(&l, norm_sq_projected);
+        }
+        loop {
+            let rhs: Vec<f64> = candidate
+                .iter()
+                .map(|&i| matrix_operations::inner_product(self.row(i), x) - self.rhs(i))
+                .collect();
+            let mut multipliers = vec![0.0; candidate.len()];
+            candidate_factor.solve(&rhs, &mut multipliers);
+            // the most negative multiplier of an inequality constraint
+            let most_negative = candidate
+                .iter()
+                .zip(multipliers.iter())
+                .enumerate()
+                .filter(|(_, (&i, &ui))| i < m && ui < 0.0)
+                .min_by(|(_, (_, u1)), (_, (_, u2))| u1.partial_cmp(u2).unwrap())
+                .map(|(k, _)| k);
+            match most_negative {
+                Some(k) => {
+                    candidate.remove(k);
+                    candidate_factor.remove(k);
+                }
+                None => {
+                    self.primal_from_multipliers(x, &candidate, &multipliers, z);
+                    *working_set = candidate;
+                    *u = multipliers;
+                    *factor = candidate_factor;
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Adds constraint `p` to the working set (this is a step of the dual
+    /// active-set method); `sign` is the orientation of the constraint, that is,
+    /// the constraint $\sigma a_p'z \leq \sigma b_p$ is added to the working set
+    ///
+    /// The Cholesky factor of the Gram matrix of the working set, `factor`, is
+    /// updated whenever a constraint is added to or removed from the working set.
+    ///
+    /// Returns `false` if the constraint is inconsistent with the constraints of
+    /// the working set, that is, if the polyhedron is empty
+    fn add_constraint(
+        &self,
+        p: usize,
+        sign: f64,
+        working_set: &mut Vec<usize>,
+        u: &mut Vec<f64>,
+        z: &mut [f64],
+        factor: &mut WorkingSetFactor,
+    ) -> bool {
+        let m = self.num_inequalities();
+        let a_p: Vec<f64> = self.row(p).iter().map(|a| sign * a).collect();
+        let mut violation = matrix_operations::inner_product(&a_p, z) - sign * self.rhs(p);
+        let mut lambda_p = 0.0;
+        let mut projected_a_p = vec![0.0; self.n];
+        loop {
+            // r = (N'N) \ N'a_p = L' \ l, where l = L \ N'a_p and N'N = LL' with N = N_W,
+            // and Pa_p = a_p - Nr
+            let k = working_set.len();
+            let mut l = vec![0.0; k];
+            factor.forward_solve(&self.gram_column(working_set, p), &mut l);
+            let mut r: Vec<f64> = l.iter().map(|li| sign * li).collect();
+            factor.backward_solve(&mut r);
+            projected_a_p.copy_from_slice(&a_p);
+            for (&wi, &ri) in working_set.iter().zip(r.iter()) {
+                projected_a_p
+                    .iter_mut()
+                    .zip(self.row(wi).iter())
+                    .for_each(|(pa, aj)| *pa -= ri * aj);
+            }
+            let norm_sq_projected = matrix_operations::norm2_squared(&projected_a_p);
+
+            // full step (constraint p becomes active)
+            let full_step = if norm_sq_projected > DEPENDENCE_TOLERANCE * self.row_norms[p].powi(2)
+            {
+                violation / norm_sq_projected
+            } else {
+                f64::INFINITY
+            };
+            // partial step (the multiplier of a constraint in the working set becomes zero)
+            let mut partial_step = f64::INFINITY;
+            let mut blocking = None;
+            for (idx, (&wi, &ri)) in working_set.iter().zip(r.iter()).enumerate() {
+                if wi < m && ri > 0.0 && u[idx] / ri < partial_step {
+                    partial_step = u[idx] / ri;
+                    blocking = Some(idx);
+                }
+            }
+            if !full_step.is_finite() && !partial_step.is_finite() {
+                // a_p is linearly dependent on the constraints of the working set,
+                // so it is either redundant or inconsistent with them
+                return violation <= Polyhedron::tolerance(z) * self.row_norms[p];
+            }
+
+            let step = full_step.min(partial_step);
+            z.iter_mut()
+                .zip(projected_a_p.iter())
+                .for_each(|(zj, pj)| *zj -= step * pj);
+            u.iter_mut()
+                .zip(r.iter())
+                .for_each(|(ui, ri)| *ui -= step * ri);
+            lambda_p += step;
+            violation -= step * norm_sq_projected;
+
+            if full_step <= partial_step {
+                working_set.push(p);
+                u.push(sign * lambda_p);
+                factor.append(&l, norm_sq_projected);
+                return true;
+            }
+            let idx = blocking.unwrap();
+            working_set.remove(idx);
+            u.remove(idx);
+            factor.remove(idx);
+        }
+    }
+
+    /// Computes the projection of `x` with the dual active-set method (warm-started
+    /// using the previous active set) and returns the active inequalities, or `None`
+    /// if the polyhedron is found to be empty
+    fn solve(&self, x: &mut [f64]) -> Option<Vec<usize>> {
+        let m = self.num_inequalities();
+        let x0 = x.to_vec();
+        let mut working_set: Vec<usize> = Vec::new();
+        let mut u: Vec<f64> = Vec::new();
+        let mut factor = WorkingSetFactor::new();
+
+        // Add the equality constraints to the working set (linearly dependent
+        // equality constraints are skipped)
+        for i in m..self.num_constraints() {
+            let violation = matrix_operations::inner_product(self.row(i), x) - self.rhs(i);
+            let sign = if violation >= 0.0 { 1.0 } else { -1.0 };
+            if !self.add_constraint(i, sign, &mut working_set, &mut u, x, &mut factor) {
+                return None;
+            }
+        }
+
+        // Warm start using the previous active set
+        self.warm_start(&x0, &mut working_set, &mut u, x, &mut factor);
+
+        // Dual active-set iterations: add the most violated inequality
+        for _ in 0..MAX_ACTIVE_SET_ITERATIONS {
+            let tol = Polyhedron::tolerance(x);
+            let mut most_violated = None;
+            let mut max_violation = tol;
+            for i in (0..m).filter(|i| !working_set.contains(i)) {
+                let violation = (matrix_operations::inner_product(self.row(i), x) - self.rhs(i))
+                    / self.row_norms[i];
+                if violation > max_violation {
+                    max_violation = violation;
+                    most_violated = Some(i);
+                }
+            }
+            match most_violated {
+                Some(p) => {
+                    if !self.add_constraint(p, 1.0, &mut working_set, &mut u, x, &mut factor) {
+                        return None;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        Some(working_set.into_iter().filter(|&i| i < m).collect())
+    }
+
+    /// The cone $\\{d {}:{} a_i^\intercal d \leq 0, i\in I, Cd = 0\\}$, for a given set
+    /// of inequalities, $I$
+    fn cone(&self, inequalities: &[usize]) -> Polyhedron {
+        let a = inequalities
+            .iter()
+            .flat_map(|&i| self.row(i).iter().cloned())
+            .collect();
+        Polyhedron::from_data(a, vec![0.0; inequalities.len()], self.n)
+            .set_equality_constraints(self.c_mat.clone(), vec![0.0; self.d_vec.len()])
+    }
+}
+
+impl Constraint for Polyhedron {
+    /// Projection on the polyhedron
+    ///
+    /// The projection is computed by solving the quadratic program
+    ///
+    /// $$
+    /// \mathrm{Minimize}_{z} \tfrac{1}{2}\Vert z - x \Vert^2,
+    /// \text{ subject to } Az \leq b, Cz = d,
+    /// $$
+    ///
+    /// using a dual active-set method, which is warm-started using the active
+    /// set of the previous projection.
+    ///
+    /// The method terminates in a finite number of iterations; as a safeguard
+    /// against cycling due to round-off errors, at most 10000 constraints are
+    /// added to the working set, after which the current iterate is returned,
+    /// which may then violate some of the constraints.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the polyhedron,
+    ///   (out) projection on the polyhedron
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to the dimension of
+    /// the polyhedron, or if the polyhedron is found to be empty (which, since
+    /// this is checked upon construction, can only be due to round-off errors)
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.n, "x has wrong dimension");
+        let active_set = self.solve(x).expect("the polyhedron is empty");
+        *self.active_set.borrow_mut() = active_set;
+    }
+
+    /// Polyhedra are convex sets
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    /// Squared distance from the polyhedron, which is computed by projecting
+    /// a copy of `x`; unlike `project`, this does not update the active set
+    /// that is used to warm start the next projection
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        assert!(x.len() == self.n, "x has wrong dimension");
+        let mut projection = x.to_vec();
+        self.solve(&mut projection)
+            .expect("the polyhedron is empty");
+        matrix_operations::norm2_squared_diff(x, &projection)
+    }
+
+    /// Checks whether $Ax \leq b + \epsilon$ and $|Cx - d| \leq \epsilon$
+    /// (element-wise), which does not require a projection
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        assert!(x.len() == self.n, "x has wrong dimension");
+        let m = self.num_inequalities();
+        (0..self.num_constraints()).all(|i| {
+            let residual = matrix_operations::inner_product(self.row(i), x) - self.rhs(i);
+            if i < m {
+                residual <= tol
+            } else {
+                residual.abs() <= tol
+            }
+        })
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.n)
+    }
+}
+
+impl ActiveSet for Polyhedron {
+    /// The $i$-th inequality is active if $a_i^\intercal x \geq b_i - \epsilon\Vert{}a_i{}\Vert$,
+    /// where $a_i^\intercal$ is the $i$-th row of $A$; the equalities are always active
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let m = self.num_inequalities();
+        (0..m)
+            .filter(|&i| {
+                matrix_operations::inner_product(self.row(i), x) - self.rhs(i)
+                    >= -tol * self.row_norms[i]
+            })
+            .map(ActiveConstraint::Inequality)
+            .chain((0..self.d_vec.len()).map(ActiveConstraint::Equality))
+            .collect()
+    }
+
+    /// The support function is finite if and only if $y$ belongs to the polar of
+    /// the recession cone of the polyhedron, $\\{d {}:{} Ad \leq 0, Cd = 0\\}$;
+    /// in that case, the linear program $\max_{z\in P}\langle y, z\rangle$ is solved
+    /// with the proximal point method, $z^+ = \Pi_P(z + ty)$, with increasing $t$,
+    /// which terminates in a finite number of iterations (M.C. Ferris, Finite
+    /// termination of the proximal point algorithm, Mathematical Programming, 50
+    /// (1991), pp. 359-366); at most 100 iterations are performed.
+    fn support_function(&self, y: &[f64]) -> f64 {
+        assert!(y.len() == self.n, "y has wrong dimension");
+        let norm_y = matrix_operations::norm2(y);
+        if norm_y == 0.0 {
+            return 0.0;
+        }
+        let all_inequalities: Vec<usize> = (0..self.num_inequalities()).collect();
+        let mut y_recession = y.to_vec();
+        self.cone(&all_inequalities).project(&mut y_recession);
+        if matrix_operations::norm2(&y_recession) > FEASIBILITY_TOLERANCE * norm_y {
+            return f64::INFINITY;
+        }
+        let mut z = vec![0.0; self.n];
+        self.solve(&mut z).expect("the polyhedron is empty");
+        let mut z_next = vec![0.0; self.n];
+        let mut t = (1.0 + matrix_operations::norm_inf(&z)) / norm_y;
+        for _ in 0..MAX_SUPPORT_ITERATIONS {
+            z_next
+                .iter_mut()
+                .zip(z.iter().zip(y.iter()))
+                .for_each(|(zn, (zi, yi))| *zn = zi + t * yi);
+            self.solve(&mut z_next).expect("the polyhedron is empty");
+            let converged = matrix_operations::norm_inf_diff(&z_next, &z)
+                <= FEASIBILITY_TOLERANCE * (1.0 + matrix_operations::norm_inf(&z));
+            std::mem::swap(&mut z, &mut z_next);
+            if converged {
+                break;
+            }
+            t *= 2.0;
+        }
+        matrix_operations::inner_product(y, &z)
+    }
+
+    /// The normal cone is generated by the rows of $A$ which correspond to the
+    /// active inequalities and the rows of $C$; the projection is computed using
+    /// Moreau's decomposition, $\Pi_{N}(y) = y - \Pi_{T}(y)$, where $T$ is the
+    /// tangent cone, $\\{d {}:{} a_i^\intercal d \leq 0, i \text{ active}, Cd = 0\\}$
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        assert!(y.len() == self.n, "y has wrong dimension");
+        let active: Vec<usize> = self
+            .active_constraints(x, tol)
+            .into_iter()
+            .filter_map(|c| match c {
+                ActiveConstraint::Inequality(i) => Some(i),
+                _ => None,
+            })
+            .collect();
+        let mut y_tangent = y.to_vec();
+        self.cone(&active).project(&mut y_tangent);
+        y.iter_mut()
+            .zip(y_tangent.iter())
+            .for_each(|(yi, ti)| *yi -= ti);
+    }
+}
@@ -0,0 +1,175 @@
+use super::Constraint;
+use crate::ConstructionError;
+
+/// Maximum number of bisection iterations
+const MAX_BISECTION_ITERATIONS: usize = 1100;
+
+#[derive(Clone, Copy)]
+///
+/// The power cone
+///
+/// The (three-dimensional) power cone with parameter $\alpha\in(0, 1)$ is the set
+///
+/// $$
+/// K_\alpha = \\{(x, y, z) \in \mathbb{R}^3 {}:{} x^\alpha y^{1-\alpha} \geq |z|, x \geq 0, y \geq 0\\}.
+/// $$
+///
+/// Projections on the power cone are computed as in L. T. K. Hien,
+/// Differential properties of Euclidean projection onto power cone,
+/// Mathematical Methods of Operations Research, 82 (2015), pp. 265-284.
+///
+pub struct PowerCone {
+    alpha: f64,
+}
+
+impl PowerCone {
+    /// Construct a new instance of the power cone with parameter `alpha`
+    ///
+    /// # Arguments
+    ///
+    /// - `alpha`: parameter $\alpha$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `alpha` is not in $(0, 1)$
+    ///
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0.0 && alpha < 1.0, "alpha must be in (0, 1)");
+        PowerCone { alpha }
+    }
+
+    /// Construct a new instance of the power cone, or return an error if
+    /// `alpha` is not in $(0, 1)$
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `alpha` is not in $(0, 1)$
+    ///
+    pub fn try_new(alpha: f64) -> Result<Self, ConstructionError> {
+        if alpha > 0.0 && alpha < 1.0 {
+            Ok(PowerCone::new(alpha))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "alpha",
+                value: alpha,
+            })
+        }
+    }
+
+    /// Whether `v` is in the power cone
+    fn in_cone(&self, v: &[f64]) -> bool {
+        v[0] >= 0.0
+            && v[1] >= 0.0
+            && v[0].powf(self.alpha) * v[1].powf(1.0 - self.alpha) >= v[2].abs()
+    }
+
+    /// Whether `v` is in the polar cone, that is, $-v$ is in the dual cone,
+    /// $K_\alpha^* = \\{(u, v, w) {}:{} (u/\alpha)^\alpha (v/(1-\alpha))^{1-\alpha} \geq |w|, u\geq 0, v \geq 0\\}$
+    fn in_polar_cone(&self, v: &[f64]) -> bool {
+        let alpha = self.alpha;
+        v[0] <= 0.0
+            && v[1] <= 0.0
+            && (-v[0] / alpha).powf(alpha) * (-v[1] / (1.0 - alpha)).powf(1.0 - alpha) >= v[2].abs()
+    }
+}
+
+/// Computes $\tfrac{1}{2}(t + \sqrt{t^2 + c})$, for $c \geq 0$, avoiding
+/// cancellation when $t < 0$
+fn positive_root(t: f64, c: f64) -> f64 {
+    let s = (t * t + c).sqrt();
+    if t >= 0.0 {
+        0.5 * (t + s)
+    } else if s - t > 0.0 {
+        0.5 * c / (s - t)
+    } else {
+        0.0
+    }
+}
+
+impl Constraint for PowerCone {
+    /// Project on the power cone
+    ///
+    /// If $v_0=(x_0, y_0, z_0)$ is neither in the cone nor in its polar, and
+    /// $z_0 \neq 0$, the projection is $(\Phi_x(r), \Phi_y(r), \mathrm{sgn}(z_0)r)$, where
+    ///
+    /// $$
+    /// \Phi_x(r) = \tfrac{1}{2}\left(x_0 + \sqrt{x_0^2 + 4\alpha r(|z_0| - r)}\right),\quad
+    /// \Phi_y(r) = \tfrac{1}{2}\left(y_0 + \sqrt{y_0^2 + 4(1-\alpha) r(|z_0| - r)}\right),
+    /// $$
+    ///
+    /// and $r\in(0, |z_0|)$ is the unique root of $\Phi_x(r)^\alpha\Phi_y(r)^{1-\alpha} - r = 0$,
+    /// which is computed by bisection.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the power cone,
+    ///   (out) projection on the power cone
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to 3.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == 3, "x must be of dimension 3");
+        if self.in_cone(x) {
+            return;
+        }
+        if self.in_polar_cone(x) {
+            x.iter_mut().for_each(|xi| *xi = 0.0);
+            return;
+        }
+        let (x0, y0, z0) = (x[0], x[1], x[2]);
+        let abs_z0 = z0.abs();
+        if abs_z0 == 0.0 {
+            x[0] = f64::max(x0, 0.0);
+            x[1] = f64::max(y0, 0.0);
+            return;
+        }
+
+        let alpha = self.alpha;
+        let phi_x = |r: f64, s: f64| positive_root(x0, 4.0 * alpha * r * s);
+        let phi_y = |r: f64, s: f64| positive_root(y0, 4.0 * (1.0 - alpha) * r * s);
+        let residual = |r: f64, s: f64| phi_x(r, s).powf(alpha) * phi_y(r, s).powf(1.0 - alpha) - r;
+
+        // We bisect on r in (0, |z0|), where residual(0) >= 0 and residual(|z0|) < 0,
+        // and simultaneously on s = |z0| - r, so that both r and s are accurate
+        // when the root is close to either end of the interval
+        let (mut r_lower, mut r_upper) = (0.0, abs_z0);
+        let (mut s_lower, mut s_upper) = (abs_z0, 0.0);
+        let (mut r, mut s) = (0.5 * abs_z0, 0.5 * abs_z0);
+        for _ in 0..MAX_BISECTION_ITERATIONS {
+            if residual(r, s) > 0.0 {
+                r_lower = r;
+                s_lower = s;
+            } else {
+                r_upper = r;
+                s_upper = s;
+            }
+            let r_next = 0.5 * (r_lower + r_upper);
+            let s_next = 0.5 * (s_lower + s_upper);
+            if (r_next == r || r_next == r_lower || r_next == r_upper)
+                && (s_next == s || s_next == s_lower || s_next == s_upper)
+            {
+                break;
+            }
+            r = r_next;
+            s = s_next;
+        }
+        x[0] = phi_x(r, s);
+        x[1] = phi_y(r, s);
+        x[2] = z0.signum() * r;
+    }
+
+    /// The power cone is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        self.in_cone(x) || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(3)
+    }
+}
@@ -0,0 +1,215 @@
+use super::Constraint;
+use crate::{matrix_operations, ConstructionError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// Storage format of a symmetric matrix in a vector
+pub enum SymmetricStorage {
+    /// All $n^2$ elements of the matrix are stored (row-wise or column-wise)
+    Full,
+    /// The $n(n+1)/2$ elements of the lower triangular part of the matrix are
+    /// stored column-wise, that is, $(X_{11}, X_{21}, \ldots, X_{n1}, X_{22}, \ldots, X_{nn})$,
+    /// and the off-diagonal elements are multiplied by $\sqrt{2}$, so that the
+    /// Euclidean norm of the vector is equal to the Frobenius norm of the matrix
+    Packed,
+}
+
+#[derive(Clone, Copy)]
+/// The cone of symmetric positive semidefinite matrices,
+/// $\mathbb{S}_+^n = \\{X\in\mathbb{R}^{n\times n} {}:{} X = X^\intercal, X \succcurlyeq 0\\}$
+///
+/// A matrix $X$ is represented by a vector, either by storing all its
+/// elements ([`SymmetricStorage::Full`]), or by storing the elements of its
+/// lower triangular part ([`SymmetricStorage::Packed`]).
+///
+/// [`SymmetricStorage::Full`]: enum.SymmetricStorage.html#variant.Full
+/// [`SymmetricStorage::Packed`]: enum.SymmetricStorage.html#variant.Packed
+pub struct PsdCone {
+    n: usize,
+    storage: SymmetricStorage,
+}
+
+impl PsdCone {
+    /// Construct a new cone of positive semidefinite matrices of dimension
+    /// $n\times n$ with given storage format
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: number of rows (and columns) of the matrix
+    /// - `storage`: storage format of the matrix
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n` is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::*;
+    ///
+    /// let psd = PsdCone::new(2, SymmetricStorage::Full);
+    /// let mut x = [1.0, 2.0, 2.0, 1.0];
+    /// psd.project(&mut x);
+    /// ```
+    ///
+    pub fn new(n: usize, storage: SymmetricStorage) -> Self {
+        assert!(n > 0, "n must be positive");
+        PsdCone { n, storage }
+    }
+
+    /// Construct a new cone of positive semidefinite matrices, or return an
+    /// error if `n` is zero
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `n` is zero
+    ///
+    pub fn try_new(n: usize, storage: SymmetricStorage) -> Result<Self, ConstructionError> {
+        if n > 0 {
+            Ok(PsdCone::new(n, storage))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "n",
+                value: 0.0,
+            })
+        }
+    }
+
+    /// Dimension of the vectors on which the cone is defined, that is,
+    /// $n^2$ or $n(n+1)/2$, depending on the storage format
+    fn vector_dimension(&self) -> usize {
+        match self.storage {
+            SymmetricStorage::Full => self.n * self.n,
+            SymmetricStorage::Packed => self.n * (self.n + 1) / 2,
+        }
+    }
+
+    /// Unpacks `x` into the full symmetric matrix `mat` (row-wise)
+    fn unpack(&self, x: &[f64], mat: &mut [f64]) {
+        let n = self.n;
+        match self.storage {
+            SymmetricStorage::Full => {
+                // mat ← (X + X')/2
+                for i in 0..n {
+                    for j in 0..n {
+                        mat[i * n + j] = 0.5 * (x[i * n + j] + x[j * n + i]);
+                    }
+                }
+            }
+            SymmetricStorage::Packed => {
+                let mut idx = 0;
+                for j in 0..n {
+                    mat[j * n + j] = x[idx];
+                    idx += 1;
+                    for i in j + 1..n {
+                        mat[i * n + j] = x[idx] / std::f64::consts::SQRT_2;
+                        mat[j * n + i] = mat[i * n + j];
+                        idx += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Packs the symmetric matrix `mat` (row-wise) into `x`
+    fn pack(&self, mat: &[f64], x: &mut [f64]) {
+        let n = self.n;
+        match self.storage {
+            SymmetricStorage::Full => x.copy_from_slice(mat),
+            SymmetricStorage::Packed => {
+                let mut idx = 0;
+                for j in 0..n {
+                    x[idx] = mat[j * n + j];
+                    idx += 1;
+                    for i in j + 1..n {
+                        x[idx] = std::f64::consts::SQRT_2 * mat[i * n + j];
+                        idx += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Constraint for PsdCone {
+    /// Projection onto the cone of positive semidefinite matrices
+    ///
+    /// Given a matrix $X$, the projection is computed by first computing its
+    /// symmetric part, $X_s = (X + X^\intercal)/2$, and its eigenvalue decomposition,
+    /// $X_s = V\Lambda V^\intercal$, and then
+    ///
+    /// $$
+    /// \Pi_{\mathbb{S}_+^n}(X) = V\max\\{\Lambda, 0\\}V^\intercal.
+    /// $$
+    ///
+    /// The eigenvalue decomposition is computed using the Jacobi method.
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector representation of a matrix, (out) vector representation
+    ///   of its projection on the cone
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to the dimension of
+    /// the cone (see `dimension()`)
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.vector_dimension(), "x has wrong dimension");
+        let n = self.n;
+        let mut mat = vec![0.0; n * n];
+        let mut eigenvalues = vec![0.0; n];
+        let mut eigenvectors = vec![0.0; n * n];
+        self.unpack(x, &mut mat);
+        matrix_operations::symmetric_eigen(&mut mat, &mut eigenvalues, &mut eigenvectors);
+
+        // mat ← V max{Λ, 0} V'
+        for i in 0..n {
+            for j in 0..=i {
+                let mut mat_ij = 0.0;
+                for k in 0..n {
+                    if eigenvalues[k] > 0.0 {
+                        mat_ij +=
+                            eigenvalues[k] * eigenvectors[i * n + k] * eigenvectors[j * n + k];
+                    }
+                }
+                mat[i * n + j] = mat_ij;
+                mat[j * n + i] = mat_ij;
+            }
+        }
+        self.pack(&mat, x);
+    }
+
+    /// The cone of positive semidefinite matrices is convex
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    /// The squared distance is $\Vert (X - X^\intercal)/2 \Vert_F^2 + \sum_{\lambda_i < 0} \lambda_i^2$,
+    /// where $\lambda_i$ are the eigenvalues of the symmetric part of $X$, so the
+    /// projection does not need to be formed
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        assert!(x.len() == self.vector_dimension(), "x has wrong dimension");
+        let n = self.n;
+        let mut mat = vec![0.0; n * n];
+        let mut eigenvalues = vec![0.0; n];
+        let mut eigenvectors = vec![0.0; n * n];
+        self.unpack(x, &mut mat);
+        let skew_norm_squared = match self.storage {
+            SymmetricStorage::Full => x
+                .iter()
+                .zip(mat.iter())
+                .fold(0.0, |sum, (xi, si)| sum + (xi - si).powi(2)),
+            SymmetricStorage::Packed => 0.0,
+        };
+        matrix_operations::symmetric_eigen(&mut mat, &mut eigenvalues, &mut eigenvectors);
+        eigenvalues
+            .iter()
+            .filter(|&&lambda| lambda < 0.0)
+            .fold(skew_norm_squared, |sum, lambda| sum + lambda * lambda)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.vector_dimension())
+    }
+}
@@ -1,6 +1,8 @@
-use super::Constraint;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
+use std::borrow::Cow;
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 ///
 /// A rectangle, $R = \\{x \in \mathbb{R}^n {}:{} x_{\min} {}\leq{} x {}\leq{} x_{\max}\\}$
 ///
@@ -8,8 +10,8 @@ use super::Constraint;
 /// where $\leq$ is meant in the element-wise sense and either of $x_{\min}$ and $x_{\max}$ can
 /// be equal to infinity.
 pub struct Rectangle<'a> {
-    xmin: Option<&'a [f64]>,
-    xmax: Option<&'a [f64]>,
+    xmin: Option<Cow<'a, [f64]>>,
+    xmax: Option<Cow<'a, [f64]>>,
 }
 
 impl<'a> Rectangle<'a> {
@@ -23,8 +25,8 @@ impl<'a> Rectangle<'a> {
     /// # Note
     ///
     /// Rectangle does not copy `xmin` and `xmax` internally; it only keeps
-    /// a reference. You may set one of `xmin` and `xmax` to `None` (but not
-    /// both).
+    /// a reference (use `new_owned` to construct a rectangle which owns its
+    /// data). You may set one of `xmin` and `xmax` to `None` (but not both).
     ///
     /// # Panics
     ///
@@ -40,7 +42,143 @@ impl<'a> Rectangle<'a> {
             xmin.is_none() || xmax.is_none() || xmin.unwrap().len() == xmax.unwrap().len(),
             "incompatible dimensions of xmin and xmax"
         );
-        Rectangle { xmin, xmax }
+        Rectangle {
+            xmin: xmin.map(Cow::Borrowed),
+            xmax: xmax.map(Cow::Borrowed),
+        }
+    }
+
+    /// Construct a new rectangle with given $x_{\min}$ and $x_{\max}$, or
+    /// return an error if the data is invalid (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::MissingBounds` if both `xmin` and `xmax` are `None`
+    /// - `ConstructionError::DimensionMismatch` if `xmin` and `xmax` have
+    ///   incompatible dimensions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::Rectangle;
+    /// use optimization_engine::ConstructionError;
+    ///
+    /// let xmin = [0.0; 3];
+    /// let xmax = [1.0; 2];
+    /// let rectangle = Rectangle::try_new(Some(&xmin), Some(&xmax));
+    /// assert_eq!(
+    ///     Some(ConstructionError::DimensionMismatch { expected: 3, found: 2 }),
+    ///     rectangle.err()
+    /// );
+    /// ```
+    ///
+    pub fn try_new(
+        xmin: Option<&'a [f64]>,
+        xmax: Option<&'a [f64]>,
+    ) -> Result<Self, ConstructionError> {
+        Rectangle::check_bounds(xmin, xmax)?;
+        Ok(Rectangle::new(xmin, xmax))
+    }
+
+    /// Construct a new rectangle which owns $x_{\min}$ and $x_{\max}$, or
+    /// return an error if the data is invalid (see `try_new`)
+    pub fn try_new_owned(
+        xmin: Option<Vec<f64>>,
+        xmax: Option<Vec<f64>>,
+    ) -> Result<Self, ConstructionError> {
+        Rectangle::check_bounds(xmin.as_deref(), xmax.as_deref())?;
+        Ok(Rectangle::new_owned(xmin, xmax))
+    }
+
+    /// Checks that at least one of `xmin` and `xmax` is provided and that
+    /// their dimensions are compatible
+    fn check_bounds(xmin: Option<&[f64]>, xmax: Option<&[f64]>) -> Result<(), ConstructionError> {
+        match (xmin, xmax) {
+            (None, None) => Err(ConstructionError::MissingBounds),
+            (Some(xmin), Some(xmax)) if xmin.len() != xmax.len() => {
+                Err(ConstructionError::DimensionMismatch {
+                    expected: xmin.len(),
+                    found: xmax.len(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Construct a new rectangle which owns $x_{\min}$ and $x_{\max}$ (see `new`)
+    pub fn new_owned(xmin: Option<Vec<f64>>, xmax: Option<Vec<f64>>) -> Self {
+        assert!(xmin.is_some() || xmax.is_some()); // xmin or xmax must be Some
+        assert!(
+            xmin.is_none()
+                || xmax.is_none()
+                || xmin.as_ref().unwrap().len() == xmax.as_ref().unwrap().len(),
+            "incompatible dimensions of xmin and xmax"
+        );
+        Rectangle {
+            xmin: xmin.map(Cow::Owned),
+            xmax: xmax.map(Cow::Owned),
+        }
+    }
+
+    /// Update $x_{\min}$
+    ///
+    /// The given values are copied into the data of the rectangle, so, if the
+    /// rectangle owns its data (see `new_owned`), no memory is allocated. Borrowed
+    /// data (or `None`) is replaced by an owned copy upon the first update.
+    ///
+    /// # Arguments
+    ///
+    /// - `xmin`: new minimum value of `x`
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `xmin` does not have the dimension of the rectangle
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, Rectangle};
+    ///
+    /// let mut rectangle = Rectangle::new_owned(Some(vec![-1.0; 2]), Some(vec![1.0; 2]));
+    /// // new bounds (e.g., at the next sampling time)
+    /// rectangle.set_xmin(&[0.0, -2.0]);
+    /// rectangle.set_xmax(&[0.5, 2.0]);
+    /// let mut x = [-1.0, 3.0];
+    /// rectangle.project(&mut x);
+    /// assert_eq!([0.0, 2.0], x);
+    /// ```
+    ///
+    pub fn set_xmin(&mut self, xmin: &[f64]) {
+        Rectangle::set_bound(&mut self.xmin, self.xmax.as_deref(), xmin);
+    }
+
+    /// Update $x_{\max}$
+    ///
+    /// See `set_xmin`
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `xmax` does not have the dimension of the rectangle
+    ///
+    pub fn set_xmax(&mut self, xmax: &[f64]) {
+        Rectangle::set_bound(&mut self.xmax, self.xmin.as_deref(), xmax);
+    }
+
+    /// Copies `new_bound` into `bound` (which is either $x_{\min}$ or $x_{\max}$)
+    fn set_bound(
+        bound: &mut Option<Cow<'a, [f64]>>,
+        other_bound: Option<&[f64]>,
+        new_bound: &[f64],
+    ) {
+        let n = bound.as_deref().or(other_bound).unwrap().len();
+        assert!(
+            new_bound.len() == n,
+            "incompatible dimensions of xmin and xmax"
+        );
+        match bound {
+            Some(bound) => bound.to_mut().copy_from_slice(new_bound),
+            None => *bound = Some(Cow::Owned(new_bound.to_vec())),
+        }
     }
 }
 
@@ -66,4 +204,78 @@ impl<'a> Constraint for Rectangle<'a> {
     fn is_convex(&self) -> bool {
         true
     }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        x.iter().enumerate().fold(0.0, |sum, (i, &xi)| {
+            let lower = self.xmin.as_ref().map_or(f64::NEG_INFINITY, |xmin| xmin[i]);
+            let upper = self.xmax.as_ref().map_or(f64::INFINITY, |xmax| xmax[i]);
+            let violation = (lower - xi).max(xi - upper).max(0.0);
+            sum + violation * violation
+        })
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.xmin
+            .as_ref()
+            .or(self.xmax.as_ref())
+            .map(|bound| bound.len())
+    }
+}
+
+impl<'a> Rectangle<'a> {
+    /// Lower and upper bounds on the `i`-th element
+    fn bounds(&self, i: usize) -> (f64, f64) {
+        let lower = self.xmin.as_ref().map_or(f64::NEG_INFINITY, |xmin| xmin[i]);
+        let upper = self.xmax.as_ref().map_or(f64::INFINITY, |xmax| xmax[i]);
+        (lower, upper)
+    }
+}
+
+impl<'a> ActiveSet for Rectangle<'a> {
+    /// The bound $x_{\min, i}$ is active if $x_i \leq x_{\min, i} + \epsilon$
+    /// and $x_{\max, i}$ is active if $x_i \geq x_{\max, i} - \epsilon$
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let mut active = Vec::new();
+        x.iter().enumerate().for_each(|(i, &xi)| {
+            let (lower, upper) = self.bounds(i);
+            if xi <= lower + tol {
+                active.push(ActiveConstraint::LowerBound(i));
+            }
+            if xi >= upper - tol {
+                active.push(ActiveConstraint::UpperBound(i));
+            }
+        });
+        active
+    }
+
+    fn support_function(&self, y: &[f64]) -> f64 {
+        y.iter().enumerate().fold(0.0, |sum, (i, &yi)| {
+            let (lower, upper) = self.bounds(i);
+            if yi > 0.0 {
+                sum + yi * upper
+            } else if yi < 0.0 {
+                sum + yi * lower
+            } else {
+                sum
+            }
+        })
+    }
+
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        y.iter_mut()
+            .zip(x.iter())
+            .enumerate()
+            .for_each(|(i, (yi, &xi))| {
+                let (lower, upper) = self.bounds(i);
+                let lower_active = xi <= lower + tol;
+                let upper_active = xi >= upper - tol;
+                if !lower_active && !upper_active {
+                    *yi = 0.0;
+                } else if !upper_active {
+                    *yi = yi.min(0.0);
+                } else if !lower_active {
+                    *yi = yi.max(0.0);
+                }
+            });
+    }
 }
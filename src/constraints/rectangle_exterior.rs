@@ -0,0 +1,151 @@
+use super::Constraint;
+use crate::ConstructionError;
+
+#[derive(Clone, Copy)]
+///
+/// The exterior of a rectangle
+///
+/// A set of the form $\\{x \in \mathbb{R}^n {}:{} x_i \leq x_{\min, i} \text{ or }
+/// x_i \geq x_{\max, i} \text{ for some } i\\}$, that is, the closure of the
+/// complement of the rectangle $\\{x {}:{} x_{\min} \leq x \leq x_{\max}\\}$.
+/// Either of $x_{\min}$ and $x_{\max}$ can be equal to infinity.
+///
+/// This is a nonconvex set which can be used to model collision avoidance
+/// constraints (e.g., an axis-aligned box-shaped obstacle).
+///
+pub struct RectangleExterior<'a> {
+    xmin: Option<&'a [f64]>,
+    xmax: Option<&'a [f64]>,
+}
+
+impl<'a> RectangleExterior<'a> {
+    /// Construct the exterior of a rectangle with given $x_{\min}$ and $x_{\max}$
+    ///
+    /// # Arguments
+    ///
+    /// - `xmin`: minimum value of `x` in the rectangle
+    /// - `xmax`: maximum value of `x` in the rectangle
+    ///
+    /// # Panics
+    ///
+    /// The method panics if:
+    ///
+    /// - Both `xmin` and `xmax` are `None`
+    /// - Both `xmin` and `xmax` have been provided, but they have incompatible
+    ///   dimensions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, RectangleExterior};
+    ///
+    /// let xmin = [0.0, 0.0];
+    /// let xmax = [2.0, 1.0];
+    /// let exterior = RectangleExterior::new(Some(&xmin), Some(&xmax));
+    /// let mut x = [1.0, 0.8];
+    /// exterior.project(&mut x);
+    /// assert_eq!([1.0, 1.0], x);
+    /// ```
+    ///
+    pub fn new(xmin: Option<&'a [f64]>, xmax: Option<&'a [f64]>) -> Self {
+        assert!(xmin.is_some() || xmax.is_some()); // xmin or xmax must be Some
+        assert!(
+            xmin.is_none() || xmax.is_none() || xmin.unwrap().len() == xmax.unwrap().len(),
+            "incompatible dimensions of xmin and xmax"
+        );
+        RectangleExterior { xmin, xmax }
+    }
+
+    /// Construct the exterior of a rectangle, or return an error if the bounds
+    /// are invalid (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::MissingBounds` if both `xmin` and `xmax` are `None`
+    /// - `ConstructionError::DimensionMismatch` if `xmin` and `xmax` have
+    ///   incompatible dimensions
+    ///
+    pub fn try_new(
+        xmin: Option<&'a [f64]>,
+        xmax: Option<&'a [f64]>,
+    ) -> Result<Self, ConstructionError> {
+        match (xmin, xmax) {
+            (None, None) => Err(ConstructionError::MissingBounds),
+            (Some(xmin_), Some(xmax_)) if xmin_.len() != xmax_.len() => {
+                Err(ConstructionError::DimensionMismatch {
+                    expected: xmin_.len(),
+                    found: xmax_.len(),
+                })
+            }
+            _ => Ok(RectangleExterior::new(xmin, xmax)),
+        }
+    }
+}
+
+impl<'a> Constraint for RectangleExterior<'a> {
+    /// Projection onto the exterior of the rectangle
+    ///
+    /// If $x$ is in the interior of the rectangle, it is moved to the closest
+    /// facet of the rectangle, that is, only one of its coordinates is changed
+    /// (set to $x_{\min, i}$ or $x_{\max, i}$).
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected, (out) projection on the set
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the dimension
+    /// of the rectangle, or if $x$ is in the interior of a rectangle which has
+    /// no finite bounds (then the set is empty).
+    ///
+    fn project(&self, x: &mut [f64]) {
+        let n = self.xmin.or(self.xmax).unwrap().len();
+        assert!(x.len() == n, "x has wrong dimension");
+        let mut best_distance = f64::INFINITY;
+        let mut best = None;
+        for (i, &xi) in x.iter().enumerate() {
+            let lower = self.xmin.map_or(f64::NEG_INFINITY, |xmin| xmin[i]);
+            let upper = self.xmax.map_or(f64::INFINITY, |xmax| xmax[i]);
+            if xi <= lower || xi >= upper {
+                // x is outside the rectangle (or on its boundary)
+                return;
+            }
+            if xi - lower < best_distance {
+                best_distance = xi - lower;
+                best = Some((i, lower));
+            }
+            if upper - xi < best_distance {
+                best_distance = upper - xi;
+                best = Some((i, upper));
+            }
+        }
+        let (i, bound) = best.expect("the exterior of the rectangle is empty");
+        x[i] = bound;
+    }
+
+    /// The exterior of a rectangle is nonconvex
+    fn is_convex(&self) -> bool {
+        false
+    }
+
+    /// Distance from the exterior of the rectangle (squared), that is, the
+    /// (squared) distance of `x` from the closest facet, if `x` is in the
+    /// interior of the rectangle, or zero otherwise
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let mut distance = f64::INFINITY;
+        for (i, &xi) in x.iter().enumerate() {
+            let lower = self.xmin.map_or(f64::NEG_INFINITY, |xmin| xmin[i]);
+            let upper = self.xmax.map_or(f64::INFINITY, |xmax| xmax[i]);
+            distance = distance.min(xi - lower).min(upper - xi);
+            if distance <= 0.0 {
+                return 0.0;
+            }
+        }
+        distance * distance
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.xmin.or(self.xmax).map(|bound| bound.len())
+    }
+}
@@ -0,0 +1,82 @@
+use super::soc::soc_distance_squared;
+use super::{Constraint, SecondOrderCone};
+
+#[derive(Clone, Copy, Default)]
+///
+/// A rotated second-order cone
+///
+/// A set of the form
+///
+/// $$
+/// C = \\{x=(u, y, z) \in \mathbb{R}^{n+2}: y, z\in\mathbb{R}, \Vert{}u\Vert^2 \leq 2yz, y\geq 0, z\geq 0\\}.
+/// $$
+///
+/// The rotated second-order cone is the image of the second-order cone,
+/// $\\{(u, s, t): \Vert{}(u, s)\Vert \leq t\\}$, under the orthogonal
+/// transformation $(u, s, t) \mapsto (u, (t-s)/\sqrt{2}, (s+t)/\sqrt{2})$,
+/// so projections on it are computed by projecting on the (standard)
+/// second-order cone.
+///
+pub struct RotatedSecondOrderCone {}
+
+impl RotatedSecondOrderCone {
+    /// Construct a new instance of a rotated second-order cone
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, RotatedSecondOrderCone};
+    ///
+    /// // x[0]^2 + x[1]^2 <= 2 x[2] x[3], x[2] >= 0, x[3] >= 0
+    /// let rsoc = RotatedSecondOrderCone::new();
+    /// let mut x = [1.0, 2.0, 1.0, 0.5];
+    /// rsoc.project(&mut x);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        RotatedSecondOrderCone {}
+    }
+}
+
+/// Maps $(y, z)$ to $((z-y)/\sqrt{2}, (y+z)/\sqrt{2})$; this is an orthogonal
+/// transformation and an involution, so it is its own inverse
+fn rotate(x: &mut [f64]) {
+    let n = x.len();
+    let (y, z) = (x[n - 2], x[n - 1]);
+    x[n - 2] = (z - y) * std::f64::consts::FRAC_1_SQRT_2;
+    x[n - 1] = (y + z) * std::f64::consts::FRAC_1_SQRT_2;
+}
+
+impl Constraint for RotatedSecondOrderCone {
+    /// Project on the rotated second-order cone (updates the given vector/slice)
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) vector to be projected on the rotated second-order cone,
+    ///   (out) projection on the rotated second-order cone
+    ///
+    /// # Panics
+    ///
+    /// The methods panics is the length of `x` is less than 3.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() >= 3, "x must be of dimension at least 3");
+        rotate(x);
+        SecondOrderCone::new(1.0).project(x);
+        rotate(x);
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let n = x.len();
+        assert!(n >= 3, "x must be of dimension at least 3");
+        let (y, z) = (x[n - 2], x[n - 1]);
+        let s = (z - y) * std::f64::consts::FRAC_1_SQRT_2;
+        let t = (y + z) * std::f64::consts::FRAC_1_SQRT_2;
+        let norm_squared = crate::matrix_operations::norm2_squared(&x[..n - 2]) + s * s;
+        soc_distance_squared(1.0, norm_squared.sqrt(), t)
+    }
+}
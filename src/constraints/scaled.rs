@@ -0,0 +1,309 @@
+use super::Constraint;
+use crate::ConstructionError;
+
+/// Default maximum number of iterations of ADMM
+const DEFAULT_MAX_ITERATIONS: usize = 10_000;
+
+/// Default tolerance of ADMM
+const DEFAULT_TOLERANCE: f64 = 1e-10;
+
+/// A scaled set
+///
+/// Given a set $C$ and a diagonal matrix $D$ with nonzero diagonal elements,
+/// this is the set
+///
+/// $$
+/// \\{x \in \mathbb{R}^n {}:{} Dx \in C\\}.
+/// $$
+///
+/// If all diagonal elements of $D$ have the same absolute value, $D$ is a multiple
+/// of an orthogonal matrix and the projection is $D^{-1}\Pi_C(Dv)$. Otherwise, the
+/// projection, which is a weighted projection on $C$, is computed by the alternating
+/// direction method of multipliers (ADMM) applied to the problem
+///
+/// $$
+/// \mathrm{minimize}_{x, z}\ \tfrac{1}{2}\Vert{}x - v{}\Vert^2 \text{ subject to } Dx = z, z \in C,
+/// $$
+///
+/// which only requires projections on $C$; in this case, $C$ must be convex.
+/// ADMM terminates when the infinity norms of the primal and dual residuals do
+/// not exceed a given tolerance (see `with_tolerance`), or when the maximum
+/// number of iterations is reached (see `with_max_iterations`); the returned
+/// point is $D^{-1}z$, which is in the set, but it is only an approximation of
+/// the projection. In particular, if the maximum number of iterations is
+/// reached, the last iterate is returned, however inaccurate.
+///
+/// ADMM allocates four vectors of dimension $n$ in each projection.
+///
+#[derive(Clone)]
+pub struct Scaled<C: Constraint> {
+    set: C,
+    diagonal: Vec<f64>,
+    /// whether all diagonal elements have the same absolute value
+    is_uniform: bool,
+    /// penalty parameter of ADMM
+    rho: f64,
+    max_iterations: usize,
+    tolerance: f64,
+}
+
+impl<C: Constraint> Scaled<C> {
+    /// Construct a new scaled set
+    ///
+    /// # Arguments
+    ///
+    /// - `set`: the set $C$
+    /// - `diagonal`: the diagonal elements of $D$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if any of the elements of `diagonal` is zero or not finite,
+    /// if the diagonal elements do not have the same absolute value and $C$ is
+    /// not convex, or if the dimension of $C$ (see `Constraint::dimension`) is
+    /// known and it is not equal to the length of `diagonal`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // weighted ball-1: |x[0]| + 2|x[1]| <= 1
+    /// let weighted_ball1 = Scaled::new(Ball1::new(None, 1.0), vec![1.0, 2.0])
+    ///     .with_tolerance(1e-12);
+    /// let mut x = [2.0, 2.0];
+    /// weighted_ball1.project(&mut x);
+    /// assert!((x[0].abs() + 2.0 * x[1].abs() - 1.0).abs() < 1e-10);
+    /// ```
+    ///
+    pub fn new(set: C, diagonal: Vec<f64>) -> Self {
+        assert!(
+            diagonal.iter().all(|&di| di != 0.0 && di.is_finite()),
+            "the diagonal elements must be nonzero and finite"
+        );
+        let n = diagonal.len();
+        let is_uniform = diagonal.windows(2).all(|w| w[0].abs() == w[1].abs());
+        assert!(
+            is_uniform || set.is_convex(),
+            "the set must be convex unless all diagonal elements have the same absolute value"
+        );
+        assert!(
+            set.dimension().unwrap_or(n) == n,
+            "the set and the diagonal have incompatible dimensions"
+        );
+        let rho = n as f64 / crate::matrix_operations::norm2_squared(&diagonal);
+        Scaled {
+            set,
+            diagonal,
+            is_uniform,
+            rho,
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    /// Construct a new scaled set, or return an error if the data is invalid
+    /// (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::InvalidParameter` if any of the elements of `diagonal`
+    ///   is zero or not finite
+    /// - `ConstructionError::NonconvexSet` if the diagonal elements do not have the
+    ///   same absolute value and $C$ is not convex
+    /// - `ConstructionError::DimensionMismatch` if the dimension of $C$ is known
+    ///   and it is not equal to the length of `diagonal`
+    ///
+    pub fn try_new(set: C, diagonal: Vec<f64>) -> Result<Self, ConstructionError> {
+        if let Some(&di) = diagonal.iter().find(|&&di| di == 0.0 || !di.is_finite()) {
+            return Err(ConstructionError::InvalidParameter {
+                name: "diagonal",
+                value: di,
+            });
+        }
+        let is_uniform = diagonal.windows(2).all(|w| w[0].abs() == w[1].abs());
+        if !is_uniform && !set.is_convex() {
+            return Err(ConstructionError::NonconvexSet);
+        }
+        if let Some(expected) = set.dimension().filter(|&n| n != diagonal.len()) {
+            return Err(ConstructionError::DimensionMismatch {
+                expected,
+                found: diagonal.len(),
+            });
+        }
+        Ok(Scaled::new(set, diagonal))
+    }
+
+    /// Set the maximum number of iterations of ADMM
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `max_iterations` is zero
+    ///
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> Self {
+        assert!(max_iterations > 0, "max_iterations must be positive");
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Set the maximum number of iterations of ADMM, or return an error if it
+    /// is zero
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `max_iterations` is zero
+    ///
+    pub fn try_with_max_iterations(self, max_iterations: usize) -> Result<Self, ConstructionError> {
+        if max_iterations > 0 {
+            Ok(self.with_max_iterations(max_iterations))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "max_iterations",
+                value: 0.0,
+            })
+        }
+    }
+
+    /// Set the tolerance of ADMM
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `tolerance` is not positive
+    ///
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "tolerance must be positive");
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the tolerance of ADMM, or return an error if it is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `tolerance` is not positive
+    ///
+    pub fn try_with_tolerance(self, tolerance: f64) -> Result<Self, ConstructionError> {
+        if tolerance > 0.0 {
+            Ok(self.with_tolerance(tolerance))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "tolerance",
+                value: tolerance,
+            })
+        }
+    }
+
+    /// The vector $Dx$
+    fn scaled(&self, x: &[f64]) -> Vec<f64> {
+        x.iter()
+            .zip(self.diagonal.iter())
+            .map(|(xi, di)| di * xi)
+            .collect()
+    }
+}
+
+impl<C: Constraint> Constraint for Scaled<C> {
+    /// Projection on the scaled set
+    ///
+    /// Starting from $z = \Pi_C(Dv)$ and $u = 0$, each iteration of ADMM computes
+    ///
+    /// $$
+    /// x \leftarrow (I + \rho D^2)^{-1}(v + \rho D(z - u)),\quad
+    /// z \leftarrow \Pi_C(Dx + u),\quad
+    /// u \leftarrow u + Dx - z.
+    /// $$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the dimension
+    /// of $D$, or if the projection on $C$ panics.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.diagonal.len(), "x has wrong dimension");
+        if self.is_uniform {
+            x.iter_mut()
+                .zip(self.diagonal.iter())
+                .for_each(|(xi, di)| *xi *= di);
+            self.set.project(x);
+            x.iter_mut()
+                .zip(self.diagonal.iter())
+                .for_each(|(xi, di)| *xi /= di);
+            return;
+        }
+        let rho = self.rho;
+        // z = proj(Dv), u = 0
+        let v = x.to_vec();
+        let mut z: Vec<f64> = v
+            .iter()
+            .zip(self.diagonal.iter())
+            .map(|(vi, di)| di * vi)
+            .collect();
+        self.set.project(&mut z);
+        let mut z_previous = vec![0.0; x.len()];
+        let mut u = vec![0.0; x.len()];
+        for _ in 0..self.max_iterations {
+            // x = (I + rho D^2)^{-1}(v + rho D(z - u)), z = Dx + u
+            z_previous.copy_from_slice(&z);
+            x.iter_mut()
+                .zip(self.diagonal.iter())
+                .enumerate()
+                .for_each(|(i, (xi, di))| {
+                    *xi = (v[i] + rho * di * (z[i] - u[i])) / (1.0 + rho * di * di);
+                    z[i] = di * *xi + u[i];
+                });
+            // z = proj(Dx + u)
+            self.set.project(&mut z);
+            // u = u + Dx - z
+            let mut primal_residual: f64 = 0.0;
+            let mut dual_residual: f64 = 0.0;
+            for (i, di) in self.diagonal.iter().enumerate() {
+                let residual = di * x[i] - z[i];
+                primal_residual = primal_residual.max(residual.abs());
+                dual_residual = dual_residual.max((rho * di * (z[i] - z_previous[i])).abs());
+                u[i] += residual;
+            }
+            if primal_residual <= self.tolerance && dual_residual <= self.tolerance {
+                break;
+            }
+        }
+        // x = D^{-1}z is in the set
+        x.iter_mut()
+            .zip(z.iter().zip(self.diagonal.iter()))
+            .for_each(|(xi, (zi, di))| *xi = zi / di);
+    }
+
+    /// A scaled set is convex if and only if $C$ is convex
+    fn is_convex(&self) -> bool {
+        self.set.is_convex()
+    }
+
+    /// If all diagonal elements of $D$ have the same absolute value, $\delta$,
+    /// the distance is $\mathrm{dist}_C(Dx)/\delta$; otherwise, it is computed
+    /// using the (approximate) projection
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        assert!(x.len() == self.diagonal.len(), "x has wrong dimension");
+        match self.diagonal.first() {
+            Some(d0) if self.is_uniform => self.set.distance_squared(&self.scaled(x)) / (d0 * d0),
+            _ => {
+                let mut projection = x.to_vec();
+                self.project(&mut projection);
+                crate::matrix_operations::norm2_squared_diff(x, &projection)
+            }
+        }
+    }
+
+    /// If all diagonal elements of $D$ have the same absolute value, $\delta$,
+    /// this checks whether $Dx$ is in $C$ with tolerance $\delta\epsilon$;
+    /// otherwise, whether $Dx \in C$ or $\mathrm{dist}(x) \leq \epsilon$
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        assert!(x.len() == self.diagonal.len(), "x has wrong dimension");
+        let dx = self.scaled(x);
+        match self.diagonal.first() {
+            Some(d0) if self.is_uniform => self.set.contains(&dx, d0.abs() * tol),
+            _ => self.set.contains(&dx, 0.0) || self.distance(x) <= tol,
+        }
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.diagonal.len())
+    }
+}
@@ -1,4 +1,6 @@
-use super::Constraint;
+use super::active_set::piecewise_linear_root;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
 
 #[derive(Copy, Clone)]
 /// A simplex with level $\alpha$ is a set of the form
@@ -16,74 +18,165 @@ impl Simplex {
         assert!(alpha > 0.0, "alpha is nonpositive");
         Simplex { alpha }
     }
+
+    /// Construct a new simplex with given $\alpha$, or return an error if
+    /// $\alpha$ is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `alpha` is not positive
+    ///
+    pub fn try_new(alpha: f64) -> Result<Self, ConstructionError> {
+        if alpha > 0.0 {
+            Ok(Simplex::new(alpha))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "alpha",
+                value: alpha,
+            })
+        }
+    }
 }
 
 impl Constraint for Simplex {
-    /// Project onto $\Delta_\alpha^n$ using Condat's fast projection algorithm.
+    /// Project onto $\Delta_\alpha^n$, that is, compute $x_i \leftarrow \max\\{x_i - \tau, 0\\}$,
+    /// where the threshold $\tau$ is determined by `simplex_threshold`
+    /// (Condat's algorithm followed by Michelot's passes), without allocating memory.
     ///
     /// See: Laurent Condat. Fast Projection onto the Simplex and the $\ell_1$ Ball.
     /// <em>Mathematical Programming, Series A,</em> Springer, 2016, 158 (1), pp.575-585.
     /// ⟨<a href="https://dx.doi.org/10.1007/s10107-015-0946-6">10.1007/s10107-015-0946-6</a>⟩.
     fn project(&self, x: &mut [f64]) {
-        let a = &self.alpha;
+        let tau = simplex_threshold(x.len(), self.alpha, |i| x[i], |_| 1.0);
+        x.iter_mut().for_each(|xi| *xi = f64::max(*xi - tau, 0.0));
+    }
 
-        // ---- step 1
-        let mut v = Vec::<f64>::with_capacity(x.len()); // vector containing x[0]
-        v.push(x[0]);
-        let mut v_size_old: i64 = -1; // 64 bit signed int
-        let mut v_tilde: Vec<f64> = Vec::new(); // empty vector of f64
-        let mut rho: f64 = x[0] - a; // 64 bit float
+    fn is_convex(&self) -> bool {
+        true
+    }
 
-        // ---- step 2
-        x.iter().skip(1).for_each(|x_n| {
-            if *x_n > rho {
-                rho += (*x_n - rho) / ((v.len() + 1) as f64);
-                if rho > *x_n - a {
-                    v.push(*x_n);
-                } else {
-                    v_tilde.extend(&v);
-                    v = vec![*x_n];
-                    rho = *x_n - a;
-                }
-            }
-        });
+    /// Since $x_i - \max\{x_i - \tau, 0\} = \min\{x_i, \tau\}$, the squared
+    /// distance is $\sum_i \min\{x_i, \tau\}^2$ (no memory is allocated)
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let tau = simplex_threshold(x.len(), self.alpha, |i| x[i], |_| 1.0);
+        x.iter()
+            .fold(0.0, |sum, &xi| sum + f64::min(xi, tau).powi(2))
+    }
+
+    /// Checks whether $x_i \geq -\epsilon$, for all $i$, and
+    /// $|\sum_i x_i - \alpha| \leq \epsilon$
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        x.iter().all(|&xi| xi >= -tol) && (x.iter().sum::<f64>() - self.alpha).abs() <= tol
+    }
+}
 
-        // ---- step 3
-        if !v_tilde.is_empty() {
-            v_tilde.iter().for_each(|v_t_n| {
-                if *v_t_n > rho {
-                    v.push(*v_t_n);
-                    rho += (*v_t_n - rho) / (v.len() as f64);
-                }
-            });
+/// Computes the threshold, $\tau$, which solves
+///
+/// $$
+/// \sum_{i=0}^{n-1} w_i \max\\{v_i - w_i \tau, 0\\} = \alpha,
+/// $$
+///
+/// where $\alpha > 0$ and $w_i > 0$; the projection on the simplex is obtained
+/// with $v = x$ and $w = 1$, and the projection on the (weighted) $\ell_1$ ball
+/// with $v_i = |x_i|$.
+///
+/// Since $\tau \geq (\sum_{i\in S} w_i v_i - \alpha) / \sum_{i\in S} w_i^2$ for any
+/// nonempty set of indices $S$, a single pass of Condat's algorithm (without
+/// revisiting the discarded elements) gives a lower bound on $\tau$. This is
+/// refined by Michelot's method, that is, by replacing $S$ with
+/// $\\{i {}:{} v_i > w_i\tau\\}$, which increases $\tau$ monotonically and
+/// terminates when $S$ no longer changes; this typically takes very few passes.
+///
+/// No memory is allocated: the values $v_i$ and weights $w_i$ are accessed
+/// through the closures `value` and `weight`.
+pub(crate) fn simplex_threshold(
+    n: usize,
+    alpha: f64,
+    value: impl Fn(usize) -> f64,
+    weight: impl Fn(usize) -> f64,
+) -> f64 {
+    // ---- one pass of Condat's algorithm
+    let (v0, w0) = (value(0), weight(0));
+    let mut sum_wv = w0 * v0;
+    let mut sum_ww = w0 * w0;
+    let mut tau = (sum_wv - alpha) / sum_ww;
+    for i in 1..n {
+        let (vi, wi) = (value(i), weight(i));
+        if vi > wi * tau {
+            sum_wv += wi * vi;
+            sum_ww += wi * wi;
+            tau = (sum_wv - alpha) / sum_ww;
+            let tau_i = (wi * vi - alpha) / (wi * wi);
+            if tau <= tau_i {
+                sum_wv = wi * vi;
+                sum_ww = wi * wi;
+                tau = tau_i;
+            }
         }
+    }
 
-        // ---- step 4
-        let mut keep_running = true;
-        while keep_running {
-            let mut hit_list: Vec<usize> = Vec::with_capacity(x.len());
-            let mut current_len_v = v.len() as i64;
-            v.iter().enumerate().for_each(|(n, v_n)| {
-                if *v_n <= rho {
-                    hit_list.push(n);
-                    current_len_v -= 1;
-                    rho += (rho - *v_n) / (current_len_v as f64);
-                }
-            });
-            hit_list.iter().rev().for_each(|target| {
-                // remove in reverse to keep indexing correct
-                v.remove(*target);
-            });
-            keep_running = current_len_v != v_size_old;
-            v_size_old = current_len_v;
+    // ---- Michelot's passes
+    let mut count_previous = n + 1;
+    loop {
+        let (mut sum_wv, mut sum_ww, mut count) = (0.0, 0.0, 0);
+        for i in 0..n {
+            let (vi, wi) = (value(i), weight(i));
+            if vi > wi * tau {
+                sum_wv += wi * vi;
+                sum_ww += wi * wi;
+                count += 1;
+            }
+        }
+        if count == 0 || count >= count_previous {
+            return tau;
         }
+        tau = (sum_wv - alpha) / sum_ww;
+        count_previous = count;
+    }
+}
 
-        // ---- step 6
-        let zero: f64 = 0.0;
-        x.iter_mut().for_each(|x_n| *x_n = zero.max(*x_n - rho));
+impl ActiveSet for Simplex {
+    /// The constraint $\sum_i x_i = \alpha$ is always active (as `Equality(0)`)
+    /// and $x_i \geq 0$ is active if $x_i \leq \epsilon$
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let mut active = vec![ActiveConstraint::Equality(0)];
+        x.iter()
+            .enumerate()
+            .filter(|(_, &xi)| xi <= tol)
+            .for_each(|(i, _)| active.push(ActiveConstraint::LowerBound(i)));
+        active
     }
 
-    fn is_convex(&self) -> bool {
-        true
+    /// The support function is $\sigma(y) = \alpha\max_i y_i$
+    fn support_function(&self, y: &[f64]) -> f64 {
+        self.alpha * y.iter().fold(f64::NEG_INFINITY, |max, &yi| max.max(yi))
+    }
+
+    /// The normal cone is $\\{\mu 1 - s {}:{} \mu\in\mathbb{R}, s \geq 0, s_i = 0
+    /// \text{ if } x_i > \epsilon\\}$
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        let count = x.iter().filter(|&&xi| xi > tol).count();
+        if count == 0 {
+            return;
+        }
+        let sum = x
+            .iter()
+            .zip(y.iter())
+            .filter(|(&xi, _)| xi > tol)
+            .fold(0.0, |sum, (_, yi)| sum + yi);
+        let mut values: Vec<f64> = x
+            .iter()
+            .zip(y.iter())
+            .filter(|(&xi, _)| xi <= tol)
+            .map(|(_, &yi)| yi)
+            .collect();
+        let mu = piecewise_linear_root(count, sum, &mut values);
+        y.iter_mut().zip(x.iter()).for_each(|(yi, &xi)| {
+            if xi > tol {
+                *yi = mu;
+            } else {
+                *yi = yi.min(mu);
+            }
+        });
     }
 }
@@ -1,5 +1,9 @@
-use super::Constraint;
-use crate::matrix_operations;
+use super::{ActiveConstraint, ActiveSet, Constraint};
+use crate::ConstructionError;
+
+/// Tolerance (relative to the norm of $y$) used to decide whether the support
+/// function at $y$ is finite
+const POLAR_TOLERANCE: f64 = 1e-12;
 
 #[derive(Clone, Copy)]
 ///
@@ -13,12 +17,18 @@ use crate::matrix_operations;
 ///
 /// where $\alpha$ is a positive scalar.
 ///
+/// By default, the scalar component, $t$, is the last element of $x$; it can
+/// be placed at any other index using [`with_scalar_index`].
+///
 /// Projections on the second-order cone are computed as in H.H. Bauschke's
 /// 1996 doctoral dissertation: Projection Algorithms and Monotone Operators
 /// (p. 40, Theorem 3.3.6).
 ///
+/// [`with_scalar_index`]: struct.SecondOrderCone.html#method.with_scalar_index
+///
 pub struct SecondOrderCone {
     alpha: f64,
+    scalar_index: Option<usize>,
 }
 
 impl SecondOrderCone {
@@ -41,7 +51,56 @@ impl SecondOrderCone {
     /// The method panics if the given parameter `alpha` is nonpositive.
     pub fn new(alpha: f64) -> SecondOrderCone {
         assert!(alpha > 0.0); // alpha must be positive
-        SecondOrderCone { alpha }
+        SecondOrderCone {
+            alpha,
+            scalar_index: None,
+        }
+    }
+
+    /// Construct a new instance of SecondOrderCone with parameter `alpha`, or
+    /// return an error if `alpha` is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `alpha` is not positive
+    ///
+    pub fn try_new(alpha: f64) -> Result<SecondOrderCone, ConstructionError> {
+        if alpha > 0.0 {
+            Ok(SecondOrderCone::new(alpha))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "alpha",
+                value: alpha,
+            })
+        }
+    }
+
+    /// Place the scalar component of the cone at a given index
+    ///
+    /// The second-order cone becomes the set of all $x\in\mathbb{R}^{n+1}$
+    /// such that $\Vert{}(x_0, \ldots, x_{i-1}, x_{i+1}, \ldots, x_n)\Vert \leq \alpha x_i$,
+    /// where $i$ is the given index. This way, quadratic-over-linear constraints
+    /// can be imposed without having to reorder the decision variables.
+    ///
+    /// # Arguments
+    ///
+    /// - `index`: index of the scalar component, $t$
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, SecondOrderCone};
+    ///
+    /// // ||(x[1], x[2])|| <= x[0]
+    /// let soc = SecondOrderCone::new(1.0).with_scalar_index(0);
+    /// let mut x = [1.0, 3.0, 4.0];
+    /// soc.project(&mut x);
+    /// assert!((x[0] - 3.0).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn with_scalar_index(mut self, index: usize) -> SecondOrderCone {
+        self.scalar_index = Some(index);
+        self
     }
 }
 
@@ -55,27 +114,109 @@ impl Constraint for SecondOrderCone {
     ///
     /// # Panics
     ///
-    /// The methods panics is the length of `x` is less than 2.
+    /// The methods panics is the length of `x` is less than 2, or if the index of
+    /// the scalar component is not smaller than the length of `x`.
     ///
     fn project(&self, x: &mut [f64]) {
         // x = (z, r)
-        let n = x.len();
-        assert!(n >= 2, "x must be of dimension at least 2");
-        let z = &x[..n - 1];
-        let r = x[n - 1];
-        let norm_z = matrix_operations::norm2(z);
+        let (idx, norm_z) = self.split(x);
+        let r = x[idx];
         if self.alpha * norm_z <= -r {
             x.iter_mut().for_each(|v| *v = 0.0);
         } else if norm_z > self.alpha * r {
             let beta = (self.alpha * norm_z + r) / (self.alpha.powi(2) + 1.0);
-            x[..n - 1]
-                .iter_mut()
-                .for_each(|v| *v *= self.alpha * beta / norm_z);
-            x[n - 1] = beta;
+            x.iter_mut().for_each(|v| *v *= self.alpha * beta / norm_z);
+            x[idx] = beta;
         }
     }
 
     fn is_convex(&self) -> bool {
         true
     }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let (idx, norm_z) = self.split(x);
+        soc_distance_squared(self.alpha, norm_z, x[idx])
+    }
+}
+
+impl SecondOrderCone {
+    /// Index of the scalar component and norm of the vector component of `x`
+    fn split(&self, x: &[f64]) -> (usize, f64) {
+        let n = x.len();
+        assert!(n >= 2, "x must be of dimension at least 2");
+        let idx = self.scalar_index.unwrap_or(n - 1);
+        assert!(idx < n, "the scalar index is out of bounds");
+        let norm_z = x
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != idx)
+            .fold(0.0, |sum, (_, v)| sum + v * v)
+            .sqrt();
+        (idx, norm_z)
+    }
+}
+
+impl ActiveSet for SecondOrderCone {
+    /// The constraint is active if $\Vert{}z{}\Vert - \alpha t \geq -\epsilon\sqrt{1+\alpha^2}$,
+    /// where $x = (z, t)$ (this includes the apex of the cone)
+    fn active_constraints(&self, x: &[f64], tol: f64) -> Vec<ActiveConstraint> {
+        let (idx, norm_z) = self.split(x);
+        if norm_z - self.alpha * x[idx] >= -tol * (1.0 + self.alpha.powi(2)).sqrt() {
+            vec![ActiveConstraint::Boundary]
+        } else {
+            vec![]
+        }
+    }
+
+    /// The support function of a cone is $0$ on its polar cone, which is the set of
+    /// all $y = (w, s)$ with $\alpha\Vert{}w{}\Vert \leq -s$, and $+\infty$ elsewhere
+    fn support_function(&self, y: &[f64]) -> f64 {
+        let (idx, norm_w) = self.split(y);
+        let tol = POLAR_TOLERANCE * crate::matrix_operations::norm2(y);
+        if self.alpha * norm_w + y[idx] <= tol {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// In the interior of the cone, the normal cone is $\\{0\\}$; at the apex, it
+    /// is the polar cone, so the projection is computed using Moreau's decomposition,
+    /// $\Pi_{N}(y) = y - \Pi_{C_\alpha}(y)$; elsewhere on the boundary, it is the ray
+    /// generated by $(z/\Vert{}z{}\Vert, -\alpha)$, where $x = (z, t)$
+    fn project_on_normal_cone(&self, x: &[f64], y: &mut [f64], tol: f64) {
+        if self.active_constraints(x, tol).is_empty() {
+            y.iter_mut().for_each(|yi| *yi = 0.0);
+            return;
+        }
+        let (idx, norm_z) = self.split(x);
+        if crate::matrix_operations::norm2(x) <= tol {
+            let mut y_cone = y.to_vec();
+            self.project(&mut y_cone);
+            y.iter_mut()
+                .zip(y_cone.iter())
+                .for_each(|(yi, ci)| *yi -= ci);
+            return;
+        }
+        let mut direction: Vec<f64> = x.iter().map(|xi| xi / norm_z).collect();
+        direction[idx] = -self.alpha;
+        let factor = crate::matrix_operations::inner_product(y, &direction).max(0.0)
+            / (1.0 + self.alpha.powi(2));
+        y.iter_mut()
+            .zip(direction.iter())
+            .for_each(|(yi, di)| *yi = factor * di);
+    }
+}
+
+/// Squared distance of $(z, t)$ from the second-order cone $C_\alpha$, given
+/// $\Vert{}z{}\Vert$ and $t$
+pub(super) fn soc_distance_squared(alpha: f64, norm_z: f64, t: f64) -> f64 {
+    if norm_z <= alpha * t {
+        0.0
+    } else if alpha * norm_z <= -t {
+        norm_z * norm_z + t * t
+    } else {
+        (norm_z - alpha * t).powi(2) / (alpha * alpha + 1.0)
+    }
 }
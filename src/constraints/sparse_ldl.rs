@@ -0,0 +1,292 @@
+/// Pivots of the factorisation of $AA^\intercal$ which are smaller than this
+/// tolerance (relative to the corresponding diagonal element) are treated as
+/// zero, that is, the corresponding rows of $A$ are linearly dependent
+const PIVOT_TOLERANCE: f64 = 1e-12;
+
+/// A sparse matrix in compressed sparse row (CSR) format
+///
+/// The column indices and values of the nonzero elements of row `i` are
+/// stored at positions `row_offsets[i]..row_offsets[i + 1]` of `col_indices`
+/// and `values`.
+#[derive(Clone)]
+pub(super) struct CsrMatrix {
+    n_cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl CsrMatrix {
+    /// Constructs a CSR matrix (the data is assumed to be valid)
+    pub(super) fn new(
+        n_cols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<f64>,
+    ) -> Self {
+        CsrMatrix {
+            n_cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Number of rows
+    pub(super) fn n_rows(&self) -> usize {
+        self.row_offsets.len() - 1
+    }
+
+    /// Values of the nonzero elements
+    pub(super) fn values_mut(&mut self) -> &mut [f64] {
+        &mut self.values
+    }
+
+    /// Column indices and values of the nonzero elements of row `i`
+    pub(super) fn row(&self, i: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.col_indices[range.clone()]
+            .iter()
+            .copied()
+            .zip(self.values[range].iter().copied())
+    }
+
+    /// Inner product of row `i` with `x`
+    pub(super) fn row_dot(&self, i: usize, x: &[f64]) -> f64 {
+        self.row(i).fold(0.0, |sum, (j, aij)| sum + aij * x[j])
+    }
+
+    /// The transpose of the matrix, in CSR format (that is, the matrix in
+    /// compressed sparse column format)
+    fn transpose(&self) -> CsrMatrix {
+        let mut row_offsets = vec![0; self.n_cols + 1];
+        self.col_indices
+            .iter()
+            .for_each(|&j| row_offsets[j + 1] += 1);
+        for j in 0..self.n_cols {
+            row_offsets[j + 1] += row_offsets[j];
+        }
+        let mut next = row_offsets.clone();
+        let mut col_indices = vec![0; self.values.len()];
+        let mut values = vec![0.0; self.values.len()];
+        for i in 0..self.n_rows() {
+            for (j, aij) in self.row(i) {
+                col_indices[next[j]] = i;
+                values[next[j]] = aij;
+                next[j] += 1;
+            }
+        }
+        CsrMatrix::new(self.n_rows(), row_offsets, col_indices, values)
+    }
+}
+
+/// $LDL^\intercal$ factorisation of $PAA^\intercal P^\intercal$, where $A$ is
+/// a sparse matrix and $P$ is a permutation matrix
+///
+/// The rows of $A$ are reordered with the reverse Cuthill-McKee algorithm, which
+/// reduces the envelope (profile) of $AA^\intercal$; the factor $L$ is stored by
+/// rows, each of which only extends from its first nonzero element to the
+/// diagonal, since there is no fill-in outside the envelope. This is very
+/// efficient for the banded matrices which arise, e.g., from the dynamics of
+/// multiple-shooting formulations of optimal control problems.
+///
+/// Linearly dependent rows of $A$ lead to zero pivots (since $AA^\intercal$ is
+/// positive semidefinite, the rest of the corresponding column of the Schur
+/// complement vanishes too); these are detected and the corresponding elements
+/// of $D$ are set to zero.
+#[derive(Clone)]
+pub(super) struct SparseLdl {
+    /// `perm[i]` is the row of $A$ at position `i` of the ordering
+    perm: Vec<usize>,
+    /// first column of the envelope of each row of $L$
+    first: Vec<usize>,
+    /// elements `first[i]..i` of row `i` of $L$ are stored at `offsets[i]..offsets[i + 1]`
+    offsets: Vec<usize>,
+    /// strictly lower triangular part of $L$ (by rows, within the envelope)
+    l: Vec<f64>,
+    /// diagonal of $D$ (zero for linearly dependent rows)
+    d: Vec<f64>,
+}
+
+impl SparseLdl {
+    /// Computes the ordering, the envelope and the factorisation of $AA^\intercal$
+    pub(super) fn new(a: &CsrMatrix) -> Self {
+        let m = a.n_rows();
+        let a_t = a.transpose();
+
+        // adjacency lists of the graph of AA'
+        let mut marker = vec![usize::MAX; m];
+        let adjacency: Vec<Vec<usize>> = (0..m)
+            .map(|p| {
+                let mut neighbours = vec![];
+                for (k, _) in a.row(p) {
+                    for (q, _) in a_t.row(k) {
+                        if q != p && marker[q] != p {
+                            marker[q] = p;
+                            neighbours.push(q);
+                        }
+                    }
+                }
+                neighbours
+            })
+            .collect();
+
+        // reverse Cuthill-McKee ordering (breadth-first search from nodes of
+        // minimum degree, visiting the neighbours by increasing degree)
+        let mut nodes: Vec<usize> = (0..m).collect();
+        nodes.sort_by_key(|&p| adjacency[p].len());
+        let mut visited = vec![false; m];
+        let mut perm = Vec::with_capacity(m);
+        for &start in nodes.iter() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let mut head = perm.len();
+            perm.push(start);
+            while head < perm.len() {
+                let p = perm[head];
+                head += 1;
+                let mut neighbours: Vec<usize> = adjacency[p]
+                    .iter()
+                    .copied()
+                    .filter(|&q| !visited[q])
+                    .collect();
+                neighbours.sort_by_key(|&q| adjacency[q].len());
+                for q in neighbours {
+                    visited[q] = true;
+                    perm.push(q);
+                }
+            }
+        }
+        perm.reverse();
+
+        // envelope
+        let mut inverse_perm = vec![0; m];
+        perm.iter()
+            .enumerate()
+            .for_each(|(i, &p)| inverse_perm[p] = i);
+        let first: Vec<usize> = (0..m)
+            .map(|i| {
+                adjacency[perm[i]]
+                    .iter()
+                    .map(|&q| inverse_perm[q])
+                    .fold(i, usize::min)
+            })
+            .collect();
+        let mut offsets = vec![0; m + 1];
+        for i in 0..m {
+            offsets[i + 1] = offsets[i] + i - first[i];
+        }
+
+        let mut ldl = SparseLdl {
+            perm,
+            first,
+            l: vec![0.0; offsets[m]],
+            offsets,
+            d: vec![0.0; m],
+        };
+        ldl.factorize(a);
+        ldl
+    }
+
+    /// Recomputes the factorisation for a matrix with the same sparsity pattern
+    pub(super) fn factorize(&mut self, a: &CsrMatrix) {
+        let m = self.d.len();
+        let a_t = a.transpose();
+        let mut inverse_perm = vec![0; m];
+        self.perm
+            .iter()
+            .enumerate()
+            .for_each(|(i, &p)| inverse_perm[p] = i);
+        self.l.iter_mut().for_each(|lij| *lij = 0.0);
+
+        for i in 0..m {
+            let fi = self.first[i];
+            let row_i = self.offsets[i];
+
+            // row i of PAA'P' (lower triangular part)
+            let mut m_ii = 0.0;
+            for (k, apk) in a.row(self.perm[i]) {
+                for (q, aqk) in a_t.row(k) {
+                    let j = inverse_perm[q];
+                    if j < i {
+                        self.l[row_i + j - fi] += apk * aqk;
+                    } else if j == i {
+                        m_ii += apk * aqk;
+                    }
+                }
+            }
+
+            // L(i, j) = (M(i, j) - sum_k L(i, k) D(k) L(j, k)) / D(j)
+            for j in fi..i {
+                let fj = self.first[j];
+                let start = fi.max(fj);
+                let mut s = self.l[row_i + j - fi];
+                for k in start..j {
+                    s -= self.l[row_i + k - fi] * self.d[k] * self.l[self.offsets[j] + k - fj];
+                }
+                self.l[row_i + j - fi] = if self.d[j] > 0.0 { s / self.d[j] } else { 0.0 };
+            }
+
+            // D(i) = M(i, i) - sum_k L(i, k)^2 D(k)
+            let mut d_i = m_ii;
+            for k in fi..i {
+                let lik = self.l[row_i + k - fi];
+                d_i -= lik * lik * self.d[k];
+            }
+            self.d[i] = if d_i > PIVOT_TOLERANCE * m_ii {
+                d_i
+            } else {
+                0.0
+            };
+        }
+    }
+
+    /// Returns true if and only if $r$ is in the range of $AA^\intercal$, up to
+    /// the given tolerance, that is, if the elements of $L^{-1}Pr$ which
+    /// correspond to zero pivots vanish; `y` is a workspace
+    pub(super) fn in_range(&self, r: &[f64], y: &mut [f64], tol: f64) -> bool {
+        y.iter_mut()
+            .zip(self.perm.iter())
+            .for_each(|(yi, &p)| *yi = r[p]);
+        self.solve_lower(y);
+        y.iter()
+            .zip(self.d.iter())
+            .all(|(yi, &di)| di > 0.0 || yi.abs() <= tol)
+    }
+
+    /// Solves $Lz = y$ (the result is stored in `y`)
+    fn solve_lower(&self, y: &mut [f64]) {
+        for i in 0..y.len() {
+            let fi = self.first[i];
+            let row = &self.l[self.offsets[i]..self.offsets[i + 1]];
+            y[i] -= row
+                .iter()
+                .zip(y[fi..i].iter())
+                .fold(0.0, |sum, (lik, yk)| sum + lik * yk);
+        }
+    }
+
+    /// Solves $PAA^\intercal P^\intercal z = y$ (the result is stored in `y`);
+    /// the elements of $z$ which correspond to zero pivots are set to zero
+    pub(super) fn solve(&self, y: &mut [f64]) {
+        self.solve_lower(y);
+        y.iter_mut().zip(self.d.iter()).for_each(|(yi, &di)| {
+            *yi = if di > 0.0 { *yi / di } else { 0.0 };
+        });
+        for i in (0..y.len()).rev() {
+            let fi = self.first[i];
+            let yi = y[i];
+            self.l[self.offsets[i]..self.offsets[i + 1]]
+                .iter()
+                .zip(y[fi..i].iter_mut())
+                .for_each(|(lik, yk)| *yk -= lik * yi);
+        }
+    }
+
+    /// Position-to-row permutation of the ordering
+    pub(super) fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+}
@@ -0,0 +1,97 @@
+use super::stiefel::polar_factor;
+use super::Constraint;
+use crate::ConstructionError;
+
+#[derive(Clone, Copy)]
+///
+/// The special orthogonal group
+///
+/// The set of $n\times n$ rotation matrices, that is,
+///
+/// $$
+/// \mathrm{SO}(n) = \\{R \in \mathbb{R}^{n\times n} {}:{} R^\intercal R = I, \det R = 1\\}.
+/// $$
+///
+/// Typically $n = 3$ (rotations in the three-dimensional space). Matrices are
+/// stored column-wise in slices of length $n^2$.
+///
+/// This is a nonconvex set. The projection of a matrix $M = U\Sigma V^\intercal$
+/// (singular value decomposition) on $\mathrm{SO}(n)$ is $UDV^\intercal$, where
+/// $D = \mathrm{diag}(1, \ldots, 1, \det(UV^\intercal))$ and the last singular
+/// value is the smallest one.
+///
+pub struct SpecialOrthogonal {
+    n: usize,
+}
+
+impl SpecialOrthogonal {
+    /// Construct a new instance of the set of $n\times n$ rotation matrices
+    ///
+    /// # Arguments
+    ///
+    /// - `n`: size of the matrices (e.g., 3 for three-dimensional rotations)
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n` is zero
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, SpecialOrthogonal};
+    ///
+    /// let so3 = SpecialOrthogonal::new(3);
+    /// let mut x = [1.0, 0.1, 0.0, -0.1, 1.0, 0.0, 0.0, 0.0, 0.9];
+    /// so3.project(&mut x);
+    /// ```
+    ///
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "n must be positive");
+        SpecialOrthogonal { n }
+    }
+
+    /// Construct a new instance of the set of $n\times n$ rotation matrices, or
+    /// return an error if `n` is zero
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `n` is zero
+    ///
+    pub fn try_new(n: usize) -> Result<Self, ConstructionError> {
+        if n > 0 {
+            Ok(SpecialOrthogonal::new(n))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "n",
+                value: 0.0,
+            })
+        }
+    }
+}
+
+impl Constraint for SpecialOrthogonal {
+    /// Project on the special orthogonal group
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) $n\times n$ matrix to be projected, stored column-wise,
+    ///   (out) projection on $\mathrm{SO}(n)$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to $n^2$.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.n * self.n, "x has wrong dimension");
+        polar_factor(x, self.n, self.n, true);
+    }
+
+    /// The special orthogonal group is nonconvex
+    fn is_convex(&self) -> bool {
+        false
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.n * self.n)
+    }
+}
@@ -1,4 +1,5 @@
 use super::Constraint;
+use crate::ConstructionError;
 
 #[derive(Copy, Clone)]
 /// A Euclidean sphere, that is, a set given by $S_2^r = \\{x \in \mathbb{R}^n {}:{} \Vert{}x{}\Vert = r\\}$
@@ -15,6 +16,24 @@ impl<'a> Sphere2<'a> {
         assert!(radius > 0.0);
         Sphere2 { center, radius }
     }
+
+    /// Construct a new sphere with given center and radius, or return an
+    /// error if the radius is not positive
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `radius` is not positive
+    ///
+    pub fn try_new(center: Option<&'a [f64]>, radius: f64) -> Result<Self, ConstructionError> {
+        if radius > 0.0 {
+            Ok(Sphere2::new(center, radius))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            })
+        }
+    }
 }
 
 impl<'a> Constraint for Sphere2<'a> {
@@ -61,4 +80,17 @@ impl<'a> Constraint for Sphere2<'a> {
     fn is_convex(&self) -> bool {
         false
     }
+
+    /// Distance from the sphere, $|\Vert{}x-x_c{}\Vert - r|$ (squared)
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        let norm_difference = match &self.center {
+            Some(center) => crate::matrix_operations::norm2_squared_diff(x, center).sqrt(),
+            None => crate::matrix_operations::norm2(x),
+        };
+        (norm_difference - self.radius).powi(2)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.center.map(|center| center.len())
+    }
 }
@@ -0,0 +1,261 @@
+use super::Constraint;
+use crate::{matrix_operations, ConstructionError};
+
+/// Maximum number of sweeps of the one-sided Jacobi method
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+#[derive(Clone, Copy)]
+///
+/// The Stiefel manifold
+///
+/// The set of $n\times p$ matrices with orthonormal columns, that is,
+///
+/// $$
+/// \mathrm{St}(n, p) = \\{X \in \mathbb{R}^{n\times p} {}:{} X^\intercal X = I_p\\},
+/// $$
+///
+/// where $n \geq p$. Matrices are stored column-wise in slices of length $np$.
+///
+/// This is a nonconvex set. The projection of a matrix $M = U\Sigma V^\intercal$
+/// (singular value decomposition) on the Stiefel manifold is its polar factor,
+/// $UV^\intercal$, which is computed using the one-sided Jacobi method.
+///
+pub struct Stiefel {
+    n_rows: usize,
+    n_cols: usize,
+}
+
+impl Stiefel {
+    /// Construct a new instance of the Stiefel manifold of $n\times p$ matrices
+    /// with orthonormal columns
+    ///
+    /// # Arguments
+    ///
+    /// - `n_rows`: number of rows, $n$
+    /// - `n_cols`: number of columns, $p$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `n_cols` is zero or larger than `n_rows`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, Stiefel};
+    ///
+    /// // 3x2 matrices with orthonormal columns (stored column-wise)
+    /// let stiefel = Stiefel::new(3, 2);
+    /// let mut x = [1.0, 0.1, 0.0, 0.2, 2.0, 0.5];
+    /// stiefel.project(&mut x);
+    /// ```
+    ///
+    pub fn new(n_rows: usize, n_cols: usize) -> Self {
+        assert!(
+            n_cols > 0 && n_cols <= n_rows,
+            "the number of columns must be positive and not larger than the number of rows"
+        );
+        Stiefel { n_rows, n_cols }
+    }
+
+    /// Construct a new instance of the Stiefel manifold, or return an error if
+    /// the number of columns is invalid
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `n_cols` is zero or larger
+    /// than `n_rows`
+    ///
+    pub fn try_new(n_rows: usize, n_cols: usize) -> Result<Self, ConstructionError> {
+        if n_cols > 0 && n_cols <= n_rows {
+            Ok(Stiefel::new(n_rows, n_cols))
+        } else {
+            Err(ConstructionError::InvalidParameter {
+                name: "n_cols",
+                value: n_cols as f64,
+            })
+        }
+    }
+}
+
+/// Replaces the $n\times p$ matrix $M = U\Sigma V^\intercal$, stored column-wise
+/// in `m`, with its polar factor, $UV^\intercal$
+///
+/// If `positive_determinant` is `true` (only for square matrices), the sign of
+/// the column of $U$ which corresponds to the smallest singular value is changed,
+/// if necessary, so that the result has a positive determinant.
+pub(super) fn polar_factor(
+    m: &mut [f64],
+    n_rows: usize,
+    n_cols: usize,
+    positive_determinant: bool,
+) {
+    // One-sided Jacobi: M V = U Sigma (the columns of M are orthogonalised)
+    let mut v = vec![0.0; n_cols * n_cols];
+    (0..n_cols).for_each(|i| v[i * n_cols + i] = 1.0);
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut rotated = false;
+        for j in 0..n_cols {
+            for k in j + 1..n_cols {
+                let (col_j, col_k) = (
+                    &m[j * n_rows..(j + 1) * n_rows],
+                    &m[k * n_rows..(k + 1) * n_rows],
+                );
+                let alpha = matrix_operations::norm2_squared(col_j);
+                let beta = matrix_operations::norm2_squared(col_k);
+                let gamma = matrix_operations::inner_product(col_j, col_k);
+                if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() || gamma == 0.0 {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+                let s = c * t;
+                for i in 0..n_rows {
+                    let (m_ij, m_ik) = (m[j * n_rows + i], m[k * n_rows + i]);
+                    m[j * n_rows + i] = c * m_ij - s * m_ik;
+                    m[k * n_rows + i] = s * m_ij + c * m_ik;
+                }
+                for i in 0..n_cols {
+                    let (v_ij, v_ik) = (v[j * n_cols + i], v[k * n_cols + i]);
+                    v[j * n_cols + i] = c * v_ij - s * v_ik;
+                    v[k * n_cols + i] = s * v_ij + c * v_ik;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    // U: normalise the columns of MV; columns that correspond to (numerically)
+    // zero singular values are completed to an orthonormal set
+    let mut sigma: Vec<f64> = (0..n_cols)
+        .map(|j| matrix_operations::norm2(&m[j * n_rows..(j + 1) * n_rows]))
+        .collect();
+    let sigma_max = sigma.iter().cloned().fold(0.0, f64::max);
+    let tolerance = f64::EPSILON * (n_rows as f64) * sigma_max;
+    let mut u = vec![0.0; n_rows * n_cols];
+    let (nonzero, zero): (Vec<usize>, Vec<usize>) =
+        (0..n_cols).partition(|&j| sigma[j] > tolerance);
+    for &j in nonzero.iter() {
+        u[j * n_rows..(j + 1) * n_rows]
+            .iter_mut()
+            .zip(m[j * n_rows..(j + 1) * n_rows].iter())
+            .for_each(|(uij, mij)| *uij = mij / sigma[j]);
+    }
+    let mut completed = nonzero;
+    let mut w = vec![0.0; n_rows];
+    let mut best_w = vec![0.0; n_rows];
+    for &j in zero.iter() {
+        // Gram-Schmidt on the vector of the standard basis with the largest
+        // component orthogonal to the columns computed so far (since there are
+        // fewer than n such columns, its norm is at least 1/sqrt(n))
+        let mut best_norm = 0.0;
+        for basis_index in 0..n_rows {
+            w.iter_mut().for_each(|wi| *wi = 0.0);
+            w[basis_index] = 1.0;
+            for _ in 0..2 {
+                for &l in completed.iter() {
+                    let u_l = &u[l * n_rows..(l + 1) * n_rows];
+                    let projection = matrix_operations::inner_product(&w, u_l);
+                    w.iter_mut()
+                        .zip(u_l.iter())
+                        .for_each(|(wi, ui)| *wi -= projection * ui);
+                }
+            }
+            let norm_w = matrix_operations::norm2(&w);
+            if norm_w > best_norm {
+                best_norm = norm_w;
+                best_w.copy_from_slice(&w);
+            }
+        }
+        u[j * n_rows..(j + 1) * n_rows]
+            .iter_mut()
+            .zip(best_w.iter())
+            .for_each(|(uij, wi)| *uij = wi / best_norm);
+        sigma[j] = 0.0;
+        completed.push(j);
+    }
+
+    if positive_determinant
+        && n_rows == n_cols
+        && determinant(&u, n_rows) * determinant(&v, n_cols) < 0.0
+    {
+        let j_min = (0..n_cols)
+            .min_by(|&a, &b| sigma[a].partial_cmp(&sigma[b]).unwrap())
+            .unwrap();
+        u[j_min * n_rows..(j_min + 1) * n_rows]
+            .iter_mut()
+            .for_each(|uij| *uij = -*uij);
+    }
+
+    // M = U V' (column-wise)
+    for c in 0..n_cols {
+        for r in 0..n_rows {
+            m[c * n_rows + r] = (0..n_cols)
+                .map(|j| u[j * n_rows + r] * v[j * n_cols + c])
+                .sum();
+        }
+    }
+}
+
+/// Determinant of a square matrix (computed by Gaussian elimination with
+/// partial pivoting)
+fn determinant(a: &[f64], n: usize) -> f64 {
+    let mut lu = a.to_vec();
+    let mut det = 1.0;
+    for k in 0..n {
+        let pivot_row = (k..n)
+            .max_by(|&i, &j| {
+                lu[i * n + k]
+                    .abs()
+                    .partial_cmp(&lu[j * n + k].abs())
+                    .unwrap()
+            })
+            .unwrap();
+        if lu[pivot_row * n + k] == 0.0 {
+            return 0.0;
+        }
+        if pivot_row != k {
+            (0..n).for_each(|j| lu.swap(k * n + j, pivot_row * n + j));
+            det = -det;
+        }
+        det *= lu[k * n + k];
+        for i in k + 1..n {
+            let factor = lu[i * n + k] / lu[k * n + k];
+            (k..n).for_each(|j| lu[i * n + j] -= factor * lu[k * n + j]);
+        }
+    }
+    det
+}
+
+impl Constraint for Stiefel {
+    /// Project on the Stiefel manifold
+    ///
+    /// # Arguments
+    ///
+    /// - `x`: (in) $n\times p$ matrix to be projected, stored column-wise,
+    ///   (out) projection on the Stiefel manifold
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the length of `x` is not equal to $np$.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(
+            x.len() == self.n_rows * self.n_cols,
+            "x has wrong dimension"
+        );
+        polar_factor(x, self.n_rows, self.n_cols, false);
+    }
+
+    /// The Stiefel manifold is nonconvex
+    fn is_convex(&self) -> bool {
+        false
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.n_rows * self.n_cols)
+    }
+}
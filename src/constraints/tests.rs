@@ -1,5 +1,4 @@
 use super::*;
-use modcholesky::ModCholeskySE99;
 use rand;
 
 #[test]
@@ -463,11 +462,101 @@ fn t_second_order_cone_short_vector() {
     soc.project(&mut _x);
 }
 
+#[test]
+fn t_second_order_cone_scalar_index() {
+    let alpha = 0.8;
+    let soc_last = SecondOrderCone::new(alpha);
+    let soc_first = SecondOrderCone::new(alpha).with_scalar_index(0);
+    let soc_middle = SecondOrderCone::new(alpha).with_scalar_index(1);
+    for _ in 0..100 {
+        let v: Vec<f64> = (0..4).map(|_| 2. * rand::random::<f64>() - 1.).collect();
+        let mut x_last = v.clone();
+        soc_last.project(&mut x_last);
+        let mut x_first = vec![v[3], v[0], v[1], v[2]];
+        soc_first.project(&mut x_first);
+        let mut x_middle = vec![v[0], v[3], v[1], v[2]];
+        soc_middle.project(&mut x_middle);
+        unit_test_utils::assert_nearly_equal_array(
+            &x_last,
+            &[x_first[1], x_first[2], x_first[3], x_first[0]],
+            1e-12,
+            1e-14,
+            "wrong projection (scalar index = 0)",
+        );
+        unit_test_utils::assert_nearly_equal_array(
+            &x_last,
+            &[x_middle[0], x_middle[2], x_middle[3], x_middle[1]],
+            1e-12,
+            1e-14,
+            "wrong projection (scalar index = 1)",
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_second_order_cone_scalar_index_out_of_bounds() {
+    let soc = SecondOrderCone::new(1.0).with_scalar_index(3);
+    let mut x = [1.0, 2.0, 3.0];
+    soc.project(&mut x);
+}
+
+#[test]
+fn t_rotated_second_order_cone_inside() {
+    let rsoc = RotatedSecondOrderCone::new();
+    let mut x = [1.0, -1.0, 2.0, 0.6];
+    let x_copy = x;
+    rsoc.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&x, &x_copy, 1e-12, 1e-14, "x modified");
+}
+
+#[test]
+fn t_rotated_second_order_cone_polar() {
+    // the polar cone is {(u, y, z): ||u||^2 <= 2yz, y <= 0, z <= 0}
+    let rsoc = RotatedSecondOrderCone::new();
+    let mut x = [1.0, -1.0, -2.0, -0.6];
+    rsoc.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf(&x) < 1e-14, "not zero");
+}
+
+#[test]
+fn t_rotated_second_order_cone_random() {
+    let rsoc = RotatedSecondOrderCone::new();
+    for _ in 0..1000 {
+        let v: Vec<f64> = (0..5).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+        let mut p = v.clone();
+        rsoc.project(&mut p);
+        // p is in the cone (on the boundary)
+        let norm_u_sq = crate::matrix_operations::norm2_squared(&p[..3]);
+        assert!(p[3] >= -1e-12 && p[4] >= -1e-12, "p not in the cone");
+        assert!(norm_u_sq <= 2.0 * p[3] * p[4] + 1e-10, "p not in the cone");
+        // v - p is orthogonal to p and p is optimal (compared to random points
+        // in the cone)
+        let d: Vec<f64> = v.iter().zip(p.iter()).map(|(a, b)| a - b).collect();
+        assert!(crate::matrix_operations::inner_product(&d, &p).abs() < 1e-10);
+        for _ in 0..50 {
+            let mut q: Vec<f64> = (0..5).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+            q[3] = q[3].abs();
+            q[4] = crate::matrix_operations::norm2_squared(&q[..3]) / (2.0 * q[3]) + q[4].abs();
+            let diff: Vec<f64> = q.iter().zip(p.iter()).map(|(a, b)| a - b).collect();
+            assert!(crate::matrix_operations::inner_product(&d, &diff) <= 1e-10);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_rotated_second_order_cone_short_vector() {
+    let rsoc = RotatedSecondOrderCone::new();
+    let mut x = [1.0, 2.0];
+    rsoc.project(&mut x);
+}
+
 #[test]
 fn t_cartesian_product_dimension() {
     let data: &[&[f64]] = &[&[0.0, 0.0], &[1.0, 1.0]];
     let finite_set = FiniteSet::new(data);
-    let finite_set_2 = finite_set;
+    let finite_set_2 = finite_set.clone();
     let ball = Ball2::new(None, 1.0);
     let no_constraints = NoConstraints::new();
     let cartesian = CartesianProduct::new_with_capacity(4)
@@ -609,6 +698,7 @@ fn t_is_convex_finite_set() {
 fn t_is_convex_soc() {
     let soc = SecondOrderCone::new(2.0);
     assert!(soc.is_convex());
+    assert!(RotatedSecondOrderCone::new().is_convex());
 }
 
 #[test]
@@ -941,3 +1031,3162 @@ fn t_affine_space_wrong_dimensions() {
     let b = vec![1., 2., -0.5];
     let _ = AffineSpace::new(a, b);
 }
+
+#[test]
+fn t_ellipsoid_inside() {
+    let q = vec![2.0, 0.5, 0.5, 1.0];
+    let ellipsoid = Ellipsoid::new(q, None, 1.0);
+    let mut x = [0.2, -0.3];
+    ellipsoid.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.2, -0.3], &x, 1e-12, 1e-14, "x modified");
+}
+
+#[test]
+fn t_ellipsoid_identity_is_ball() {
+    let q = vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+    let center = [1.0, -2.0, 0.5];
+    let radius: f64 = 1.5;
+    let ellipsoid = Ellipsoid::new(q, Some(center.to_vec()), radius.powi(2));
+    let ball = Ball2::new(Some(&center), radius);
+    let mut x = [4.0, 3.0, -2.0];
+    let mut x_ball = x;
+    ellipsoid.project(&mut x);
+    ball.project(&mut x_ball);
+    unit_test_utils::assert_nearly_equal_array(&x_ball, &x, 1e-10, 1e-12, "wrong projection");
+}
+
+#[test]
+fn t_ellipsoid_random_optimality_conditions() {
+    // Q = [4 1 0; 1 3 0.5; 0 0.5 0.2]
+    let q = vec![4.0, 1.0, 0.0, 1.0, 3.0, 0.5, 0.0, 0.5, 0.2];
+    let center = vec![0.5, -1.0, 2.0];
+    let r = 0.7;
+    let ellipsoid = Ellipsoid::new(q.clone(), Some(center.clone()), r);
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..3)
+            .map(|_| 20. * (2. * rand::random::<f64>() - 1.))
+            .collect();
+        let mut x = v.clone();
+        ellipsoid.project(&mut x);
+        let y: Vec<f64> = x.iter().zip(center.iter()).map(|(a, b)| a - b).collect();
+        let q_y: Vec<f64> = (0..3)
+            .map(|i| (0..3).map(|j| q[3 * i + j] * y[j]).sum())
+            .collect();
+        let quad_form = crate::matrix_operations::inner_product(&y, &q_y);
+        assert!(quad_form <= r * (1. + 1e-10), "infeasible projection");
+        // optimality: v - x = mu * Q (x - c) with mu >= 0
+        let diff: Vec<f64> = v.iter().zip(x.iter()).map(|(a, b)| a - b).collect();
+        let norm_diff = crate::matrix_operations::norm2(&diff);
+        if norm_diff > 1e-10 {
+            unit_test_utils::assert_nearly_equal(r, quad_form, 1e-8, 1e-10, "not on boundary");
+            let cos_angle = crate::matrix_operations::inner_product(&diff, &q_y)
+                / (norm_diff * crate::matrix_operations::norm2(&q_y));
+            unit_test_utils::assert_nearly_equal(1.0, cos_angle, 1e-7, 1e-7, "not optimal");
+        }
+    }
+}
+
+#[test]
+fn t_ellipsoid_ill_conditioned() {
+    let q = vec![1e4, 0.0, 0.0, 1e-3];
+    let ellipsoid = Ellipsoid::new(q, None, 1.0);
+    let mut x = [100.0, 100.0];
+    ellipsoid.project(&mut x);
+    let quad_form = 1e4 * x[0].powi(2) + 1e-3 * x[1].powi(2);
+    unit_test_utils::assert_nearly_equal(1.0, quad_form, 1e-8, 1e-10, "not on boundary");
+}
+
+#[test]
+fn t_ellipsoid_is_convex() {
+    let ellipsoid = Ellipsoid::new(vec![1.0], None, 1.0);
+    assert!(ellipsoid.is_convex());
+}
+
+#[test]
+#[should_panic]
+fn t_ellipsoid_not_symmetric() {
+    let _ = Ellipsoid::new(vec![2.0, 0.5, 0.0, 1.0], None, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn t_ellipsoid_not_positive_definite() {
+    let _ = Ellipsoid::new(vec![1.0, 2.0, 2.0, 1.0], None, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn t_ellipsoid_not_square() {
+    let _ = Ellipsoid::new(vec![1.0, 0.0, 0.0], None, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn t_ellipsoid_wrong_center_dimension() {
+    let _ = Ellipsoid::new(vec![1.0, 0.0, 0.0, 1.0], Some(vec![1.0; 3]), 1.0);
+}
+
+#[test]
+fn t_psd_cone_full() {
+    let psd = PsdCone::new(2, SymmetricStorage::Full);
+    assert_eq!(Some(4), psd.dimension());
+    let mut x = [1.0, 2.0, 2.0, 1.0];
+    psd.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.5, 1.5, 1.5, 1.5],
+        &x,
+        1e-10,
+        1e-12,
+        "wrong projection on PSD cone",
+    );
+}
+
+#[test]
+fn t_psd_cone_full_nonsymmetric() {
+    let psd = PsdCone::new(2, SymmetricStorage::Full);
+    let mut x = [1.0, 3.0, 1.0, 1.0];
+    psd.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.5, 1.5, 1.5, 1.5],
+        &x,
+        1e-10,
+        1e-12,
+        "wrong projection on PSD cone",
+    );
+}
+
+#[test]
+fn t_psd_cone_packed() {
+    let psd = PsdCone::new(2, SymmetricStorage::Packed);
+    assert_eq!(Some(3), psd.dimension());
+    let sqrt2 = std::f64::consts::SQRT_2;
+    let mut x = [1.0, 2.0 * sqrt2, 1.0];
+    psd.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.5, 1.5 * sqrt2, 1.5],
+        &x,
+        1e-10,
+        1e-12,
+        "wrong projection on PSD cone (packed)",
+    );
+}
+
+#[test]
+fn t_psd_cone_inside() {
+    let psd = PsdCone::new(3, SymmetricStorage::Full);
+    let mut x = [2.0, -1.0, 0.0, -1.0, 2.0, -1.0, 0.0, -1.0, 2.0];
+    let x_copy = x;
+    psd.project(&mut x);
+    assert!(
+        crate::matrix_operations::norm_inf_diff(&x, &x_copy) < 1e-10,
+        "x modified"
+    );
+}
+
+#[test]
+fn t_psd_cone_random_optimality_conditions() {
+    let n = 4;
+    let psd = PsdCone::new(n, SymmetricStorage::Full);
+    for _ in 0..100 {
+        let mut x = vec![0.0; n * n];
+        for i in 0..n {
+            for j in 0..=i {
+                x[i * n + j] = 10. * (2. * rand::random::<f64>() - 1.);
+                x[j * n + i] = x[i * n + j];
+            }
+        }
+        let x_original = x.clone();
+        psd.project(&mut x);
+        // the projection, P, is PSD
+        let mut eigenvalues = vec![0.0; n];
+        let mut eigenvectors = vec![0.0; n * n];
+        let mut p_work = x.clone();
+        crate::matrix_operations::symmetric_eigen(&mut p_work, &mut eigenvalues, &mut eigenvectors);
+        assert!(eigenvalues.iter().all(|&e| e >= -1e-10), "P is not PSD");
+        // X - P is NSD
+        let mut d: Vec<f64> = x_original
+            .iter()
+            .zip(x.iter())
+            .map(|(a, b)| a - b)
+            .collect();
+        let inner = crate::matrix_operations::inner_product(&d, &x);
+        crate::matrix_operations::symmetric_eigen(&mut d, &mut eigenvalues, &mut eigenvectors);
+        assert!(eigenvalues.iter().all(|&e| e <= 1e-10), "X - P is not NSD");
+        // <X - P, P> = 0
+        assert!(inner.abs() <= 1e-9, "complementarity fails");
+    }
+}
+
+#[test]
+fn t_psd_cone_in_cartesian_product() {
+    let psd = PsdCone::new(2, SymmetricStorage::Packed);
+    let ball = Ball2::new(None, 1.0);
+    let cart_prod = CartesianProduct::new()
+        .add_constraint(3, psd)
+        .add_constraint(5, ball);
+    assert!(cart_prod.is_convex());
+    let sqrt2 = std::f64::consts::SQRT_2;
+    let mut x = [1.0, 2.0 * sqrt2, 1.0, 3.0, 4.0];
+    cart_prod.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.5, 1.5 * sqrt2, 1.5, 0.6, 0.8],
+        &x,
+        1e-10,
+        1e-12,
+        "wrong projection",
+    );
+}
+
+#[test]
+#[should_panic]
+fn t_psd_cone_wrong_dimension() {
+    let psd = PsdCone::new(3, SymmetricStorage::Packed);
+    let mut x = [0.0; 9];
+    psd.project(&mut x);
+}
+
+/// Checks that `p` is the projection of `v` on a cone, `K`, that is, `p` is
+/// (approximately) in `K`, <v - p, p> = 0 and <v - p, q> <= 0 for all rays
+/// `q` of `K` in `rays`
+fn assert_cone_projection(
+    v: &[f64],
+    p: &[f64],
+    in_cone: impl Fn(&[f64]) -> bool,
+    rays: &[[f64; 3]],
+) {
+    let d: Vec<f64> = v.iter().zip(p.iter()).map(|(a, b)| a - b).collect();
+    let scale = 1.0 + crate::matrix_operations::norm2(v);
+    assert!(in_cone(p), "projection not in the cone: {:?} -> {:?}", v, p);
+    assert!(
+        crate::matrix_operations::inner_product(p, &d).abs() <= 1e-8 * scale * scale,
+        "complementarity fails: {:?} -> {:?}",
+        v,
+        p
+    );
+    for q in rays.iter() {
+        let q_norm = crate::matrix_operations::norm2(q);
+        assert!(
+            crate::matrix_operations::inner_product(q, &d) <= 1e-8 * scale * q_norm,
+            "v - p not in the polar cone: {:?} -> {:?}",
+            v,
+            p
+        );
+    }
+}
+
+#[test]
+fn t_exponential_cone_inside() {
+    let exp_cone = ExponentialCone::new();
+    let mut x = [1.0, 1.0, 3.0];
+    exp_cone.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[1.0, 1.0, 3.0], &x, 1e-12, 1e-14, "modified");
+}
+
+#[test]
+fn t_exponential_cone_polar() {
+    let exp_cone = ExponentialCone::new();
+    let mut x = [1.0, 0.0, -1.0];
+    exp_cone.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf(&x) == 0.0, "not zero");
+}
+
+#[test]
+fn t_exponential_cone_negative_orthant() {
+    let exp_cone = ExponentialCone::new();
+    let mut x = [-2.0, -1.0, 3.0];
+    exp_cone.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[-2.0, 0.0, 3.0], &x, 1e-12, 1e-14, "wrong");
+}
+
+#[test]
+fn t_exponential_cone_random() {
+    let exp_cone = ExponentialCone::new();
+    let in_cone = |v: &[f64]| {
+        let tol = 1e-8 * (1.0 + crate::matrix_operations::norm2(v));
+        (v[1] > 0.0 && v[1] * (v[0] / v[1]).exp() <= v[2] + tol)
+            || (v[1].abs() <= tol && v[0] <= tol && v[2] >= -tol)
+    };
+    // rays of the exponential cone, (rho, 1, exp(rho)), (-1, 0, 0) and (0, 0, 1)
+    let mut rays: Vec<[f64; 3]> = (-400..=400)
+        .map(|i| {
+            let rho = 0.05 * i as f64;
+            [rho, 1.0, rho.exp()]
+        })
+        .collect();
+    rays.push([-1.0, 0.0, 0.0]);
+    rays.push([0.0, 0.0, 1.0]);
+    for _ in 0..5000 {
+        let v: Vec<f64> = (0..3)
+            .map(|_| 10. * (2. * rand::random::<f64>() - 1.))
+            .collect();
+        let mut p = v.clone();
+        exp_cone.project(&mut p);
+        assert_cone_projection(&v, &p, in_cone, &rays);
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_exponential_cone_wrong_dimension() {
+    let exp_cone = ExponentialCone::new();
+    let mut x = [1.0, 2.0];
+    exp_cone.project(&mut x);
+}
+
+#[test]
+fn t_power_cone_inside() {
+    let power_cone = PowerCone::new(0.3);
+    let mut x = [1.0, 2.0, -1.0];
+    power_cone.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[1.0, 2.0, -1.0], &x, 1e-12, 1e-14, "modified");
+}
+
+#[test]
+fn t_power_cone_z_zero() {
+    let power_cone = PowerCone::new(0.5);
+    let mut x = [-1.0, 2.0, 0.0];
+    power_cone.project(&mut x);
+    assert!(
+        x[0] == 0.0 && x[1] == 2.0 && x[2] == 0.0,
+        "wrong projection"
+    );
+}
+
+#[test]
+fn t_power_cone_random() {
+    for &alpha in [0.1, 0.5, 0.75].iter() {
+        let power_cone = PowerCone::new(alpha);
+        let in_cone = |v: &[f64]| {
+            let tol = 1e-8 * (1.0 + crate::matrix_operations::norm2(v));
+            v[0] >= -tol
+                && v[1] >= -tol
+                && v[0].max(0.0).powf(alpha) * v[1].max(0.0).powf(1.0 - alpha) >= v[2].abs() - tol
+        };
+        // rays of the power cone, (t, 1 - t, +/- t^alpha (1 - t)^(1 - alpha))
+        let rays: Vec<[f64; 3]> = (0..=200)
+            .flat_map(|i| {
+                let t = i as f64 / 200.0;
+                let w = t.powf(alpha) * (1.0 - t).powf(1.0 - alpha);
+                vec![[t, 1.0 - t, w], [t, 1.0 - t, -w]]
+            })
+            .collect();
+        for _ in 0..2000 {
+            let v: Vec<f64> = (0..3)
+                .map(|_| 10. * (2. * rand::random::<f64>() - 1.))
+                .collect();
+            let mut p = v.clone();
+            power_cone.project(&mut p);
+            assert_cone_projection(&v, &p, in_cone, &rays);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_power_cone_illegal_alpha() {
+    let _ = PowerCone::new(1.0);
+}
+
+#[test]
+fn t_is_convex_exp_and_power_cones() {
+    assert!(ExponentialCone::new().is_convex());
+    assert!(PowerCone::new(0.4).is_convex());
+}
+
+#[test]
+fn t_polyhedron_box() {
+    // the box [-1, 2] x [0, 1] x [-3, -1] as a polyhedron
+    let a = vec![
+        1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0,
+    ];
+    let b = vec![2.0, 1.0, 1.0, 0.0, -1.0, 3.0];
+    let polyhedron = Polyhedron::new(a, b);
+    let xmin = [-1.0, 0.0, -3.0];
+    let xmax = [2.0, 1.0, -1.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    assert_eq!(Some(3), polyhedron.dimension());
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..3).map(|_| 10. * rand::random::<f64>() - 5.).collect();
+        let mut x_poly = v.clone();
+        let mut x_rect = v.clone();
+        polyhedron.project(&mut x_poly);
+        rectangle.project(&mut x_rect);
+        assert!(
+            crate::matrix_operations::norm_inf_diff(&x_rect, &x_poly) < 1e-10,
+            "wrong projection on box"
+        );
+    }
+}
+
+#[test]
+fn t_polyhedron_simplex_with_equalities() {
+    // the simplex {x >= 0, sum x = 2} in R^5
+    let n = 5;
+    let mut a = vec![0.0; n * n];
+    (0..n).for_each(|i| a[i * n + i] = -1.0);
+    let polyhedron =
+        Polyhedron::new(a, vec![0.0; n]).with_equality_constraints(vec![1.0; n], vec![2.0]);
+    let simplex = Simplex::new(2.0);
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..n).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x_poly = v.clone();
+        let mut x_simplex = v.clone();
+        polyhedron.project(&mut x_poly);
+        simplex.project(&mut x_simplex);
+        assert!(
+            crate::matrix_operations::norm_inf_diff(&x_simplex, &x_poly) < 1e-10,
+            "wrong projection on simplex"
+        );
+    }
+}
+
+#[test]
+fn t_polyhedron_degenerate_vertex() {
+    // pyramid with apex at (0, 0, 1) where four facets meet, plus redundant
+    // (repeated) constraints
+    let a = vec![
+        1.0, 0.0, 1.0, -1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, -1.0, 1.0, 0.0, 0.0, -1.0, 1.0, 0.0, 1.0,
+    ];
+    let b = vec![1.0, 1.0, 1.0, 1.0, 0.0, 1.0];
+    let polyhedron = Polyhedron::new(a, b);
+    let mut x = [0.0, 0.0, 5.0];
+    polyhedron.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.0, 0.0, 1.0], &x, 1e-10, 1e-12, "not apex");
+
+    // the projection satisfies the optimality conditions (compared to the
+    // vertices of the pyramid)
+    let vertices = [
+        [0.0, 0.0, 1.0],
+        [1.0, 1.0, 0.0],
+        [1.0, -1.0, 0.0],
+        [-1.0, 1.0, 0.0],
+        [-1.0, -1.0, 0.0],
+    ];
+    for _ in 0..500 {
+        let v: Vec<f64> = (0..3).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut p = v.clone();
+        polyhedron.project(&mut p);
+        assert!(p[0].abs() + p[2] <= 1.0 + 1e-10 && p[1].abs() + p[2] <= 1.0 + 1e-10);
+        assert!(p[2] >= -1e-10);
+        for q in vertices.iter() {
+            let inner: f64 = (0..3).map(|i| (v[i] - p[i]) * (q[i] - p[i])).sum();
+            assert!(inner <= 1e-10, "not optimal");
+        }
+    }
+}
+
+#[test]
+fn t_polyhedron_warm_start() {
+    let a = vec![1.0, 1.0, -1.0, 0.0, 0.0, -1.0, 1.0, -1.0];
+    let b = vec![1.0, 0.0, 0.0, 0.5];
+    let polyhedron = Polyhedron::new(a, b);
+    let polyhedron_cold = polyhedron.clone();
+    let mut x_prev = [0.0; 2];
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..2).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+        let mut x_warm = v.clone();
+        polyhedron.project(&mut x_warm);
+        let mut x_cold = v.clone();
+        Polyhedron::new(
+            vec![1.0, 1.0, -1.0, 0.0, 0.0, -1.0, 1.0, -1.0],
+            vec![1.0, 0.0, 0.0, 0.5],
+        )
+        .project(&mut x_cold);
+        assert!(
+            crate::matrix_operations::norm_inf_diff(&x_cold, &x_warm) < 1e-10,
+            "warm start"
+        );
+        // projecting again from the solution does not change it
+        x_prev.copy_from_slice(&x_warm);
+        polyhedron.project(&mut x_warm);
+        assert!(
+            crate::matrix_operations::norm_inf_diff(&x_prev, &x_warm) < 1e-10,
+            "not idempotent"
+        );
+    }
+    assert!(polyhedron_cold.is_convex());
+}
+
+#[test]
+fn t_polyhedron_dependent_equalities() {
+    // x1 + x2 = 1 (given twice), x1 - x2 <= 0
+    let polyhedron = Polyhedron::new(vec![1.0, -1.0], vec![0.0])
+        .with_equality_constraints(vec![1.0, 1.0, 2.0, 2.0], vec![1.0, 2.0]);
+    let mut x = [3.0, 0.0];
+    polyhedron.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.5, 0.5], &x, 1e-10, 1e-12, "wrong");
+}
+
+#[test]
+#[should_panic]
+fn t_polyhedron_empty() {
+    // x1 <= -1 and x1 >= 1
+    let _polyhedron = Polyhedron::new(vec![1.0, 0.0, -1.0, 0.0], vec![-1.0, -1.0]);
+}
+
+#[test]
+fn t_try_new_polyhedron() {
+    assert!(Polyhedron::try_new(vec![1.0, 0.0, -1.0, 0.0], vec![1.0, 1.0]).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        Polyhedron::try_new(vec![1.0, 0.0], vec![]).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        Polyhedron::try_new(vec![1.0, 0.0, 1.0], vec![1.0, 1.0]).err()
+    );
+    // x1 <= -1 and x1 >= 1
+    assert_eq!(
+        Some(crate::ConstructionError::EmptySet),
+        Polyhedron::try_new(vec![1.0, 0.0, -1.0, 0.0], vec![-1.0, -1.0]).err()
+    );
+    // x1 + x2 <= 1, x1 >= 0, x2 >= 0 and x1 + x2 = 2
+    let simplex =
+        Polyhedron::try_new(vec![1.0, 1.0, -1.0, 0.0, 0.0, -1.0], vec![1.0, 0.0, 0.0]).unwrap();
+    assert_eq!(
+        Some(crate::ConstructionError::EmptySet),
+        simplex
+            .clone()
+            .try_with_equality_constraints(vec![1.0, 1.0], vec![2.0])
+            .err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        simplex
+            .try_with_equality_constraints(vec![1.0, 1.0, 1.0], vec![1.0])
+            .err()
+    );
+}
+
+#[test]
+#[should_panic]
+fn t_polyhedron_wrong_dimension() {
+    let polyhedron = Polyhedron::new(vec![1.0, 0.0, -1.0, 0.0], vec![1.0, 1.0]);
+    let mut x = [0.0, 0.0, 0.0];
+    polyhedron.project(&mut x);
+}
+
+#[test]
+fn t_polyhedron_random() {
+    // random polytope in R^6 with 20 inequalities (containing the origin) and one
+    // equality constraint
+    let (n, m) = (6, 20);
+    let a: Vec<f64> = (0..n * m)
+        .map(|_| 2. * rand::random::<f64>() - 1.)
+        .collect();
+    let b: Vec<f64> = (0..m).map(|_| rand::random::<f64>() + 0.1).collect();
+    let c: Vec<f64> = (0..n).map(|_| 2. * rand::random::<f64>() - 1.).collect();
+    let polyhedron =
+        Polyhedron::new(a.clone(), b.clone()).with_equality_constraints(c.clone(), vec![0.0]);
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..n).map(|_| 10. * rand::random::<f64>() - 5.).collect();
+        let mut p = v.clone();
+        polyhedron.project(&mut p);
+        // p is feasible
+        for i in 0..m {
+            assert!(
+                crate::matrix_operations::inner_product(&a[i * n..(i + 1) * n], &p) <= b[i] + 1e-9
+            );
+        }
+        assert!(crate::matrix_operations::inner_product(&c, &p).abs() <= 1e-9);
+        // p is the projection of every point on the ray p + t(v - p), t >= 0
+        for &t in [0.5, 2.0].iter() {
+            let mut w: Vec<f64> = v
+                .iter()
+                .zip(p.iter())
+                .map(|(vi, pi)| pi + t * (vi - pi))
+                .collect();
+            polyhedron.project(&mut w);
+            assert!(
+                crate::matrix_operations::norm_inf_diff(&w, &p) < 1e-9,
+                "not optimal"
+            );
+        }
+    }
+}
+
+#[test]
+fn t_polyhedron_random_warm_versus_cold_start() {
+    // the working set changes many times (constraints are added and dropped) and
+    // the projections do not depend on the warm start
+    let (n, m) = (10, 40);
+    let a: Vec<f64> = (0..n * m)
+        .map(|_| 2. * rand::random::<f64>() - 1.)
+        .collect();
+    let b: Vec<f64> = (0..m).map(|_| rand::random::<f64>() + 0.1).collect();
+    let warm = Polyhedron::new(a.clone(), b.clone());
+    for _ in 0..100 {
+        let v: Vec<f64> = (0..n).map(|_| 10. * rand::random::<f64>() - 5.).collect();
+        let mut p_warm = v.clone();
+        warm.project(&mut p_warm);
+        let mut p_cold = v.clone();
+        Polyhedron::new(a.clone(), b.clone()).project(&mut p_cold);
+        assert!(crate::matrix_operations::norm_inf_diff(&p_warm, &p_cold) < 1e-9);
+        for i in 0..m {
+            assert!(
+                crate::matrix_operations::inner_product(&a[i * n..(i + 1) * n], &p_warm)
+                    <= b[i] + 1e-9
+            );
+        }
+    }
+}
+
+#[test]
+fn t_intersection_ball_halfspace() {
+    // the projection of (2, 2) on the intersection of the unit ball with the
+    // halfspace x[0] + x[1] <= 0.5 lies on the line x[0] = x[1]
+    let normal_vector = [1.0, 1.0];
+    let intersection = Intersection::new(2)
+        .add_constraint(Ball2::new(None, 1.0))
+        .add_constraint(Halfspace::new(&normal_vector, 0.5));
+    let mut x = [2.0, 2.0];
+    intersection.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &[0.25, 0.25]) < 1e-8);
+
+    // the projection of (2, -0.5) is on the unit circle and the line x[0] + x[1] = 0.5
+    let mut x = [2.0, -0.5];
+    intersection.project(&mut x);
+    assert!((crate::matrix_operations::norm2(&x) - 1.0).abs() < 1e-8);
+    assert!((x[0] + x[1] - 0.5).abs() < 1e-8);
+}
+
+#[test]
+fn t_intersection_box_halfspace_vs_polyhedron() {
+    let xmin = [-1.0, -1.0, -1.0];
+    let xmax = [1.0, 1.0, 1.0];
+    let normal_vector = [1.0, 2.0, -1.0];
+    let intersection = Intersection::new(3)
+        .add_constraint(Rectangle::new(Some(&xmin), Some(&xmax)))
+        .add_constraint(Halfspace::new(&normal_vector, 0.5))
+        .with_max_iterations(10000)
+        .with_tolerance(1e-12);
+    let polyhedron = Polyhedron::new(
+        vec![
+            1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, -1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0,
+            -1.0, 1.0, 2.0, -1.0,
+        ],
+        vec![1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5],
+    );
+    assert_eq!(Some(3), intersection.dimension());
+    for _ in 0..100 {
+        let v: Vec<f64> = (0..3).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x_dykstra = v.clone();
+        let mut x_poly = v.clone();
+        intersection.project(&mut x_dykstra);
+        polyhedron.project(&mut x_poly);
+        assert!(
+            crate::matrix_operations::norm_inf_diff(&x_dykstra, &x_poly) < 1e-7,
+            "wrong projection on intersection"
+        );
+    }
+}
+
+#[test]
+fn t_intersection_box_affine_space() {
+    // box [0, 1]^3 intersected with {x: x[0] + x[1] + x[2] = 1} (unit simplex)
+    let xmin = [0.0; 3];
+    let xmax = [1.0; 3];
+    let intersection = Intersection::new(3)
+        .add_constraint(Rectangle::new(Some(&xmin), Some(&xmax)))
+        .add_constraint(AffineSpace::new(vec![1.0, 1.0, 1.0], vec![1.0]))
+        .with_tolerance(1e-12);
+    let simplex = Simplex::new(1.0);
+    for _ in 0..100 {
+        let v: Vec<f64> = (0..3).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+        let mut x_dykstra = v.clone();
+        let mut x_simplex = v.clone();
+        intersection.project(&mut x_dykstra);
+        simplex.project(&mut x_simplex);
+        assert!(crate::matrix_operations::norm_inf_diff(&x_dykstra, &x_simplex) < 1e-7);
+    }
+}
+
+#[test]
+fn t_intersection_single_set() {
+    let intersection = Intersection::new(2).add_constraint(Ball2::new(None, 1.0));
+    let mut x = [3.0, 4.0];
+    intersection.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &[0.6, 0.8]) < 1e-12);
+    assert!(intersection.is_convex());
+}
+
+#[test]
+fn t_intersection_is_convex() {
+    let intersection = Intersection::new(2)
+        .add_constraint(Ball2::new(None, 1.0))
+        .add_constraint(Sphere2::new(None, 0.5));
+    assert!(!intersection.is_convex());
+}
+
+#[test]
+#[should_panic]
+fn t_intersection_wrong_dimension() {
+    let intersection = Intersection::new(3).add_constraint(Ball2::new(None, 1.0));
+    let mut x = [3.0, 4.0];
+    intersection.project(&mut x);
+}
+
+#[test]
+fn t_union_two_lanes() {
+    // either in lane A, [0, 10] x [0, 1], or in lane B, [0, 10] x [2, 3]
+    let xmin_a = [0.0, 0.0];
+    let xmax_a = [10.0, 1.0];
+    let xmin_b = [0.0, 2.0];
+    let xmax_b = [10.0, 3.0];
+    let union = Union::new()
+        .add_constraint(Rectangle::new(Some(&xmin_a), Some(&xmax_a)))
+        .add_constraint(Rectangle::new(Some(&xmin_b), Some(&xmax_b)));
+    let mut x = [5.0, 1.4];
+    union.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[5.0, 1.0], &x, 1e-12, 1e-14, "lane A");
+    let mut x = [11.0, 1.6];
+    union.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[10.0, 2.0], &x, 1e-12, 1e-14, "lane B");
+    let mut x = [3.0, 2.5];
+    union.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[3.0, 2.5], &x, 1e-12, 1e-14, "inside");
+    assert!(!union.is_convex());
+}
+
+#[test]
+fn t_union_finite_set_and_sphere() {
+    let data: &[&[f64]] = &[&[0.0, 0.0], &[5.0, 5.0]];
+    let union = Union::new_with_capacity(2)
+        .add_constraint(FiniteSet::new(data))
+        .add_constraint(Sphere2::new(None, 2.0));
+    let mut x = [0.5, 0.0];
+    union.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.0, 0.0], &x, 1e-12, 1e-14, "finite set");
+    let mut x = [1.5, 0.0];
+    union.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[2.0, 0.0], &x, 1e-12, 1e-14, "sphere");
+    let mut x = [4.0, 4.5];
+    union.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[5.0, 5.0], &x, 1e-12, 1e-14, "finite set");
+}
+
+#[test]
+fn t_union_single_set_is_convex() {
+    let union = Union::new().add_constraint(Ball2::new(None, 1.0));
+    assert!(union.is_convex());
+    let union = Union::new().add_constraint(Sphere2::new(None, 1.0));
+    assert!(!union.is_convex());
+}
+
+#[test]
+#[should_panic]
+fn t_union_empty() {
+    let union = Union::new();
+    let mut x = [1.0, 2.0];
+    union.project(&mut x);
+}
+
+#[test]
+fn t_cardinality() {
+    let cardinality = Cardinality::new(2);
+    let mut x = [1.0, -3.0, 0.5, 2.0, -1.5];
+    cardinality.project(&mut x);
+    assert_eq!([0.0, -3.0, 0.0, 2.0, 0.0], x);
+    assert!(!cardinality.is_convex());
+
+    // k is larger than the dimension of x
+    let cardinality = Cardinality::new(10);
+    let mut x = [1.0, -3.0, 0.5];
+    cardinality.project(&mut x);
+    assert_eq!([1.0, -3.0, 0.5], x);
+
+    // k = 0
+    let cardinality = Cardinality::new(0);
+    let mut x = [1.0, -3.0, 0.5];
+    cardinality.project(&mut x);
+    assert_eq!([0.0; 3], x);
+}
+
+#[test]
+fn t_cardinality_with_bounds() {
+    // the element with the largest magnitude is not kept, because it is
+    // clipped by the box
+    let xmin = [-1.0, -5.0, -5.0];
+    let xmax = [1.0, 5.0, 5.0];
+    let cardinality = Cardinality::new(1).with_bounds(Some(&xmin), Some(&xmax));
+    let mut x = [2.2, 2.0, -0.5];
+    cardinality.project(&mut x);
+    assert_eq!([0.0, 2.0, 0.0], x);
+    let mut x = [10.0, 2.0, -0.5];
+    cardinality.project(&mut x);
+    assert_eq!([1.0, 0.0, 0.0], x);
+
+    let cardinality = Cardinality::new(2).with_bounds(None, Some(&xmax));
+    let mut x = [10.0, 2.0, -7.0];
+    cardinality.project(&mut x);
+    assert_eq!([1.0, 0.0, -7.0], x);
+}
+
+#[test]
+fn t_cardinality_with_bounds_brute_force() {
+    // compare with the best of all supports of size k
+    let n = 6;
+    let k = 3;
+    let xmin = [-1.0, -0.5, 0.0, -2.0, -0.1, -1.0];
+    let xmax = [0.5, 1.0, 2.0, 0.0, 0.1, 1.0];
+    let cardinality = Cardinality::new(k).with_bounds(Some(&xmin), Some(&xmax));
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    for _ in 0..100 {
+        let v: Vec<f64> = (0..n).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x = v.clone();
+        cardinality.project(&mut x);
+        let mut best_distance = f64::INFINITY;
+        for support in 0..(1_usize << n) {
+            if support.count_ones() as usize != k {
+                continue;
+            }
+            let mut candidate = v.clone();
+            rectangle.project(&mut candidate);
+            (0..n)
+                .filter(|i| support & (1 << i) == 0)
+                .for_each(|i| candidate[i] = 0.0);
+            best_distance =
+                best_distance.min(crate::matrix_operations::norm2_squared_diff(&candidate, &v));
+        }
+        let distance = crate::matrix_operations::norm2_squared_diff(&x, &v);
+        assert!((distance - best_distance).abs() < 1e-12, "not optimal");
+        assert!(x.iter().filter(|&&xi| xi != 0.0).count() <= k);
+    }
+}
+
+#[test]
+fn t_cardinality_with_ball2() {
+    let cardinality = Cardinality::new(2).with_ball2(1.0);
+    let mut x = [3.0, 0.1, -4.0];
+    cardinality.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.6, 0.0, -0.8], &x, 1e-12, 1e-14, "wrong");
+    let mut x = [0.3, 0.1, -0.4];
+    cardinality.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.3, 0.0, -0.4], &x, 1e-12, 1e-14, "wrong");
+}
+
+#[test]
+#[should_panic]
+fn t_cardinality_box_without_origin() {
+    let xmin = [0.5, -1.0];
+    let _ = Cardinality::new(1).with_bounds(Some(&xmin), None);
+}
+
+#[test]
+#[should_panic]
+fn t_cardinality_wrong_dimension() {
+    let xmax = [1.0, 1.0];
+    let cardinality = Cardinality::new(1).with_bounds(None, Some(&xmax));
+    let mut x = [1.0, 2.0, 3.0];
+    cardinality.project(&mut x);
+}
+
+/// Checks that `p` is the projection of `v` on an epigraph, `{(z, t): f(z) <= t}`,
+/// using random points of the epigraph, `q`, and the variational inequality
+/// <v - p, q - p> <= 0
+fn assert_epigraph_projection(v: &[f64], p: &[f64], f: impl Fn(&[f64]) -> f64) {
+    let n = v.len();
+    assert!(f(&p[..n - 1]) <= p[n - 1] + 1e-10, "p not in the epigraph");
+    for _ in 0..100 {
+        let mut q: Vec<f64> = (0..n).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+        q[n - 1] = f(&q[..n - 1]) + rand::random::<f64>();
+        let inner: f64 = (0..n).map(|i| (v[i] - p[i]) * (q[i] - p[i])).sum();
+        assert!(inner <= 1e-10, "not optimal");
+    }
+}
+
+#[test]
+fn t_epigraph_squared_norm() {
+    let epi = EpigraphSquaredNorm::new();
+    let mut x = [1.0, 1.0, 3.0];
+    epi.project(&mut x);
+    assert_eq!([1.0, 1.0, 3.0], x);
+
+    // the projection of (1, 0) is (s^-1, (s - 1)/2), where s^3 - s^2 - 2 = 0, i.e., s ~ 1.6956
+    let mut x = [1.0, 0.0];
+    epi.project(&mut x);
+    assert!((x[0] * x[0] - x[1]).abs() < 1e-12);
+    assert!((x[0] - 1.0 / 1.695_620_769_559_862).abs() < 1e-10);
+
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..4).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut p = v.clone();
+        epi.project(&mut p);
+        assert_epigraph_projection(&v, &p, crate::matrix_operations::norm2_squared);
+    }
+    assert!(epi.is_convex());
+}
+
+#[test]
+fn t_epigraph_norm_l1() {
+    let epi = EpigraphNormL1::new();
+    let mut x = [1.0, -1.0, 3.0];
+    epi.project(&mut x);
+    assert_eq!([1.0, -1.0, 3.0], x);
+
+    // polar cone: ||z||_inf <= -t
+    let mut x = [1.0, -0.5, -2.0];
+    epi.project(&mut x);
+    assert_eq!([0.0, 0.0, 0.0], x);
+
+    // lambda = 1: (2, -1, 0) -> (1, 0, 1)
+    let mut x = [2.0, -1.0, 0.0];
+    epi.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[1.0, 0.0, 1.0], &x, 1e-12, 1e-14, "wrong");
+
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..5).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut p = v.clone();
+        epi.project(&mut p);
+        assert_epigraph_projection(&v, &p, crate::matrix_operations::norm1);
+    }
+    assert!(epi.is_convex());
+}
+
+#[test]
+fn t_epigraph_norm_inf() {
+    let epi = EpigraphNormInf::new();
+    let mut x = [1.0, -1.0, 3.0];
+    epi.project(&mut x);
+    assert_eq!([1.0, -1.0, 3.0], x);
+
+    // polar cone: ||z||_1 <= -t
+    let mut x = [1.0, -0.5, -2.0];
+    epi.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf(&x) < 1e-14);
+
+    // (3, 0.5, 1) -> (2, 0.5, 2)
+    let mut x = [3.0, 0.5, 1.0];
+    epi.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[2.0, 0.5, 2.0], &x, 1e-12, 1e-14, "wrong");
+
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..5).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut p = v.clone();
+        epi.project(&mut p);
+        assert_epigraph_projection(&v, &p, crate::matrix_operations::norm_inf);
+    }
+    assert!(epi.is_convex());
+}
+
+#[test]
+#[should_panic]
+fn t_epigraph_squared_norm_short_vector() {
+    let epi = EpigraphSquaredNorm::new();
+    let mut x = [1.0];
+    epi.project(&mut x);
+}
+
+/// Polyhedron {x: x[0] <= x[1] <= ... <= x[n-1], xmin <= x <= xmax}
+fn monotone_polyhedron(xmin: &[f64], xmax: &[f64]) -> Polyhedron {
+    let n = xmin.len();
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for i in 0..n - 1 {
+        let mut row = vec![0.0; n];
+        row[i] = 1.0;
+        row[i + 1] = -1.0;
+        a.extend(row);
+        b.push(0.0);
+    }
+    for i in 0..n {
+        let mut row = vec![0.0; n];
+        row[i] = -1.0;
+        a.extend(row.iter().cloned());
+        b.push(-xmin[i]);
+        row[i] = 1.0;
+        a.extend(row);
+        b.push(xmax[i]);
+    }
+    Polyhedron::new(a, b)
+}
+
+#[test]
+fn t_monotone_cone() {
+    let monotone_cone = MonotoneCone::new();
+    let mut x = [1.0, 3.0, 2.0, 4.0];
+    monotone_cone.project(&mut x);
+    assert_eq!([1.0, 2.5, 2.5, 4.0], x);
+    let mut x = [4.0, 3.0, 2.0, 1.0];
+    monotone_cone.project(&mut x);
+    assert_eq!([2.5; 4], x);
+    let mut x = [1.0, 2.0, 3.0];
+    monotone_cone.project(&mut x);
+    assert_eq!([1.0, 2.0, 3.0], x);
+    assert!(monotone_cone.is_convex());
+
+    // compare with the projection on the polyhedron
+    let n = 7;
+    let polyhedron = monotone_polyhedron(&[-1e3; 7], &[1e3; 7]);
+    for _ in 0..100 {
+        let v: Vec<f64> = (0..n).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x_pav = v.clone();
+        let mut x_poly = v.clone();
+        monotone_cone.project(&mut x_pav);
+        polyhedron.project(&mut x_poly);
+        assert!(crate::matrix_operations::norm_inf_diff(&x_pav, &x_poly) < 1e-9);
+    }
+}
+
+#[test]
+fn t_bounded_monotone() {
+    // clipping the projection on the monotone cone is not the projection
+    // when the bounds are element-wise
+    let xmax = [0.0, 10.0];
+    let set = BoundedMonotone::new(None, Some(&xmax));
+    let mut x = [2.0, 0.0];
+    set.project(&mut x);
+    assert_eq!([0.0, 0.0], x);
+    assert_eq!(Some(2), set.dimension());
+    assert!(set.is_convex());
+}
+
+#[test]
+fn t_bounded_monotone_random() {
+    let n = 6;
+    for _ in 0..100 {
+        // random (nonmonotone) bounds such that the set is nonempty
+        let xmin: Vec<f64> = (0..n).map(|_| 3. * rand::random::<f64>() - 2.).collect();
+        let mut running_max = f64::NEG_INFINITY;
+        let xmax: Vec<f64> = xmin
+            .iter()
+            .map(|&xi| {
+                running_max = running_max.max(xi);
+                running_max + rand::random::<f64>()
+            })
+            .collect();
+        let set = BoundedMonotone::new(Some(&xmin), Some(&xmax));
+        let polyhedron = monotone_polyhedron(&xmin, &xmax);
+        let v: Vec<f64> = (0..n).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x_pav = v.clone();
+        let mut x_poly = v.clone();
+        set.project(&mut x_pav);
+        polyhedron.project(&mut x_poly);
+        assert!(crate::matrix_operations::norm_inf_diff(&x_pav, &x_poly) < 1e-9);
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_bounded_monotone_empty() {
+    let xmin = [0.0, 2.0, -1.0];
+    let xmax = [1.0, 3.0, 1.5];
+    let _set = BoundedMonotone::new(Some(&xmin), Some(&xmax));
+}
+
+/// Computes A'B, where A is n-by-p and B is n-by-q (both stored column-wise)
+fn transpose_times(a: &[f64], b: &[f64], n: usize) -> Vec<f64> {
+    let (p, q) = (a.len() / n, b.len() / n);
+    let mut c = vec![0.0; p * q];
+    for i in 0..p {
+        for j in 0..q {
+            c[i * q + j] = crate::matrix_operations::inner_product(
+                &a[i * n..(i + 1) * n],
+                &b[j * n..(j + 1) * n],
+            );
+        }
+    }
+    c
+}
+
+fn assert_orthonormal_columns(x: &[f64], n: usize) {
+    let p = x.len() / n;
+    let xtx = transpose_times(x, x, n);
+    for i in 0..p {
+        for j in 0..p {
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((xtx[i * p + j] - expected).abs() < 1e-10, "X'X != I");
+        }
+    }
+}
+
+fn det3(r: &[f64]) -> f64 {
+    r[0] * (r[4] * r[8] - r[7] * r[5]) - r[3] * (r[1] * r[8] - r[7] * r[2])
+        + r[6] * (r[1] * r[5] - r[4] * r[2])
+}
+
+#[test]
+fn t_stiefel_polar_factor() {
+    let (n, p) = (5, 3);
+    let stiefel = Stiefel::new(n, p);
+    assert_eq!(Some(15), stiefel.dimension());
+    assert!(!stiefel.is_convex());
+    for _ in 0..100 {
+        let m: Vec<f64> = (0..n * p)
+            .map(|_| 2. * rand::random::<f64>() - 1.)
+            .collect();
+        let mut x = m.clone();
+        stiefel.project(&mut x);
+        assert_orthonormal_columns(&x, n);
+        // X'M is symmetric and positive semidefinite (polar decomposition M = XH)
+        let h = transpose_times(&x, &m, n);
+        for i in 0..p {
+            for j in 0..p {
+                assert!(
+                    (h[i * p + j] - h[j * p + i]).abs() < 1e-10,
+                    "X'M not symmetric"
+                );
+            }
+        }
+        let mut eigenvalues = vec![0.0; p];
+        let mut eigenvectors = vec![0.0; p * p];
+        let mut h_copy = h.clone();
+        crate::matrix_operations::symmetric_eigen(&mut h_copy, &mut eigenvalues, &mut eigenvectors);
+        assert!(eigenvalues.iter().all(|&e| e >= -1e-10), "X'M not PSD");
+    }
+}
+
+#[test]
+fn t_stiefel_rank_deficient() {
+    // the second column is zero, so it is completed to an orthonormal set
+    let stiefel = Stiefel::new(3, 2);
+    let mut x = [2.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+    stiefel.project(&mut x);
+    assert_orthonormal_columns(&x, 3);
+    assert!((x[0] - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn t_stiefel_orthonormal_unchanged() {
+    let stiefel = Stiefel::new(3, 2);
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let mut x = [s, s, 0.0, 0.0, 0.0, 1.0];
+    let x_copy = x;
+    stiefel.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &x_copy) < 1e-12);
+}
+
+#[test]
+fn t_special_orthogonal_3() {
+    let so3 = SpecialOrthogonal::new(3);
+    assert_eq!(Some(9), so3.dimension());
+    assert!(!so3.is_convex());
+    for _ in 0..100 {
+        let m: Vec<f64> = (0..9).map(|_| 2. * rand::random::<f64>() - 1.).collect();
+        let mut x = m.clone();
+        so3.project(&mut x);
+        assert_orthonormal_columns(&x, 3);
+        assert!((det3(&x) - 1.0).abs() < 1e-10, "det != 1");
+        // compare with random rotations (obtained by projecting random matrices)
+        let distance = crate::matrix_operations::norm2_squared_diff(&x, &m);
+        for _ in 0..50 {
+            let mut r: Vec<f64> = (0..9).map(|_| 2. * rand::random::<f64>() - 1.).collect();
+            so3.project(&mut r);
+            assert!(distance <= crate::matrix_operations::norm2_squared_diff(&r, &m) + 1e-10);
+        }
+    }
+}
+
+#[test]
+fn t_special_orthogonal_reflection() {
+    // the projection of a reflection, diag(1, 1, -1), on SO(3)
+    let so3 = SpecialOrthogonal::new(3);
+    let mut x = [1.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, -0.5];
+    so3.project(&mut x);
+    let expected = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &expected) < 1e-12);
+}
+
+#[test]
+fn t_orthogonal_projection_rank_deficient() {
+    // M = I - 11'/n has rank n - 1 and the missing left singular vector,
+    // 1/sqrt(n), is not close to any vector of the standard basis
+    for &n in [4, 5, 8].iter() {
+        let m: Vec<f64> = (0..n * n)
+            .map(|k| if k % (n + 1) == 0 { 1.0 } else { 0.0 } - 1.0 / n as f64)
+            .collect();
+
+        // the projections on O(n) are I and I - 211'/n
+        let mut x = m.clone();
+        Stiefel::new(n, n).project(&mut x);
+        assert_orthonormal_columns(&x, n);
+        unit_test_utils::assert_nearly_equal(
+            1.0,
+            crate::matrix_operations::norm2_squared_diff(&x, &m),
+            1e-10,
+            1e-12,
+            "distance from O(n)",
+        );
+
+        // the projection on SO(n) is I
+        let mut x = m.clone();
+        SpecialOrthogonal::new(n).project(&mut x);
+        let identity: Vec<f64> = (0..n * n)
+            .map(|k| if k % (n + 1) == 0 { 1.0 } else { 0.0 })
+            .collect();
+        assert!(crate::matrix_operations::norm_inf_diff(&x, &identity) < 1e-10);
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_stiefel_illegal_dimensions() {
+    let _ = Stiefel::new(2, 3);
+}
+
+#[test]
+#[should_panic]
+fn t_special_orthogonal_wrong_dimension() {
+    let so3 = SpecialOrthogonal::new(3);
+    let mut x = [1.0; 8];
+    so3.project(&mut x);
+}
+
+#[test]
+fn t_ball2_exterior_inside() {
+    let center = [1.0, -1.0, 2.0];
+    let exterior = Ball2Exterior::new(Some(&center), 2.0);
+    let mut x = [1.5, -0.5, 2.5];
+    exterior.project(&mut x);
+    let d = 2.0 / 3.0_f64.sqrt();
+    let expected = [1.0 + d, -1.0 + d, 2.0 + d];
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &expected) < 1e-12);
+    assert!(!exterior.is_convex());
+}
+
+#[test]
+fn t_ball2_exterior_outside() {
+    let exterior = Ball2Exterior::new(None, 1.0);
+    let mut x = [0.8, -0.7];
+    let x_copy = x;
+    exterior.project(&mut x);
+    assert_eq!(x_copy, x);
+}
+
+#[test]
+fn t_ball2_exterior_center() {
+    let center = [1.0, 2.0];
+    let exterior = Ball2Exterior::new(Some(&center), 0.5);
+    let mut x = [1.0, 2.0];
+    exterior.project(&mut x);
+    assert!((crate::matrix_operations::norm2_squared_diff(&x, &center).sqrt() - 0.5).abs() < 1e-12);
+    let exterior_origin = Ball2Exterior::new(None, 3.0);
+    let mut x = [0.0, 0.0];
+    exterior_origin.project(&mut x);
+    assert_eq!([3.0, 0.0], x);
+}
+
+#[test]
+#[should_panic]
+fn t_ball2_exterior_nonpositive_radius() {
+    let _ = Ball2Exterior::new(None, 0.0);
+}
+
+#[test]
+fn t_rectangle_exterior_nearest_face() {
+    let xmin = [-1.0, -2.0, 0.0];
+    let xmax = [1.0, 2.0, 5.0];
+    let exterior = RectangleExterior::new(Some(&xmin), Some(&xmax));
+    let mut x = [0.2, 1.5, 2.0];
+    exterior.project(&mut x);
+    assert_eq!([0.2, 2.0, 2.0], x);
+    let mut x = [-0.9, 0.0, 2.5];
+    exterior.project(&mut x);
+    assert_eq!([-1.0, 0.0, 2.5], x);
+    assert!(!exterior.is_convex());
+}
+
+#[test]
+fn t_rectangle_exterior_outside() {
+    let xmin = [-1.0, -2.0];
+    let xmax = [1.0, 2.0];
+    let exterior = RectangleExterior::new(Some(&xmin), Some(&xmax));
+    let mut x = [0.0, 3.0];
+    exterior.project(&mut x);
+    assert_eq!([0.0, 3.0], x);
+    let mut x = [1.0, 0.0];
+    exterior.project(&mut x);
+    assert_eq!([1.0, 0.0], x);
+}
+
+#[test]
+fn t_rectangle_exterior_one_sided() {
+    let xmax = [1.0, f64::INFINITY];
+    let exterior = RectangleExterior::new(None, Some(&xmax));
+    let mut x = [-5.0, 3.0];
+    exterior.project(&mut x);
+    assert_eq!([1.0, 3.0], x);
+}
+
+#[test]
+fn t_rectangle_exterior_random() {
+    let xmin = [-1.0, -0.5, -2.0, 0.0];
+    let xmax = [1.0, 0.5, 3.0, 1.0];
+    let exterior = RectangleExterior::new(Some(&xmin), Some(&xmax));
+    for _ in 0..200 {
+        let v: Vec<f64> = (0..4).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x = v.clone();
+        exterior.project(&mut x);
+        let distance = crate::matrix_operations::norm2_squared_diff(&x, &v);
+        // compare with the projections on all faces
+        for i in 0..4 {
+            for bound in [xmin[i], xmax[i]] {
+                let mut y = v.clone();
+                y[i] = bound;
+                assert!(distance <= crate::matrix_operations::norm2_squared_diff(&y, &v) + 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_rectangle_exterior_no_bounds() {
+    let _ = RectangleExterior::new(None, None);
+}
+
+#[test]
+#[should_panic]
+fn t_rectangle_exterior_empty() {
+    let xmin = [f64::NEG_INFINITY; 2];
+    let exterior = RectangleExterior::new(Some(&xmin), None);
+    let mut x = [0.0, 0.0];
+    exterior.project(&mut x);
+}
+
+#[test]
+fn t_owned_constraints_same_as_borrowed() {
+    let center = [1.0, -2.0, 0.5];
+    let xmin = [-1.0, -1.0, 0.0];
+    let xmax = [1.0, 2.0, 1.0];
+    let normal_vector = [1.0, 2.0, -1.0];
+    let data: &[&[f64]] = &[&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], &[2.0, -1.0, 0.0]];
+    let borrowed: Vec<Box<dyn Constraint>> = vec![
+        Box::new(Ball1::new(Some(&center), 0.7)),
+        Box::new(Ball2::new(Some(&center), 0.7)),
+        Box::new(Rectangle::new(Some(&xmin), Some(&xmax))),
+        Box::new(Halfspace::new(&normal_vector, 0.5)),
+        Box::new(Hyperplane::new(&normal_vector, 0.5)),
+        Box::new(FiniteSet::new(data)),
+    ];
+    let owned: Vec<Box<dyn Constraint>> = vec![
+        Box::new(Ball1::new_owned(Some(center.to_vec()), 0.7)),
+        Box::new(Ball2::new_owned(Some(center.to_vec()), 0.7)),
+        Box::new(Rectangle::new_owned(
+            Some(xmin.to_vec()),
+            Some(xmax.to_vec()),
+        )),
+        Box::new(Halfspace::new_owned(normal_vector.to_vec(), 0.5)),
+        Box::new(Hyperplane::new_owned(normal_vector.to_vec(), 0.5)),
+        Box::new(FiniteSet::new_owned(
+            data.iter().map(|v| v.to_vec()).collect(),
+        )),
+    ];
+    for (c_borrowed, c_owned) in borrowed.iter().zip(owned.iter()) {
+        let mut x = [2.5, -0.3, 1.7];
+        let mut y = x;
+        c_borrowed.project(&mut x);
+        c_owned.project(&mut y);
+        assert_eq!(x, y);
+    }
+}
+
+/// A structure which holds constraints that were constructed at runtime
+struct ConstraintHolder {
+    bounds: Rectangle<'static>,
+    set_c: Ball2<'static>,
+}
+
+fn make_constraint_holder(n: usize) -> ConstraintHolder {
+    let xmax: Vec<f64> = (0..n).map(|i| i as f64).collect();
+    let center = vec![1.0; n];
+    ConstraintHolder {
+        bounds: Rectangle::new_owned(None, Some(xmax)),
+        set_c: Ball2::new_owned(Some(center), 1.0),
+    }
+}
+
+#[test]
+fn t_owned_constraints_in_struct() {
+    let holder = make_constraint_holder(3);
+    let mut x = [3.0, 3.0, 3.0];
+    holder.bounds.project(&mut x);
+    assert_eq!([0.0, 1.0, 2.0], x);
+    let mut x = [1.0, 1.0, 3.0];
+    holder.set_c.project(&mut x);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &[1.0, 1.0, 2.0]) < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn t_rectangle_owned_incompatible_dims() {
+    let _ = Rectangle::new_owned(Some(vec![1.0; 3]), Some(vec![2.0; 4]));
+}
+
+#[test]
+#[should_panic]
+fn t_finite_set_owned_inconsistent_dimensions() {
+    let _ = FiniteSet::new_owned(vec![vec![1.0, 2.0], vec![1.0]]);
+}
+
+#[test]
+fn t_rectangle_update_bounds() {
+    let mut rectangle = Rectangle::new_owned(Some(vec![-1.0; 3]), None);
+    let rectangle_initial = rectangle.clone();
+    for k in 0..5 {
+        let t = k as f64;
+        let xmin = [-1.0 + t, -2.0, 0.5 * t];
+        let xmax = [1.0 + t, 2.0, 1.0 + t];
+        rectangle.set_xmin(&xmin);
+        rectangle.set_xmax(&xmax);
+        let mut x = [-3.0, 5.0, 0.7];
+        let mut y = x;
+        rectangle.project(&mut x);
+        Rectangle::new(Some(&xmin), Some(&xmax)).project(&mut y);
+        assert_eq!(y, x);
+    }
+    // the original rectangle is not affected
+    let mut x = [-3.0, 5.0, 0.7];
+    rectangle_initial.project(&mut x);
+    assert_eq!([-1.0, 5.0, 0.7], x);
+}
+
+#[test]
+fn t_rectangle_update_borrowed_bounds() {
+    let xmin = [0.0, 0.0];
+    let xmax = [1.0, 1.0];
+    let mut rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    rectangle.set_xmax(&[0.5, 2.0]);
+    let mut x = [3.0, 3.0];
+    rectangle.project(&mut x);
+    assert_eq!([0.5, 2.0], x);
+    assert_eq!([1.0, 1.0], xmax);
+}
+
+#[test]
+#[should_panic]
+fn t_rectangle_update_wrong_dimension() {
+    let mut rectangle = Rectangle::new_owned(Some(vec![-1.0; 3]), None);
+    rectangle.set_xmax(&[1.0; 2]);
+}
+
+#[test]
+fn t_ball2_ballinf_update() {
+    let mut ball2 = Ball2::new(None, 1.0);
+    let mut ballinf = BallInf::new(None, 1.0);
+    let center = [1.0, 2.0];
+    ball2.set_center(&center);
+    ball2.set_radius(0.5);
+    ballinf.set_center(&center);
+    ballinf.set_radius(0.5);
+    let mut x = [3.0, 2.0];
+    let mut y = [3.0, 4.0];
+    ball2.project(&mut x);
+    ballinf.project(&mut y);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &[1.5, 2.0]) < 1e-12);
+    assert_eq!([1.5, 2.5], y);
+}
+
+#[test]
+#[should_panic]
+fn t_ball2_update_nonpositive_radius() {
+    let mut ball2 = Ball2::new(None, 1.0);
+    ball2.set_radius(-1.0);
+}
+
+#[test]
+fn t_halfspace_update() {
+    let normal_vector = [1.0, 2.0];
+    let mut halfspace = Halfspace::new(&normal_vector, 1.0);
+    halfspace.set_normal_vector(&[2.0, -1.0]);
+    halfspace.set_offset(0.5);
+    let mut x = [3.0, 1.0];
+    let mut y = x;
+    halfspace.project(&mut x);
+    Halfspace::new(&[2.0, -1.0], 0.5).project(&mut y);
+    assert_eq!(y, x);
+}
+
+#[test]
+fn t_affine_space_update() {
+    let a = vec![
+        0.5, 0.1, 0.2, -0.3, -0.6, 0.3, 0., 0.5, 1.0, 0.1, -1.0, -0.4,
+    ];
+    let b = vec![1., 2., -0.5];
+    let mut affine_set = AffineSpace::new(a.clone(), b);
+    let b_new = [0.3, -1.0, 2.0];
+    affine_set.set_b(&b_new);
+    let mut x = [1., -2., -0.3, 0.5];
+    let mut y = x;
+    affine_set.project(&mut x);
+    AffineSpace::new(a, b_new.to_vec()).project(&mut y);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &y) < 1e-12);
+
+    let a_new = vec![1.0, 0.0, 1.0, 0.0, 0.0, 2.0, 0.0, -1.0, 1.0, 1.0, 1.0, 1.0];
+    affine_set.set_a(&a_new);
+    let mut x = [1., -2., -0.3, 0.5];
+    let mut y = x;
+    affine_set.project(&mut x);
+    AffineSpace::new(a_new, b_new.to_vec()).project(&mut y);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &y) < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn t_affine_space_update_wrong_dimension() {
+    let mut affine_set = AffineSpace::new(vec![1.0, 1.0], vec![1.0]);
+    affine_set.set_b(&[1.0, 2.0]);
+}
+
+/// Checks that `distance_squared`, `distance` and `contains` are consistent with
+/// the projection on the set at random points of dimension `n`
+fn assert_distance_consistent(c: &dyn Constraint, n: usize, scale: f64) {
+    for _ in 0..100 {
+        let x: Vec<f64> = (0..n)
+            .map(|_| scale * (2. * rand::random::<f64>() - 1.))
+            .collect();
+        let mut projection = x.clone();
+        c.project(&mut projection);
+        let expected = crate::matrix_operations::norm2_squared_diff(&x, &projection);
+        let distance_squared = c.distance_squared(&x);
+        assert!(
+            (distance_squared - expected).abs() <= 1e-9 * (1.0 + expected),
+            "wrong distance: {} != {}",
+            distance_squared,
+            expected
+        );
+        assert!((c.distance(&x) - expected.sqrt()).abs() <= 1e-8);
+        assert!(c.contains(&projection, 1e-8));
+        assert_eq!(expected.sqrt() <= 1e-3, c.contains(&x, 1e-3));
+    }
+}
+
+#[test]
+fn t_distance_closed_form() {
+    let center = [1.0, -0.5, 0.2];
+    let xmin = [-1.0, f64::NEG_INFINITY, 0.0];
+    let xmax = [1.0, 0.5, f64::INFINITY];
+    let normal_vector = [1.0, -2.0, 0.5];
+    let data: &[&[f64]] = &[&[0.0, 0.0, 0.0], &[1.0, 1.0, 1.0], &[-1.0, 0.5, 0.0]];
+    let sets: Vec<Box<dyn Constraint>> = vec![
+        Box::new(Ball2::new(None, 0.8)),
+        Box::new(Ball2::new(Some(&center), 0.8)),
+        Box::new(Ball2Exterior::new(Some(&center), 0.8)),
+        Box::new(BallInf::new(Some(&center), 0.3)),
+        Box::new(BallInf::new(None, 0.3)),
+        Box::new(Rectangle::new(Some(&xmin), Some(&xmax))),
+        Box::new(Rectangle::new(None, Some(&xmax))),
+        Box::new(RectangleExterior::new(Some(&xmin), Some(&xmax))),
+        Box::new(Halfspace::new(&normal_vector, 0.5)),
+        Box::new(Hyperplane::new(&normal_vector, 0.5)),
+        Box::new(Sphere2::new(Some(&center), 0.8)),
+        Box::new(FiniteSet::new(data)),
+        Box::new(Zero::new()),
+        Box::new(NoConstraints::new()),
+        Box::new(SecondOrderCone::new(1.5)),
+        Box::new(SecondOrderCone::new(0.5).with_scalar_index(1)),
+        Box::new(RotatedSecondOrderCone::new()),
+        Box::new(
+            CartesianProduct::new()
+                .add_constraint(1, Ball2::new(None, 0.2))
+                .add_constraint(3, BallInf::new(None, 0.1)),
+        ),
+        Box::new(
+            Union::new()
+                .add_constraint(Ball2::new(Some(&center), 0.5))
+                .add_constraint(Halfspace::new(&normal_vector, -2.0)),
+        ),
+    ];
+    for set in sets.iter() {
+        assert_distance_consistent(set.as_ref(), 3, 2.0);
+    }
+}
+
+#[test]
+fn t_distance_default_and_contains() {
+    let center = [1.0, -0.5, 0.2];
+    let sets: Vec<Box<dyn Constraint>> = vec![
+        Box::new(Ball1::new(Some(&center), 0.8)),
+        Box::new(MonotoneCone::new()),
+        Box::new(EpigraphSquaredNorm::new()),
+        Box::new(EpigraphNormL1::new()),
+        Box::new(EpigraphNormInf::new()),
+        Box::new(ExponentialCone::new()),
+        Box::new(PowerCone::new(0.3)),
+        Box::new(Cardinality::new(1)),
+    ];
+    for set in sets.iter() {
+        assert_distance_consistent(set.as_ref(), 3, 2.0);
+    }
+}
+
+#[test]
+fn t_distance_overrides() {
+    let center = [1.0, -0.5, 0.2];
+    let upper_bounds = [0.5, 1.0, f64::INFINITY];
+    let sets: Vec<Box<dyn Constraint>> = vec![
+        Box::new(Simplex::new(1.5)),
+        Box::new(CappedSimplex::new(&upper_bounds, 1.2)),
+        Box::new(
+            Polyhedron::new(vec![1.0, 1.0, 0.0, -1.0, 0.5, 2.0], vec![1.0, 0.5])
+                .with_equality_constraints(vec![1.0, -1.0, 1.0], vec![0.2]),
+        ),
+        Box::new(Ellipsoid::new(
+            vec![2.0, 0.5, 0.0, 0.5, 1.0, 0.2, 0.0, 0.2, 3.0],
+            Some(center.to_vec()),
+            0.7,
+        )),
+        Box::new(AffineSpace::new(
+            vec![1.0, 2.0, -1.0, 0.0, 1.0, 1.0],
+            vec![1.0, -0.5],
+        )),
+        Box::new(AffineSpace::new_sparse(
+            3,
+            vec![0, 2, 4],
+            vec![0, 1, 1, 2],
+            vec![1.0, 2.0, 1.0, 1.0],
+            vec![1.0, -0.5],
+        )),
+        Box::new(PsdCone::new(2, SymmetricStorage::Packed)),
+        Box::new(
+            Intersection::new(3)
+                .add_constraint(Ball2::new(None, 1.0))
+                .add_constraint(Halfspace::new(&center, 0.3)),
+        ),
+        Box::new(Translated::new(Simplex::new(1.0), center.to_vec())),
+        Box::new(Permuted::new(SecondOrderCone::new(1.0), vec![2, 0, 1])),
+        Box::new(Scaled::new(Ball1::new(None, 1.0), vec![-2.0, 2.0, 2.0])),
+    ];
+    for set in sets.iter() {
+        assert_distance_consistent(set.as_ref(), 3, 2.0);
+    }
+    assert_distance_consistent(&PsdCone::new(2, SymmetricStorage::Full), 4, 2.0);
+}
+
+#[test]
+fn t_contains_constraint_violation() {
+    let polyhedron = Polyhedron::new(vec![1.0, 1.0], vec![1.0])
+        .with_equality_constraints(vec![1.0, -1.0], vec![0.0]);
+    assert!(polyhedron.contains(&[0.5, 0.5], 0.0));
+    assert!(polyhedron.contains(&[0.5 + 1e-9, 0.5], 1e-8));
+    assert!(!polyhedron.contains(&[0.6, 0.6], 1e-8));
+    assert!(!polyhedron.contains(&[0.3, 0.2], 1e-8));
+    let affine_space = AffineSpace::new(vec![3.0, 4.0], vec![5.0]);
+    assert!(affine_space.contains(&[3.0, -1.0], 1e-12));
+    assert!(!affine_space.contains(&[3.0, -0.9], 0.1));
+    let simplex = Simplex::new(1.0);
+    assert!(simplex.contains(&[0.5, 0.5, 0.0], 0.0));
+    assert!(simplex.contains(&[1.0 + 1e-9, -1e-9], 1e-8));
+    assert!(!simplex.contains(&[1.5, -0.5], 1e-8));
+}
+
+#[test]
+fn t_distance_examples() {
+    let ball = Ball2::new(None, 1.0);
+    assert!((ball.distance(&[3.0, 4.0]) - 4.0).abs() < 1e-12);
+    assert_eq!(0.0, ball.distance(&[0.3, 0.4]));
+    let xmin = [0.0, 0.0];
+    let xmax = [1.0, 1.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    assert!((rectangle.distance_squared(&[2.0, -1.0]) - 2.0).abs() < 1e-12);
+    assert!(rectangle.contains(&[1.0 + 1e-9, 0.5], 1e-8));
+    assert!(!rectangle.contains(&[1.1, 0.5], 1e-8));
+    let halfspace = Halfspace::new(&[3.0, 4.0], 5.0);
+    assert!((halfspace.distance(&[3.0, 4.0]) - 4.0).abs() < 1e-12);
+    assert!(halfspace.contains(&[-10.0, 0.0], 0.0));
+}
+
+#[test]
+fn t_constraint_dimension() {
+    let center = [1.0, 2.0];
+    let xmax = [1.0; 4];
+    assert_eq!(None, Constraint::dimension(&Ball2::new(None, 1.0)));
+    assert_eq!(
+        Some(2),
+        Constraint::dimension(&Ball2::new(Some(&center), 1.0))
+    );
+    assert_eq!(
+        Some(4),
+        Constraint::dimension(&Rectangle::new(None, Some(&xmax)))
+    );
+    assert_eq!(
+        Some(2),
+        Constraint::dimension(&Halfspace::new(&center, 1.0))
+    );
+    assert_eq!(Some(3), Constraint::dimension(&ExponentialCone::new()));
+    assert_eq!(Some(6), Constraint::dimension(&Stiefel::new(3, 2)));
+    assert_eq!(Some(9), Constraint::dimension(&SpecialOrthogonal::new(3)));
+    assert_eq!(
+        Some(5),
+        Constraint::dimension(
+            &CartesianProduct::new()
+                .add_constraint(2, Ball2::new(None, 1.0))
+                .add_constraint(5, Zero::new())
+        )
+    );
+    assert_eq!(Some(7), Constraint::dimension(&Intersection::new(7)));
+    assert_eq!(None, Constraint::dimension(&Simplex::new(1.0)));
+}
+
+/// Checks the KKT conditions at the projection of `v` on `c`: if $x = \Pi_C(v)$,
+/// then $v - x \in N_C(x)$ and $\sigma_C(v - x) = \langle v - x, x\rangle$
+///
+/// The support function is not checked if $0 < \Vert v - x \Vert < 10^{-3}$,
+/// since then the relative error of $v - x$ (due to cancellation) may exceed the
+/// tolerances of the support functions of cones, hyperplanes, etc.
+fn assert_normal_cone_at_projection(c: &dyn ActiveSet, v: &[f64]) {
+    let mut x = v.to_vec();
+    c.project(&mut x);
+    let y: Vec<f64> = v.iter().zip(x.iter()).map(|(vi, xi)| vi - xi).collect();
+    let mut z = y.clone();
+    c.project_on_normal_cone(&x, &mut z, 1e-9);
+    assert!(
+        crate::matrix_operations::norm_inf_diff(&y, &z) < 1e-9,
+        "v - proj(v) is not in the normal cone"
+    );
+    let norm_y = crate::matrix_operations::norm2(&y);
+    if norm_y > 0.0 && norm_y < 1e-3 {
+        return;
+    }
+    let support = c.support_function(&y);
+    let inner_product = crate::matrix_operations::inner_product(&y, &x);
+    assert!((support - inner_product).abs() < 1e-9 * (1.0 + inner_product.abs()));
+}
+
+#[test]
+fn t_active_set_kkt_at_projection() {
+    let center = [1.0, -0.5, 0.2];
+    let xmin = [-1.0, f64::NEG_INFINITY, 0.0];
+    let xmax = [1.0, 0.5, f64::INFINITY];
+    let normal_vector = [1.0, -2.0, 0.5];
+    let sets: Vec<Box<dyn ActiveSet>> = vec![
+        Box::new(Rectangle::new(Some(&xmin), Some(&xmax))),
+        Box::new(BallInf::new(Some(&center), 0.3)),
+        Box::new(Ball2::new(Some(&center), 0.8)),
+        Box::new(Ball1::new(Some(&center), 0.8)),
+        Box::new(Ball1::new(None, 1.5)),
+        Box::new(Simplex::new(1.5)),
+        Box::new(Halfspace::new(&normal_vector, 0.5)),
+        Box::new(Hyperplane::new(&normal_vector, 0.5)),
+        Box::new(Zero::new()),
+        Box::new(NoConstraints::new()),
+        Box::new(
+            Polyhedron::new(vec![1.0, 1.0, 1.0, -1.0, 0.0, 0.0], vec![1.0, 0.0])
+                .with_equality_constraints(vec![1.0, 0.0, -1.0], vec![0.2]),
+        ),
+        Box::new(AffineSpace::new(
+            vec![1.0, 2.0, -1.0, 2.0, 4.0, -2.0],
+            vec![0.5, 1.0],
+        )),
+        Box::new(SecondOrderCone::new(0.7)),
+        Box::new(SecondOrderCone::new(1.5).with_scalar_index(0)),
+        Box::new(
+            CartesianProduct::new()
+                .add_active_set_constraint(1, Rectangle::new(Some(&xmin[..1]), Some(&xmax[..1])))
+                .add_active_set_constraint(3, Ball2::new(None, 0.8)),
+        ),
+    ];
+    for set in sets.iter() {
+        for _ in 0..100 {
+            let v: Vec<f64> = (0..3).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+            assert_normal_cone_at_projection(set.as_ref(), &v);
+        }
+    }
+}
+
+#[test]
+fn t_active_set_polyhedron() {
+    // 0 <= x[0] <= 1, x[0] + x[1] <= 2.5, x[1] <= 2
+    let a = vec![1.0, 0.0, -1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+    let b = vec![1.0, 0.0, 2.5, 2.0];
+    let polyhedron = Polyhedron::new(a, b);
+    let x = [0.5, 2.0];
+    assert_eq!(
+        vec![
+            ActiveConstraint::Inequality(2),
+            ActiveConstraint::Inequality(3)
+        ],
+        polyhedron.active_constraints(&x, 1e-8)
+    );
+    unit_test_utils::assert_nearly_equal(
+        2.5,
+        polyhedron.support_function(&[1.0, 1.0]),
+        1e-10,
+        1e-12,
+        "wrong support function",
+    );
+    unit_test_utils::assert_nearly_equal(
+        3.5,
+        polyhedron.support_function(&[2.0, 1.0]),
+        1e-10,
+        1e-12,
+        "wrong support function",
+    );
+    assert_eq!(0.0, polyhedron.support_function(&[0.0, 0.0]));
+    // the polyhedron is unbounded in the direction (0, -1)
+    assert!(polyhedron.support_function(&[0.0, -1.0]).is_infinite());
+    let mut y = [1.0, 1.0];
+    polyhedron.project_on_normal_cone(&[0.5, 1.0], &mut y, 1e-8);
+    assert_eq!([0.0, 0.0], y);
+}
+
+#[test]
+fn t_active_set_affine_space() {
+    let affine_space = AffineSpace::new(vec![1.0, 1.0, 0.0, 0.0, 0.0, 1.0], vec![1.0, 2.0]);
+    assert_eq!(
+        vec![ActiveConstraint::Equality(0), ActiveConstraint::Equality(1)],
+        affine_space.active_constraints(&[0.5, 0.5, 2.0], 1e-8)
+    );
+    unit_test_utils::assert_nearly_equal(
+        -1.0,
+        affine_space.support_function(&[1.0, 1.0, -1.0]),
+        1e-10,
+        1e-12,
+        "wrong support function",
+    );
+    assert!(affine_space
+        .support_function(&[1.0, -1.0, 0.0])
+        .is_infinite());
+    let mut y = [2.0, 0.0, 1.0];
+    affine_space.project_on_normal_cone(&[0.5, 0.5, 2.0], &mut y, 1e-8);
+    assert!(crate::matrix_operations::norm_inf_diff(&y, &[1.0, 1.0, 1.0]) < 1e-12);
+}
+
+#[test]
+fn t_active_set_second_order_cone() {
+    let soc = SecondOrderCone::new(1.0);
+    assert_eq!(
+        vec![ActiveConstraint::Boundary],
+        soc.active_constraints(&[3.0, 4.0, 5.0], 1e-8)
+    );
+    assert_eq!(
+        vec![ActiveConstraint::Boundary],
+        soc.active_constraints(&[0.0, 0.0, 0.0], 1e-8)
+    );
+    assert!(soc.active_constraints(&[3.0, 4.0, 6.0], 1e-8).is_empty());
+    assert_eq!(0.0, soc.support_function(&[0.5, 0.0, -1.0]));
+    assert!(soc.support_function(&[0.0, 0.0, 1.0]).is_infinite());
+    let mut y = [3.0, 4.0, 5.0];
+    soc.project_on_normal_cone(&[3.0, 4.0, 5.0], &mut y, 1e-8);
+    assert_eq!([0.0, 0.0, 0.0], y);
+    let mut y = [3.0, 4.0, -5.0];
+    soc.project_on_normal_cone(&[3.0, 4.0, 5.0], &mut y, 1e-8);
+    assert!(crate::matrix_operations::norm_inf_diff(&y, &[3.0, 4.0, -5.0]) < 1e-12);
+}
+
+#[test]
+fn t_active_set_cartesian_product() {
+    let xmin = [-1.0, -1.0];
+    let xmax = [1.0, 1.0];
+    let cart_prod = CartesianProduct::new()
+        .add_active_set_constraint(2, Zero::new())
+        .add_active_set_constraint(4, Rectangle::new(Some(&xmin), Some(&xmax)))
+        .add_active_set_constraint(6, Ball2::new(None, 1.0));
+    let x = [0.0, 0.0, 0.5, -1.0, 0.6, 0.8];
+    assert_eq!(
+        vec![
+            ActiveConstraint::Segment(0, Box::new(ActiveConstraint::Equality(0))),
+            ActiveConstraint::Segment(0, Box::new(ActiveConstraint::Equality(1))),
+            ActiveConstraint::LowerBound(3),
+            ActiveConstraint::Segment(2, Box::new(ActiveConstraint::Boundary)),
+        ],
+        cart_prod.active_constraints(&x, 1e-8)
+    );
+    unit_test_utils::assert_nearly_equal(
+        2.0 + 5.0,
+        cart_prod.support_function(&[1.0, 1.0, 1.0, -1.0, 3.0, 4.0]),
+        1e-10,
+        1e-12,
+        "wrong support function",
+    );
+    let mut y = [1.0, 2.0, 1.0, -1.0, 3.0, -4.0];
+    cart_prod.project_on_normal_cone(&x, &mut y, 1e-8);
+    assert!(crate::matrix_operations::norm_inf_diff(&y, &[1.0, 2.0, 0.0, -1.0, 0.0, 0.0]) < 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn t_active_set_cartesian_product_plain_set() {
+    let cart_prod = CartesianProduct::new()
+        .add_active_set_constraint(2, Zero::new())
+        .add_constraint(4, Ball2::new(None, 1.0));
+    cart_prod.active_constraints(&[0.0; 4], 1e-8);
+}
+
+#[test]
+fn t_active_set_normal_cone_projection_optimal() {
+    // projections on the normal cones of the ball-1 and the simplex are
+    // compared with the elements of the cones that are obtained by perturbing
+    // the multipliers
+    let x_ball1 = [0.5, 0.0, -0.3, 0.0];
+    let ball1 = Ball1::new(None, 0.8);
+    let x_simplex = [0.5, 0.0, 1.0, 0.0];
+    let simplex = Simplex::new(1.5);
+    for _ in 0..100 {
+        let y: Vec<f64> = (0..4).map(|_| 4. * rand::random::<f64>() - 2.).collect();
+
+        let mut z = y.clone();
+        ball1.project_on_normal_cone(&x_ball1, &mut z, 1e-12);
+        let lambda = z[0];
+        let ball1_cone_element = |lambda: f64| -> Vec<f64> {
+            vec![
+                lambda,
+                y[1].max(-lambda).min(lambda),
+                -lambda,
+                y[3].max(-lambda).min(lambda),
+            ]
+        };
+        assert!(crate::matrix_operations::norm_inf_diff(&z, &ball1_cone_element(lambda)) < 1e-12);
+        let distance = crate::matrix_operations::norm2_squared_diff(&y, &z);
+        for delta in [1e-3, 1e-2, 1e-1, 1.0] {
+            for lambda_perturbed in [(lambda + delta), (lambda - delta).max(0.0)] {
+                let w = ball1_cone_element(lambda_perturbed);
+                assert!(distance <= crate::matrix_operations::norm2_squared_diff(&y, &w) + 1e-12);
+            }
+        }
+
+        let mut z = y.clone();
+        simplex.project_on_normal_cone(&x_simplex, &mut z, 1e-12);
+        let mu = z[0];
+        let simplex_cone_element =
+            |mu: f64| -> Vec<f64> { vec![mu, y[1].min(mu), mu, y[3].min(mu)] };
+        assert!(crate::matrix_operations::norm_inf_diff(&z, &simplex_cone_element(mu)) < 1e-12);
+        let distance = crate::matrix_operations::norm2_squared_diff(&y, &z);
+        for delta in [1e-3, 1e-2, 1e-1, 1.0, -1e-3, -1e-2, -1e-1, -1.0] {
+            let w = simplex_cone_element(mu + delta);
+            assert!(distance <= crate::matrix_operations::norm2_squared_diff(&y, &w) + 1e-12);
+        }
+    }
+}
+
+#[test]
+fn t_active_set_rectangle() {
+    let xmin = [0.0, 0.0, -1.0];
+    let xmax = [1.0, 2.0, -1.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    let x = [0.0, 1.9999, -1.0];
+    let active = rectangle.active_constraints(&x, 1e-3);
+    assert_eq!(
+        vec![
+            ActiveConstraint::LowerBound(0),
+            ActiveConstraint::UpperBound(1),
+            ActiveConstraint::LowerBound(2),
+            ActiveConstraint::UpperBound(2)
+        ],
+        active
+    );
+    assert!(rectangle.active_constraints(&x, 1e-6).len() == 3);
+    let mut y = [1.0, 1.0, 1.0];
+    rectangle.project_on_normal_cone(&x, &mut y, 1e-3);
+    assert_eq!([0.0, 1.0, 1.0], y);
+    assert_eq!(4.0 - 2.0, rectangle.support_function(&[-1.0, 2.0, 2.0]));
+    let rectangle_no_upper = Rectangle::new(Some(&xmin), None);
+    assert_eq!(
+        f64::INFINITY,
+        rectangle_no_upper.support_function(&[1.0, 0.0, 0.0])
+    );
+}
+
+#[test]
+fn t_active_set_halfspace_and_balls() {
+    let normal_vector = [1.0, 1.0];
+    let halfspace = Halfspace::new(&normal_vector, 1.0);
+    assert_eq!(
+        vec![ActiveConstraint::Boundary],
+        halfspace.active_constraints(&[0.5, 0.5], 1e-10)
+    );
+    assert!(halfspace.active_constraints(&[0.0, 0.5], 1e-10).is_empty());
+    assert_eq!(2.0, halfspace.support_function(&[2.0, 2.0]));
+    assert_eq!(f64::INFINITY, halfspace.support_function(&[-2.0, -2.0]));
+    assert_eq!(f64::INFINITY, halfspace.support_function(&[1.0, 0.0]));
+
+    let ball = Ball2::new(None, 2.0);
+    assert!((ball.support_function(&[3.0, 4.0]) - 10.0).abs() < 1e-12);
+    assert!(ball.active_constraints(&[1.0, 1.0], 1e-10).is_empty());
+    let mut y = [1.0, 1.0];
+    ball.project_on_normal_cone(&[1.0, 1.0], &mut y, 1e-10);
+    assert_eq!([0.0, 0.0], y);
+}
+
+#[test]
+fn t_try_new_rectangle() {
+    let xmin = [0.0; 3];
+    let xmax = [1.0; 3];
+    assert!(Rectangle::try_new(Some(&xmin), Some(&xmax)).is_ok());
+    assert!(Rectangle::try_new(None, Some(&xmax)).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::MissingBounds),
+        Rectangle::try_new(None, None).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 3,
+            found: 2
+        }),
+        Rectangle::try_new(Some(&xmin), Some(&xmax[..2])).err()
+    );
+    assert!(Rectangle::try_new_owned(Some(vec![0.0; 2]), Some(vec![1.0; 3])).is_err());
+    assert!(Rectangle::try_new_owned(Some(vec![0.0; 2]), None).is_ok());
+}
+
+#[test]
+fn t_try_new_finite_set() {
+    let data: &[&[f64]] = &[&[1.0, 2.0], &[3.0, 4.0], &[5.0]];
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 1
+        }),
+        FiniteSet::try_new(data).err()
+    );
+    let empty: &[&[f64]] = &[];
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        FiniteSet::try_new(empty).err()
+    );
+    assert!(FiniteSet::try_new(&data[..2]).is_ok());
+    assert!(FiniteSet::try_new_owned(vec![]).is_err());
+    assert!(FiniteSet::try_new_owned(vec![vec![1.0], vec![2.0]]).is_ok());
+}
+
+#[test]
+fn t_try_new_parameters() {
+    assert!(SecondOrderCone::try_new(1.0).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "alpha",
+            value: -1.0
+        }),
+        SecondOrderCone::try_new(-1.0).err()
+    );
+    assert!(SecondOrderCone::try_new(f64::NAN).is_err());
+    assert!(Simplex::try_new(0.0).is_err());
+    assert!(Ball1::try_new(None, 0.0).is_err());
+    assert!(Ball2::try_new(None, -1.0).is_err());
+    assert!(BallInf::try_new(None, f64::NAN).is_err());
+    assert!(Ball2::try_new(None, 1.0).is_ok());
+}
+
+#[test]
+fn t_try_new_affine_space() {
+    assert!(AffineSpace::try_new(vec![1.0, 2.0, 3.0, 4.0], vec![1.0, 2.0]).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 4,
+            found: 3
+        }),
+        AffineSpace::try_new(vec![1.0, 2.0, 3.0], vec![1.0, 2.0]).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        AffineSpace::try_new(vec![1.0, 2.0], vec![]).err()
+    );
+}
+
+#[test]
+fn t_try_add_constraint_cartesian_product() {
+    let cart_prod = CartesianProduct::new()
+        .try_add_constraint(3, Ball2::new(None, 1.0))
+        .unwrap();
+    assert_eq!(
+        Some(crate::ConstructionError::IndexNotIncreasing {
+            index: 2,
+            previous: 3
+        }),
+        cart_prod.try_add_constraint(2, Zero::new()).err()
+    );
+    assert!(CartesianProduct::new()
+        .try_add_constraint(0, Zero::new())
+        .is_err());
+    // the dimension of the halfspace is 2, but the segment has length 3
+    let normal_vector = [1.0, 1.0];
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 3,
+            found: 2
+        }),
+        CartesianProduct::new()
+            .try_add_constraint(3, Halfspace::new(&normal_vector, 1.0))
+            .err()
+    );
+}
+
+#[test]
+fn t_try_new_ellipsoid() {
+    assert!(Ellipsoid::try_new(vec![2.0, 0.5, 0.5, 1.0], Some(vec![1.0, 2.0]), 1.0).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "r",
+            value: 0.0
+        }),
+        Ellipsoid::try_new(vec![1.0], None, 0.0).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 4,
+            found: 3
+        }),
+        Ellipsoid::try_new(vec![1.0, 0.0, 0.0], None, 1.0).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        Ellipsoid::try_new(vec![1.0, 0.0, 0.0, 1.0], Some(vec![1.0; 3]), 1.0).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        Ellipsoid::try_new(vec![], None, 1.0).err()
+    );
+    // not symmetric
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "q",
+            value: 0.0
+        }),
+        Ellipsoid::try_new(vec![2.0, 0.5, 0.0, 1.0], None, 1.0).err()
+    );
+    // not positive definite (the eigenvalues are 3 and -1)
+    match Ellipsoid::try_new(vec![1.0, 2.0, 2.0, 1.0], None, 1.0) {
+        Err(crate::ConstructionError::InvalidParameter { name, value }) => {
+            assert_eq!("q", name);
+            unit_test_utils::assert_nearly_equal(-1.0, value, 1e-10, 1e-12, "wrong eigenvalue");
+        }
+        _ => panic!("Q is not positive definite"),
+    }
+}
+
+#[test]
+fn t_try_new_bounded_monotone() {
+    let xmin = [0.0, 1.0, 0.0];
+    let xmax = [1.0, 2.0, 2.0];
+    assert!(BoundedMonotone::try_new(Some(&xmin), Some(&xmax)).is_ok());
+    assert!(BoundedMonotone::try_new(None, Some(&xmax)).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::MissingBounds),
+        BoundedMonotone::try_new(None, None).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 3,
+            found: 2
+        }),
+        BoundedMonotone::try_new(Some(&xmin), Some(&xmax[..2])).err()
+    );
+    // x[1] >= 1 and x[2] >= x[1], but x[2] <= 0.5
+    let xmax_empty = [1.0, 2.0, 0.5];
+    assert_eq!(
+        Some(crate::ConstructionError::EmptySet),
+        BoundedMonotone::try_new(Some(&xmin), Some(&xmax_empty)).err()
+    );
+}
+
+#[test]
+fn t_try_new_nonconvex_and_matrix_sets() {
+    let center = [1.0, 2.0];
+    assert!(Sphere2::try_new(Some(&center), 1.0).is_ok());
+    assert!(Sphere2::try_new(None, 0.0).is_err());
+    assert!(Ball2Exterior::try_new(Some(&center), 0.5).is_ok());
+    assert!(Ball2Exterior::try_new(None, f64::NAN).is_err());
+    assert!(RectangleExterior::try_new(None, Some(&center)).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::MissingBounds),
+        RectangleExterior::try_new(None, None).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 1
+        }),
+        RectangleExterior::try_new(Some(&center), Some(&center[..1])).err()
+    );
+    assert!(PowerCone::try_new(0.5).is_ok());
+    assert!(PowerCone::try_new(1.0).is_err());
+    assert!(PsdCone::try_new(2, SymmetricStorage::Packed).is_ok());
+    assert!(PsdCone::try_new(0, SymmetricStorage::Full).is_err());
+    assert!(Stiefel::try_new(3, 2).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "n_cols",
+            value: 4.0
+        }),
+        Stiefel::try_new(3, 4).err()
+    );
+    assert!(SpecialOrthogonal::try_new(3).is_ok());
+    assert!(SpecialOrthogonal::try_new(0).is_err());
+}
+
+#[test]
+fn t_try_new_halfspace_hyperplane() {
+    let normal_vector = [1.0, 2.0];
+    let zero = [0.0, 0.0];
+    assert!(Halfspace::try_new(&normal_vector, 1.0).is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        Halfspace::try_new(&[], 1.0).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "normal_vector",
+            value: 0.0
+        }),
+        Hyperplane::try_new(&zero, 1.0).err()
+    );
+    assert!(Hyperplane::try_new(&normal_vector, 1.0).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn t_halfspace_zero_normal_vector() {
+    let _ = Halfspace::new_owned(vec![0.0; 3], 1.0);
+}
+
+#[test]
+fn t_try_with_cardinality_and_intersection() {
+    let xmin = [-1.0, -1.0];
+    let xmax = [1.0, 1.0];
+    assert!(Cardinality::new(1)
+        .try_with_bounds(Some(&xmin), Some(&xmax))
+        .is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "xmin",
+            value: 1.0
+        }),
+        Cardinality::new(1).try_with_bounds(Some(&xmax), None).err()
+    );
+    assert!(Cardinality::new(1).try_with_bounds(None, None).is_err());
+    assert!(Cardinality::new(1).try_with_ball2(-1.0).is_err());
+    let intersection = Intersection::new(2)
+        .try_add_constraint(Ball2::new(None, 1.0))
+        .and_then(|intersection| intersection.try_with_max_iterations(10))
+        .and_then(|intersection| intersection.try_with_tolerance(1e-6));
+    assert!(intersection.is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 3,
+            found: 2
+        }),
+        Intersection::new(3)
+            .try_add_constraint(Rectangle::new(Some(&xmin), None))
+            .err()
+    );
+    assert!(Intersection::new(2).try_with_max_iterations(0).is_err());
+    assert!(Intersection::new(2).try_with_tolerance(0.0).is_err());
+}
+
+#[test]
+fn t_try_new_union() {
+    let center = [1.0, 2.0];
+    let union = Union::try_new(vec![
+        Box::new(Ball2::new(None, 1.0)),
+        Box::new(Ball2::new(Some(&center), 1.0)),
+    ]);
+    assert!(union.is_ok());
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        Union::try_new(vec![]).err()
+    );
+    let center3 = [1.0, 2.0, 3.0];
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        Union::try_new(vec![
+            Box::new(Ball2::new(Some(&center), 1.0)),
+            Box::new(Ball2::new(Some(&center3), 1.0)),
+        ])
+        .err()
+    );
+}
+
+#[test]
+fn t_construction_error_display() {
+    let error = crate::ConstructionError::DimensionMismatch {
+        expected: 3,
+        found: 2,
+    };
+    assert_eq!(
+        "incompatible dimensions (expected 3, found 2)",
+        error.to_string()
+    );
+}
+
+#[test]
+fn t_translated_simplex() {
+    let translation = vec![1.0, -2.0, 0.5];
+    let translated_simplex = Translated::new(Simplex::new(2.0), translation.clone());
+    let mut x = [3.0, 1.0, -4.0];
+    translated_simplex.project(&mut x);
+    let mut x_expected = [3.0 - 1.0, 1.0 + 2.0, -4.0 - 0.5];
+    Simplex::new(2.0).project(&mut x_expected);
+    x_expected
+        .iter_mut()
+        .zip(translation.iter())
+        .for_each(|(xi, ci)| *xi += ci);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-12, 1e-14, "wrong result");
+    assert!(translated_simplex.contains(&[3.0, -2.0, 0.5], 1e-12));
+    assert!(!translated_simplex.contains(&[0.0, 0.0, 0.0], 1e-12));
+    assert!(translated_simplex.is_convex());
+    assert_eq!(Some(3), translated_simplex.dimension());
+}
+
+#[test]
+fn t_translated_is_ball() {
+    let center = [1.0, -1.0];
+    let ball = Ball2::new(Some(&center), 0.5);
+    let translated_ball = Translated::new(Ball2::new(None, 0.5), center.to_vec());
+    let mut x = [3.0, 2.0];
+    let mut x_expected = x;
+    translated_ball.project(&mut x);
+    ball.project(&mut x_expected);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[test]
+fn t_permuted_soc() {
+    let soc = SecondOrderCone::new(1.5).with_scalar_index(0);
+    let permuted_soc = Permuted::new(SecondOrderCone::new(1.5), vec![1, 2, 0]);
+    let mut x = [1.0, 3.0, -4.0];
+    let mut x_expected = x;
+    permuted_soc.project(&mut x);
+    soc.project(&mut x_expected);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-12, 1e-14, "wrong result");
+    assert_eq!(Some(3), permuted_soc.dimension());
+}
+
+#[test]
+fn t_permuted_rectangle() {
+    let xmin = [0.0, 1.0, 2.0];
+    let xmax = [0.5, 1.5, 2.5];
+    let permuted_rectangle = Permuted::new(Rectangle::new(Some(&xmin), Some(&xmax)), vec![2, 0, 1]);
+    let mut x = [10.0, 10.0, 10.0];
+    permuted_rectangle.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[1.5, 2.5, 0.5], &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[test]
+#[should_panic]
+fn t_permuted_invalid_permutation() {
+    let _ = Permuted::new(Zero::new(), vec![0, 2, 2]);
+}
+
+#[test]
+fn t_scaled_uniform() {
+    // {x : -2x in B(0, 1)} = B(0, 1/2)
+    let scaled_ball = Scaled::new(Ball2::new(None, 1.0), vec![-2.0, 2.0, -2.0]);
+    let mut x = [1.0, 2.0, -2.0];
+    let mut x_expected = x;
+    scaled_ball.project(&mut x);
+    Ball2::new(None, 0.5).project(&mut x_expected);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[test]
+fn t_scaled_rectangle() {
+    // {x : xmin <= Dx <= xmax} is a rectangle
+    let xmin = [-1.0, 0.0];
+    let xmax = [1.0, 3.0];
+    let scaled_rectangle = Scaled::new(Rectangle::new(Some(&xmin), Some(&xmax)), vec![2.0, -3.0]);
+    let mut x = [4.0, 2.0];
+    scaled_rectangle.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.5, 0.0], &x, 1e-8, 1e-10, "wrong result");
+}
+
+#[test]
+fn t_scaled_ball_is_ellipsoid() {
+    // {x : ||Dx|| <= 1} = {x : x'D^2 x <= 1}
+    let diagonal = vec![1.0, 2.0, 0.5];
+    let q = vec![1.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 0.25];
+    let scaled_ball = Scaled::new(Ball2::new(None, 1.0), diagonal).with_tolerance(1e-12);
+    let ellipsoid = Ellipsoid::new(q, None, 1.0);
+    let mut x = [1.0, 2.0, -3.0];
+    let mut x_expected = x;
+    scaled_ball.project(&mut x);
+    ellipsoid.project(&mut x_expected);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-7, 1e-9, "wrong result");
+    assert!(scaled_ball.contains(&x, 1e-12));
+    assert!(scaled_ball.is_convex());
+}
+
+#[test]
+#[should_panic]
+fn t_scaled_zero_diagonal() {
+    let _ = Scaled::new(Ball2::new(None, 1.0), vec![1.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn t_scaled_nonconvex_nonuniform() {
+    let _ = Scaled::new(Sphere2::new(None, 1.0), vec![1.0, 2.0]);
+}
+
+#[test]
+fn t_scaled_try_new() {
+    assert_eq!(
+        Err(crate::ConstructionError::InvalidParameter {
+            name: "diagonal",
+            value: 0.0
+        }),
+        Scaled::try_new(Ball2::new(None, 1.0), vec![1.0, 0.0]).map(|_| ())
+    );
+    assert_eq!(
+        Err(crate::ConstructionError::NonconvexSet),
+        Scaled::try_new(Sphere2::new(None, 1.0), vec![1.0, 2.0]).map(|_| ())
+    );
+    // projections on nonconvex sets are fine if D is a multiple of a signature matrix
+    assert!(Scaled::try_new(Sphere2::new(None, 1.0), vec![2.0, -2.0]).is_ok());
+    let center = [1.0, 2.0];
+    assert_eq!(
+        Err(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        Scaled::try_new(Ball2::new(Some(&center), 1.0), vec![1.0; 3]).map(|_| ())
+    );
+}
+
+#[test]
+fn t_translated_permuted_try_new() {
+    let center = [1.0, 2.0];
+    assert!(Translated::try_new(Ball2::new(Some(&center), 1.0), vec![0.0; 2]).is_ok());
+    assert!(Translated::try_new(Simplex::new(1.0), vec![0.0; 5]).is_ok());
+    assert_eq!(
+        Err(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        Translated::try_new(Ball2::new(Some(&center), 1.0), vec![0.0; 3]).map(|_| ())
+    );
+    assert!(Permuted::try_new(SecondOrderCone::new(1.0), vec![2, 0, 1]).is_ok());
+    assert_eq!(
+        Err(crate::ConstructionError::InvalidParameter {
+            name: "permutation",
+            value: 2.0
+        }),
+        Permuted::try_new(Zero::new(), vec![0, 2, 2]).map(|_| ())
+    );
+    assert_eq!(
+        Err(crate::ConstructionError::DimensionMismatch {
+            expected: 2,
+            found: 3
+        }),
+        Permuted::try_new(Ball2::new(Some(&center), 1.0), vec![2, 0, 1]).map(|_| ())
+    );
+    let permuted = Permuted::new(Ball2::new(Some(&center), 1.0), vec![1, 0]);
+    let mut x = [5.0, 1.0];
+    permuted.clone().project(&mut x);
+    assert!(permuted.contains(&x, 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn t_translated_wrong_dimension() {
+    let center = [1.0, 2.0];
+    let _ = Translated::new(Ball2::new(Some(&center), 1.0), vec![0.0; 3]);
+}
+
+#[test]
+fn t_affine_space_is_sync() {
+    fn assert_sync<T: Sync>(_: &T) {}
+    let affine_space = AffineSpace::new(vec![1.0, 2.0, 3.0, 4.0], vec![1.0, 2.0]);
+    assert_sync(&affine_space);
+}
+
+#[test]
+fn t_wrappers_are_sync() {
+    fn assert_sync<T: Sync>(_: &T) {}
+    let xmin = [-1.0, 0.0];
+    let xmax = [1.0, 3.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    assert_sync(&Scaled::new(rectangle.clone(), vec![2.0, -3.0]));
+    assert_sync(&Permuted::new(rectangle, vec![1, 0]));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn t_constraint_spec_json() {
+    let json = r#"{
+        "type": "CartesianProduct",
+        "segments": [2, 4, 7],
+        "constraints": [
+            { "type": "Rectangle", "xmin": [-1.0, -1.0], "xmax": [1.0, 1.0] },
+            { "type": "Translated", "set": { "type": "Simplex", "alpha": 1.0 }, "translation": [1.0, 1.0] },
+            { "type": "SecondOrderCone", "alpha": 1.0, "scalar_index": 0 }
+        ]
+    }"#;
+    let spec: ConstraintSpec = serde_json::from_str(json).unwrap();
+    let constraint = spec.build().unwrap();
+    assert_eq!(Some(7), constraint.dimension());
+    let mut x = [2.0, -0.5, 3.0, 1.0, 1.0, 3.0, -4.0];
+    let mut x_expected = x;
+    constraint.project(&mut x);
+    let xmin = [-1.0, -1.0];
+    let xmax = [1.0, 1.0];
+    CartesianProduct::new()
+        .add_constraint(2, Rectangle::new(Some(&xmin), Some(&xmax)))
+        .add_constraint(4, Translated::new(Simplex::new(1.0), vec![1.0, 1.0]))
+        .add_constraint(7, SecondOrderCone::new(1.0).with_scalar_index(0))
+        .project(&mut x_expected);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn t_constraint_spec_roundtrip() {
+    let spec = ConstraintSpec::Union {
+        constraints: vec![
+            ConstraintSpec::Ball2 {
+                center: Some(vec![-2.0, 0.0]),
+                radius: 1.0,
+            },
+            ConstraintSpec::Halfspace {
+                normal_vector: vec![1.0, 0.0],
+                offset: -3.0,
+            },
+        ],
+    };
+    let json = serde_json::to_string(&spec).unwrap();
+    let spec_deserialized: ConstraintSpec = serde_json::from_str(&json).unwrap();
+    assert_eq!(spec, spec_deserialized);
+    let psd_spec = ConstraintSpec::PsdCone {
+        n: 3,
+        storage: SymmetricStorage::Packed,
+    };
+    let json = serde_json::to_string(&psd_spec).unwrap();
+    assert_eq!(psd_spec, serde_json::from_str(&json).unwrap());
+    let mut x = [-0.5, 0.0];
+    spec_deserialized.build().unwrap().project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[-1.0, 0.0], &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn t_constraint_spec_errors() {
+    let spec: ConstraintSpec =
+        serde_json::from_str(r#"{ "type": "Ball2", "radius": -1.0 }"#).unwrap();
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "radius",
+            value: -1.0
+        }),
+        spec.build().err()
+    );
+    let spec = ConstraintSpec::CartesianProduct {
+        segments: vec![3, 2],
+        constraints: vec![ConstraintSpec::Zero, ConstraintSpec::NoConstraints],
+    };
+    assert_eq!(
+        Some(crate::ConstructionError::IndexNotIncreasing {
+            index: 2,
+            previous: 3
+        }),
+        spec.build().err()
+    );
+    let spec = ConstraintSpec::CartesianProduct {
+        segments: vec![2, 5],
+        constraints: vec![
+            ConstraintSpec::Zero,
+            ConstraintSpec::Halfspace {
+                normal_vector: vec![1.0, 1.0],
+                offset: 0.0,
+            },
+        ],
+    };
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 3,
+            found: 2
+        }),
+        spec.build().err()
+    );
+    let spec = ConstraintSpec::Ellipsoid {
+        q: vec![1.0, 2.0, 2.0, 1.0],
+        center: None,
+        r: 1.0,
+    };
+    assert!(spec.build().is_err());
+    let spec = ConstraintSpec::BoundedMonotone {
+        xmin: Some(vec![1.0, 0.0]),
+        xmax: Some(vec![2.0, 0.5]),
+    };
+    assert_eq!(Some(crate::ConstructionError::EmptySet), spec.build().err());
+    let spec = ConstraintSpec::Intersection {
+        dimension: 2,
+        constraints: vec![ConstraintSpec::Halfspace {
+            normal_vector: vec![1.0, 1.0, 1.0],
+            offset: 0.0,
+        }],
+        max_iterations: None,
+        tolerance: None,
+    };
+    assert!(spec.build().is_err());
+    let spec = ConstraintSpec::Rectangle {
+        xmin: None,
+        xmax: None,
+    };
+    assert_eq!(
+        Some(crate::ConstructionError::MissingBounds),
+        spec.build().err()
+    );
+    assert!(serde_json::from_str::<ConstraintSpec>(r#"{ "type": "Ball7" }"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn t_constraint_spec_nested_errors() {
+    let ball = |dimension: usize| ConstraintSpec::Ball2 {
+        center: Some(vec![0.0; dimension]),
+        radius: 1.0,
+    };
+    let spec = ConstraintSpec::Union {
+        constraints: vec![],
+    };
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        spec.build().err()
+    );
+    let spec = ConstraintSpec::Union {
+        constraints: vec![ball(2), ConstraintSpec::Simplex { alpha: 1.0 }, ball(3)],
+    };
+    let mismatch = Some(crate::ConstructionError::DimensionMismatch {
+        expected: 2,
+        found: 3,
+    });
+    assert_eq!(mismatch, spec.build().err());
+    let spec = ConstraintSpec::Intersection {
+        dimension: 2,
+        constraints: vec![ball(3)],
+        max_iterations: None,
+        tolerance: None,
+    };
+    assert_eq!(mismatch, spec.build().err());
+    let spec = ConstraintSpec::Translated {
+        set: Box::new(ball(2)),
+        translation: vec![1.0; 3],
+    };
+    assert_eq!(mismatch, spec.build().err());
+    let spec = ConstraintSpec::Permuted {
+        set: Box::new(ball(2)),
+        permutation: vec![2, 0, 1],
+    };
+    assert_eq!(mismatch, spec.build().err());
+    let spec = ConstraintSpec::Halfspace {
+        normal_vector: vec![0.0, 0.0],
+        offset: 1.0,
+    };
+    assert!(spec.build().is_err());
+    let spec = ConstraintSpec::CappedSimplex {
+        upper_bounds: vec![0.5, 0.5],
+        alpha: 2.0,
+    };
+    assert_eq!(Some(crate::ConstructionError::EmptySet), spec.build().err());
+}
+
+/// Projection on the simplex by sorting (reference implementation)
+fn simplex_projection_by_sorting(x: &mut [f64], alpha: f64) {
+    let mut u = x.to_vec();
+    u.sort_unstable_by(|a, b| b.partial_cmp(a).unwrap());
+    let mut sum = 0.0;
+    let mut tau = 0.0;
+    for (j, &uj) in u.iter().enumerate() {
+        sum += uj;
+        let tau_j = (sum - alpha) / (j as f64 + 1.0);
+        if uj > tau_j {
+            tau = tau_j;
+        }
+    }
+    x.iter_mut().for_each(|xi| *xi = f64::max(*xi - tau, 0.0));
+}
+
+#[test]
+fn t_simplex_projection_versus_sorting() {
+    let n = 200;
+    let increasing: Vec<f64> = (0..n).map(|i| i as f64 / 10.0).collect();
+    let decreasing: Vec<f64> = increasing.iter().rev().cloned().collect();
+    let ties = vec![1.0; n];
+    let random: Vec<f64> = (0..n)
+        .map(|_| 10. * (2. * rand::random::<f64>() - 1.))
+        .collect();
+    for v in [increasing, decreasing, ties, random].iter() {
+        for &alpha in [1e-3, 1.0, 50.0, 1e4].iter() {
+            let mut x = v.clone();
+            let mut x_expected = v.clone();
+            Simplex::new(alpha).project(&mut x);
+            simplex_projection_by_sorting(&mut x_expected, alpha);
+            assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-10);
+        }
+    }
+}
+
+#[test]
+fn t_weighted_ball1_unit_weights() {
+    let n = 20;
+    let weights = vec![1.0; n];
+    let center: Vec<f64> = (0..n).map(|i| (i as f64).sin()).collect();
+    let weighted_ball = WeightedBall1::new(&weights, Some(&center), 2.0);
+    let ball = Ball1::new(Some(&center), 2.0);
+    for _ in 0..100 {
+        let mut x: Vec<f64> = (0..n)
+            .map(|_| 5. * (2. * rand::random::<f64>() - 1.))
+            .collect();
+        let mut x_expected = x.clone();
+        weighted_ball.project(&mut x);
+        ball.project(&mut x_expected);
+        assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-10);
+    }
+}
+
+#[test]
+fn t_weighted_ball1_versus_scaled() {
+    // {x : sum w_i |x_i| <= r} = {x : Wx in B_1(r)}
+    let weights = [0.5, 2.0, 1.0, 3.0];
+    let weighted_ball = WeightedBall1::new(&weights, None, 1.5);
+    let scaled_ball = Scaled::new(Ball1::new(None, 1.5), weights.to_vec()).with_tolerance(1e-12);
+    let mut x = [1.0, -2.0, 0.3, 0.2];
+    let mut x_expected = x;
+    weighted_ball.project(&mut x);
+    scaled_ball.project(&mut x_expected);
+    unit_test_utils::assert_nearly_equal_array(&x_expected, &x, 1e-7, 1e-9, "wrong result");
+    let weighted_norm = x
+        .iter()
+        .zip(weights.iter())
+        .fold(0.0, |sum, (xi, wi)| sum + wi * xi.abs());
+    unit_test_utils::assert_nearly_equal(1.5, weighted_norm, 1e-12, 1e-14, "not on the boundary");
+    assert!(weighted_ball.contains(&x, 1e-12));
+    assert!(!weighted_ball.contains(&[1.0, 1.0, 1.0, 1.0], 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn t_weighted_ball1_nonpositive_weights() {
+    let weights = [1.0, 0.0];
+    let _ = WeightedBall1::new(&weights, None, 1.0);
+}
+
+#[test]
+fn t_capped_simplex_versus_polyhedron() {
+    // {x : 1'x = alpha, 0 <= x <= u}
+    let u = [0.5, 2.0, 1.0, 0.2, 3.0];
+    let alpha = 2.5;
+    let n = u.len();
+    let mut a = vec![0.0; 2 * n * n];
+    let mut b = vec![0.0; 2 * n];
+    for i in 0..n {
+        a[i * n + i] = 1.0;
+        b[i] = u[i];
+        a[(n + i) * n + i] = -1.0;
+    }
+    let polyhedron = Polyhedron::new(a, b).with_equality_constraints(vec![1.0; n], vec![alpha]);
+    let capped_simplex = CappedSimplex::new(&u, alpha);
+    for _ in 0..200 {
+        let mut x: Vec<f64> = (0..n)
+            .map(|_| 5. * (2. * rand::random::<f64>() - 1.))
+            .collect();
+        let mut x_expected = x.clone();
+        capped_simplex.project(&mut x);
+        polyhedron.project(&mut x_expected);
+        assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-9);
+        unit_test_utils::assert_nearly_equal(
+            alpha,
+            crate::matrix_operations::sum(&x),
+            1e-12,
+            1e-12,
+            "wrong sum",
+        );
+    }
+}
+
+#[test]
+fn t_capped_simplex_infinite_bounds_is_simplex() {
+    let n = 50;
+    let u = vec![f64::INFINITY; n];
+    let capped_simplex = CappedSimplex::new_owned(u, 3.0);
+    let simplex = Simplex::new(3.0);
+    for _ in 0..100 {
+        let mut x: Vec<f64> = (0..n)
+            .map(|_| 5. * (2. * rand::random::<f64>() - 1.))
+            .collect();
+        let mut x_expected = x.clone();
+        capped_simplex.project(&mut x);
+        simplex.project(&mut x_expected);
+        assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-10);
+    }
+}
+
+#[test]
+fn t_capped_simplex_tight() {
+    // sum(u) = alpha, so the set is {u}
+    let u = [0.5, 1.0, 1.5];
+    let capped_simplex = CappedSimplex::new(&u, 3.0);
+    let mut x = [10.0, -4.0, 0.0];
+    capped_simplex.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&u, &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[test]
+#[should_panic]
+fn t_capped_simplex_empty() {
+    let u = [0.5, 0.5];
+    let _ = CappedSimplex::new(&u, 2.0);
+}
+
+#[test]
+fn t_finite_set_kd_tree_versus_linear_scan() {
+    for &n in &[1, 3, 5] {
+        let mut data: Vec<Vec<f64>> = (0..2000)
+            .map(|_| (0..n).map(|_| 2. * rand::random::<f64>() - 1.).collect())
+            .collect();
+        // duplicate elements
+        data.extend_from_within(..100);
+        let data_refs: Vec<&[f64]> = data.iter().map(|v| v.as_slice()).collect();
+        let finite_set = FiniteSet::new(&data_refs);
+        let finite_set_kd_tree = FiniteSet::new_with_kd_tree(data.clone());
+        for _ in 0..200 {
+            let x: Vec<f64> = (0..n).map(|_| 3. * rand::random::<f64>() - 1.5).collect();
+            let mut x_kd_tree = x.clone();
+            finite_set_kd_tree.project(&mut x_kd_tree);
+            assert!(data.contains(&x_kd_tree));
+            unit_test_utils::assert_nearly_equal(
+                finite_set.distance_squared(&x),
+                crate::matrix_operations::norm2_squared_diff(&x, &x_kd_tree),
+                1e-12,
+                1e-14,
+                "the kd-tree projection is not the nearest element",
+            );
+            unit_test_utils::assert_nearly_equal(
+                finite_set.distance_squared(&x),
+                finite_set_kd_tree.distance_squared(&x),
+                1e-12,
+                1e-14,
+                "wrong distance",
+            );
+        }
+    }
+}
+
+#[test]
+fn t_finite_set_kd_tree_grid() {
+    let data = (0..100)
+        .map(|i| vec![(i % 10) as f64, (i / 10) as f64])
+        .collect();
+    let finite_set = FiniteSet::new_with_kd_tree(data);
+    assert_eq!(Some(2), finite_set.dimension());
+    let mut x = [-3.0, 4.4];
+    finite_set.project(&mut x);
+    assert_eq!([0.0, 4.0], x);
+    let mut x = [7.6, 12.0];
+    finite_set.project(&mut x);
+    assert_eq!([8.0, 9.0], x);
+    assert!(finite_set.contains(&[3.0, 3.0], 1e-12));
+    assert!(!finite_set.contains(&[3.5, 3.0], 1e-12));
+}
+
+#[test]
+#[should_panic]
+fn t_finite_set_kd_tree_inconsistent_dimensions() {
+    let _ = FiniteSet::new_with_kd_tree(vec![vec![1.0, 2.0], vec![3.0]]);
+}
+
+#[test]
+fn t_convex_hull_square() {
+    let data: &[&[f64]] = &[
+        &[0.0, 0.0],
+        &[1.0, 0.0],
+        &[0.0, 1.0],
+        &[1.0, 1.0],
+        &[0.5, 0.5],
+    ];
+    let convex_hull = ConvexHull::new(data);
+    let xmin = [0.0, 0.0];
+    let xmax = [1.0, 1.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    for _ in 0..200 {
+        let mut x: Vec<f64> = (0..2).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut x_expected = x.clone();
+        convex_hull.project(&mut x);
+        rectangle.project(&mut x_expected);
+        assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-10);
+    }
+}
+
+#[test]
+fn t_convex_hull_random_optimality_conditions() {
+    let n = 4;
+    let data: Vec<Vec<f64>> = (0..50)
+        .map(|_| (0..n).map(|_| 2. * rand::random::<f64>() - 1.).collect())
+        .collect();
+    let convex_hull = FiniteSet::new_with_kd_tree(data.clone()).convex_hull();
+    for _ in 0..100 {
+        let x: Vec<f64> = (0..n).map(|_| 6. * rand::random::<f64>() - 3.).collect();
+        let mut z = x.clone();
+        convex_hull.project(&mut z);
+        // the projection, z, satisfies <x - z, p - z> <= 0 for all points p
+        for p in data.iter() {
+            let inner_product = x
+                .iter()
+                .zip(z.iter())
+                .zip(p.iter())
+                .fold(0.0, |sum, ((xi, zi), pi)| sum + (xi - zi) * (pi - zi));
+            assert!(inner_product <= 1e-8, "optimality conditions not satisfied");
+        }
+    }
+}
+
+#[test]
+fn t_convex_hull_interior_point() {
+    let data = vec![
+        vec![0.0, 0.0, 0.0],
+        vec![1.0, 0.0, 0.0],
+        vec![0.0, 1.0, 0.0],
+        vec![0.0, 0.0, 1.0],
+    ];
+    let convex_hull = ConvexHull::new_owned(data);
+    assert!(convex_hull.is_convex());
+    let mut x = [0.2, 0.3, 0.1];
+    convex_hull.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.2, 0.3, 0.1], &x, 1e-12, 1e-14, "wrong result");
+    let mut x = [1.0, 1.0, 1.0];
+    convex_hull.project(&mut x);
+    let third = 1.0 / 3.0;
+    unit_test_utils::assert_nearly_equal_array(
+        &[third, third, third],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong result",
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn t_constraint_spec_finite_sets() {
+    let spec: ConstraintSpec = serde_json::from_str(
+        r#"{ "type": "FiniteSet", "data": [[0.0, 0.0], [1.0, 1.0]], "kd_tree": true }"#,
+    )
+    .unwrap();
+    let mut x = [0.9, 0.7];
+    spec.build().unwrap().project(&mut x);
+    assert_eq!([1.0, 1.0], x);
+    let spec: ConstraintSpec =
+        serde_json::from_str(r#"{ "type": "ConvexHull", "data": [[0.0, 0.0], [1.0, 1.0]] }"#)
+            .unwrap();
+    let mut x = [1.0, 0.0];
+    spec.build().unwrap().project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[0.5, 0.5], &x, 1e-12, 1e-14, "wrong result");
+}
+
+#[test]
+fn t_affine_space_rank_deficient() {
+    // the last two equations are a copy and a combination of the first two
+    let a = vec![
+        1.0, 2.0, 0.0, -1.0, 0.0, 1.0, 1.0, 3.0, 1.0, 2.0, 0.0, -1.0, 1.0, 3.0, 1.0, 2.0,
+    ];
+    let b = vec![1.0, -2.0, 1.0, -1.0];
+    let affine_set = AffineSpace::new(a.clone(), b.clone());
+    let affine_set_full_rank = AffineSpace::new(a[..8].to_vec(), b[..2].to_vec());
+    for _ in 0..100 {
+        let mut x: Vec<f64> = (0..4).map(|_| 10. * rand::random::<f64>() - 5.).collect();
+        let mut x_expected = x.clone();
+        affine_set.project(&mut x);
+        affine_set_full_rank.project(&mut x_expected);
+        assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-10);
+    }
+}
+
+#[test]
+fn t_affine_space_more_rows_than_columns() {
+    // A is 4-by-2 and has rank 2
+    let a = vec![1.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, -1.0];
+    let b = vec![1.0, 2.0, 3.0, -1.0];
+    let affine_set = AffineSpace::new(a, b);
+    let mut x = [10.0, -4.0];
+    affine_set.project(&mut x);
+    unit_test_utils::assert_nearly_equal_array(&[1.0, 2.0], &x, 1e-12, 1e-12, "wrong result");
+}
+
+#[test]
+#[should_panic]
+fn t_affine_space_inconsistent() {
+    let _ = AffineSpace::new(vec![1.0, 1.0, 2.0, 2.0], vec![1.0, 3.0]);
+}
+
+#[test]
+#[should_panic]
+fn t_affine_space_update_inconsistent() {
+    let mut affine_set = AffineSpace::new(vec![1.0, 1.0, 2.0, 2.0], vec![1.0, 2.0]);
+    affine_set.set_b(&[1.0, 1.0]);
+}
+
+/// Row-wise data of the matrix of the equality constraints of a multiple-shooting
+/// formulation of an optimal control problem with horizon `n_horizon`, three
+/// states and two inputs (random linear dynamics), followed by `n_redundant`
+/// (random) linear combinations of these constraints; returns the matrix and
+/// its number of columns
+fn multiple_shooting_matrix(n_horizon: usize, n_redundant: usize) -> (Vec<f64>, usize) {
+    let (nx, nu) = (3, 2);
+    let n_cols = n_horizon * (nx + nu) + nx;
+    let mut a = vec![];
+    // initial state
+    for i in 0..nx {
+        let mut row = vec![0.0; n_cols];
+        row[i] = 1.0;
+        a.push(row);
+    }
+    // dynamics: x(k+1) - A x(k) - B u(k) = 0
+    for k in 0..n_horizon {
+        let offset = k * (nx + nu);
+        for i in 0..nx {
+            let mut row = vec![0.0; n_cols];
+            row[offset + nx + nu + i] = 1.0;
+            for j in 0..nx + nu {
+                row[offset + j] = 2. * rand::random::<f64>() - 1.;
+            }
+            a.push(row);
+        }
+    }
+    let m = a.len();
+    for _ in 0..n_redundant {
+        let i = rand::random::<usize>() % m;
+        let j = rand::random::<usize>() % m;
+        let row = a[i]
+            .iter()
+            .zip(a[j].iter())
+            .map(|(x, y)| 2.0 * x - y)
+            .collect();
+        a.push(row);
+    }
+    (a.concat(), n_cols)
+}
+
+/// Converts a matrix given by its row-wise data to CSR format
+fn dense_to_csr(a: &[f64], n_cols: usize) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+    let mut row_offsets = vec![0];
+    let mut col_indices = vec![];
+    let mut values = vec![];
+    for row in a.chunks(n_cols) {
+        for (j, &aij) in row.iter().enumerate() {
+            if aij != 0.0 {
+                col_indices.push(j);
+                values.push(aij);
+            }
+        }
+        row_offsets.push(values.len());
+    }
+    (row_offsets, col_indices, values)
+}
+
+#[test]
+fn t_affine_space_sparse_versus_dense() {
+    for &n_redundant in &[0, 5] {
+        let (a, n) = multiple_shooting_matrix(20, n_redundant);
+        let m = a.len() / n;
+        // b = A z for a random z, so that Ax = b is consistent
+        let z: Vec<f64> = (0..n).map(|_| 2. * rand::random::<f64>() - 1.).collect();
+        let b: Vec<f64> = a
+            .chunks(n)
+            .map(|row| crate::matrix_operations::inner_product(row, &z))
+            .collect();
+        let (row_offsets, col_indices, values) = dense_to_csr(&a, n);
+        let affine_set_sparse =
+            AffineSpace::new_sparse(n, row_offsets, col_indices, values, b.clone());
+        let affine_set_dense = AffineSpace::new(a.clone(), b.clone());
+        assert_eq!(Some(n), affine_set_sparse.dimension());
+        for _ in 0..20 {
+            let mut x: Vec<f64> = (0..n).map(|_| 10. * rand::random::<f64>() - 5.).collect();
+            let mut x_dense = x.clone();
+            affine_set_sparse.project(&mut x);
+            affine_set_dense.project(&mut x_dense);
+            assert!(crate::matrix_operations::norm_inf_diff(&x, &x_dense) <= 1e-8);
+            let residual = (0..m).fold(0.0_f64, |max, i| {
+                let ai_x = crate::matrix_operations::inner_product(&a[i * n..(i + 1) * n], &x);
+                max.max((ai_x - b[i]).abs())
+            });
+            assert!(residual <= 1e-8, "Ax = b is not satisfied");
+        }
+    }
+}
+
+#[test]
+fn t_affine_space_sparse_update() {
+    let (a, n) = multiple_shooting_matrix(10, 2);
+    let (row_offsets, col_indices, values) = dense_to_csr(&a, n);
+    let m = row_offsets.len() - 1;
+    let mut affine_set = AffineSpace::new_sparse(
+        n,
+        row_offsets.clone(),
+        col_indices.clone(),
+        values.clone(),
+        vec![0.0; m],
+    );
+    // new values with the same sparsity pattern (the redundant rows are updated too)
+    let mut a_new = a.clone();
+    let scale: Vec<f64> = (0..n).map(|_| 1.0 + rand::random::<f64>()).collect();
+    a_new.chunks_mut(n).for_each(|row| {
+        row.iter_mut()
+            .zip(scale.iter())
+            .for_each(|(aij, s)| *aij *= s)
+    });
+    let (_, _, values_new) = dense_to_csr(&a_new, n);
+    affine_set.set_a_values(&values_new);
+    let b_new: Vec<f64> = a_new.chunks(n).map(|row| row.iter().sum()).collect();
+    affine_set.set_b(&b_new);
+    let mut x: Vec<f64> = (0..n).map(|_| 10. * rand::random::<f64>() - 5.).collect();
+    let mut x_expected = x.clone();
+    affine_set.project(&mut x);
+    AffineSpace::new(a_new, b_new).project(&mut x_expected);
+    assert!(crate::matrix_operations::norm_inf_diff(&x, &x_expected) <= 1e-8);
+}
+
+#[test]
+#[should_panic]
+fn t_affine_space_sparse_inconsistent() {
+    // the second row is a copy of the first one
+    let _ = AffineSpace::new_sparse(
+        2,
+        vec![0, 2, 4],
+        vec![0, 1, 0, 1],
+        vec![1.0; 4],
+        vec![1.0, 2.0],
+    );
+}
+
+#[test]
+fn t_try_new_affine_space_sparse() {
+    assert!(
+        AffineSpace::try_new_sparse(2, vec![0, 1, 2], vec![0, 1], vec![1.0; 2], vec![1.0; 2])
+            .is_ok()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::EmptyData),
+        AffineSpace::try_new_sparse(2, vec![0], vec![], vec![], vec![]).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::DimensionMismatch {
+            expected: 3,
+            found: 2
+        }),
+        AffineSpace::try_new_sparse(2, vec![0, 1], vec![0, 1], vec![1.0; 2], vec![1.0; 2]).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "row_offsets",
+            value: 2.0
+        }),
+        AffineSpace::try_new_sparse(2, vec![0, 3, 2], vec![0, 1], vec![1.0; 2], vec![1.0; 2]).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::InvalidParameter {
+            name: "col_indices",
+            value: 2.0
+        }),
+        AffineSpace::try_new_sparse(2, vec![0, 1, 2], vec![0, 2], vec![1.0; 2], vec![1.0; 2]).err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::InconsistentSystem),
+        AffineSpace::try_new_sparse(2, vec![0, 1, 2], vec![0, 0], vec![1.0; 2], vec![1.0, 2.0])
+            .err()
+    );
+    assert_eq!(
+        Some(crate::ConstructionError::InconsistentSystem),
+        AffineSpace::try_new(vec![1.0, 1.0, 2.0, 2.0], vec![1.0, 3.0]).err()
+    );
+}
@@ -0,0 +1,121 @@
+use super::Constraint;
+use crate::ConstructionError;
+
+/// A translated set
+///
+/// Given a set $C$ and a vector $c$, this is the set
+///
+/// $$
+/// c + C = \\{x \in \mathbb{R}^n {}:{} x - c \in C\\}.
+/// $$
+///
+/// The projection on $c + C$ is $\Pi_{c + C}(x) = c + \Pi_C(x - c)$. This can be
+/// used to shift sets which have no center (e.g., `Simplex` or `SecondOrderCone`).
+///
+#[derive(Clone)]
+pub struct Translated<C: Constraint> {
+    set: C,
+    translation: Vec<f64>,
+}
+
+impl<C: Constraint> Translated<C> {
+    /// Construct a new translated set, $c + C$
+    ///
+    /// # Arguments
+    ///
+    /// - `set`: the set $C$
+    /// - `translation`: the vector $c$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of $C$ (see `Constraint::dimension`)
+    /// is known and it is not equal to the length of `translation`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // the set of all x such that x - (1, 1) is in the simplex
+    /// let translated_simplex = Translated::new(Simplex::new(1.0), vec![1.0, 1.0]);
+    /// let mut x = [3.0, 1.0];
+    /// translated_simplex.project(&mut x);
+    /// assert_eq!([2.0, 1.0], x);
+    /// ```
+    ///
+    pub fn new(set: C, translation: Vec<f64>) -> Self {
+        assert!(
+            set.dimension().unwrap_or(translation.len()) == translation.len(),
+            "the set and the translation have incompatible dimensions"
+        );
+        Translated { set, translation }
+    }
+
+    /// Construct a new translated set, or return an error if the dimensions
+    /// of the set and the translation are incompatible (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// `ConstructionError::DimensionMismatch` if the dimension of $C$ is known
+    /// and it is not equal to the length of `translation`
+    ///
+    pub fn try_new(set: C, translation: Vec<f64>) -> Result<Self, ConstructionError> {
+        match set.dimension() {
+            Some(expected) if expected != translation.len() => {
+                Err(ConstructionError::DimensionMismatch {
+                    expected,
+                    found: translation.len(),
+                })
+            }
+            _ => Ok(Translated::new(set, translation)),
+        }
+    }
+
+    /// The vector $x - c$
+    fn shifted(&self, x: &[f64]) -> Vec<f64> {
+        assert!(x.len() == self.translation.len(), "x has wrong dimension");
+        x.iter()
+            .zip(self.translation.iter())
+            .map(|(xi, ci)| xi - ci)
+            .collect()
+    }
+}
+
+impl<C: Constraint> Constraint for Translated<C> {
+    /// Projection on the translated set
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the dimension
+    /// of the translation vector, or if the projection on $C$ panics.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.translation.len(), "x has wrong dimension");
+        x.iter_mut()
+            .zip(self.translation.iter())
+            .for_each(|(xi, ci)| *xi -= ci);
+        self.set.project(x);
+        x.iter_mut()
+            .zip(self.translation.iter())
+            .for_each(|(xi, ci)| *xi += ci);
+    }
+
+    /// A translated set is convex if and only if $C$ is convex
+    fn is_convex(&self) -> bool {
+        self.set.is_convex()
+    }
+
+    /// The distance from $c + C$ is $\mathrm{dist}_C(x - c)$
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        self.set.distance_squared(&self.shifted(x))
+    }
+
+    /// Checks whether $x - c$ is in $C$, up to the given tolerance
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        self.set.contains(&self.shifted(x), tol)
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.translation.len())
+    }
+}
@@ -0,0 +1,161 @@
+use super::Constraint;
+use crate::{matrix_operations, ConstructionError};
+
+/// Union of constraints
+///
+/// Union of sets, $C_0, C_1, \ldots, C_{k-1}$, of the same dimension, that is,
+///
+/// $$
+/// C = C_0 \cup C_1 \cup \ldots \cup C_{k-1}.
+/// $$
+///
+/// Sets $C_i$ are structures which implement the trait `Constraint`.
+///
+/// The projection on $C$ is the projection on the set $C_i$ which is closest
+/// to the given point (in case of ties, the set that was added first is chosen).
+/// Unions of sets can be used to model disjunctive constraints, e.g., "$x$ is
+/// either in $C_0$ or in $C_1$"; such sets are, in general, nonconvex.
+///
+#[derive(Default)]
+pub struct Union<'a> {
+    constraints: Vec<Box<dyn Constraint + 'a>>,
+}
+
+impl<'a> Union<'a> {
+    /// Construct a new (empty) union of sets
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    ///
+    /// // x is either in the ball of radius 1 centered at (-2, 0),
+    /// // or in the ball of radius 1 centered at (2, 0)
+    /// let center_a = [-2.0, 0.0];
+    /// let center_b = [2.0, 0.0];
+    /// let union = Union::new()
+    ///     .add_constraint(Ball2::new(Some(&center_a), 1.0))
+    ///     .add_constraint(Ball2::new(Some(&center_b), 1.0));
+    /// let mut x = [0.5, 0.0];
+    /// union.project(&mut x);
+    /// assert!((x[0] - 1.0).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn new() -> Self {
+        Union {
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Constructs a new instance of a union of sets with a given capacity
+    ///
+    /// # Arguments
+    ///
+    /// - `num_sets`: number of sets; this is used to allocate initial memory
+    ///   (via `Vec::with_capacity`).
+    ///
+    pub fn new_with_capacity(num_sets: usize) -> Self {
+        Union {
+            constraints: Vec::with_capacity(num_sets),
+        }
+    }
+
+    /// Construct a new union of the given sets, or return an error if there
+    /// are no sets, or if their dimensions (see `Constraint::dimension`) are
+    /// known and not equal
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::EmptyData` if `constraints` is empty
+    /// - `ConstructionError::DimensionMismatch` if two of the sets have known,
+    ///   but different, dimensions
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optimization_engine::constraints::*;
+    /// use optimization_engine::ConstructionError;
+    ///
+    /// assert_eq!(Some(ConstructionError::EmptyData), Union::try_new(vec![]).err());
+    /// ```
+    ///
+    pub fn try_new(constraints: Vec<Box<dyn Constraint + 'a>>) -> Result<Self, ConstructionError> {
+        if constraints.is_empty() {
+            return Err(ConstructionError::EmptyData);
+        }
+        let mut dimensions = constraints.iter().filter_map(|c| c.dimension());
+        if let Some(expected) = dimensions.next() {
+            if let Some(found) = dimensions.find(|&found| found != expected) {
+                return Err(ConstructionError::DimensionMismatch { expected, found });
+            }
+        }
+        Ok(Union { constraints })
+    }
+
+    /// Add a set to the union
+    ///
+    /// # Arguments
+    ///
+    /// - `constraint`: set to be added
+    ///
+    /// # Returns
+    ///
+    /// Returns the current updated instance of the provided object
+    ///
+    pub fn add_constraint(mut self, constraint: impl Constraint + 'a) -> Self {
+        self.constraints.push(Box::new(constraint));
+        self
+    }
+}
+
+impl<'a> Constraint for Union<'a> {
+    /// Project onto the union of sets
+    ///
+    /// The given vector `x` is projected on all sets and it is updated with the
+    /// projection that is closest to it.
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the union contains no sets, or if any of the associated
+    /// projections panics.
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(!self.constraints.is_empty(), "the union has no sets");
+        let mut best = x.to_vec();
+        let mut candidate = x.to_vec();
+        let mut best_distance = f64::INFINITY;
+        for c in self.constraints.iter() {
+            candidate.copy_from_slice(x);
+            c.project(&mut candidate);
+            let distance = matrix_operations::norm2_squared_diff(&candidate, x);
+            if distance < best_distance {
+                best_distance = distance;
+                best.copy_from_slice(&candidate);
+            }
+        }
+        x.copy_from_slice(&best);
+    }
+
+    /// A union of two or more sets is treated as nonconvex; a union consisting
+    /// of a single set is convex if and only if that set is convex
+    fn is_convex(&self) -> bool {
+        self.constraints.len() == 1 && self.constraints[0].is_convex()
+    }
+
+    /// The distance from a union of sets is the smallest distance from the sets
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        assert!(!self.constraints.is_empty(), "the union has no sets");
+        self.constraints
+            .iter()
+            .map(|c| c.distance_squared(x))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        self.constraints.iter().any(|c| c.contains(x, tol))
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        self.constraints.iter().find_map(|c| c.dimension())
+    }
+}
@@ -0,0 +1,172 @@
+use super::simplex::simplex_threshold;
+use super::Constraint;
+use crate::ConstructionError;
+use std::borrow::Cow;
+
+#[derive(Clone)]
+/// A weighted $\ell_1$ ball, that is, a set of the form
+///
+/// $$
+/// B_{1, w}^{x_c, r} = \\{x \in \mathbb{R}^n {}:{} \sum_i w_i |x_i - x_{c, i}| \leq r\\},
+/// $$
+///
+/// where $w_i > 0$ are given weights and $x_c$ is the center of the ball (the
+/// origin, if not given).
+///
+/// The projection is computed without allocating memory by soft thresholding,
+/// $x_i \leftarrow x_{c, i} + \mathrm{sgn}(x_i - x_{c, i})\max\\{|x_i - x_{c, i}| - \tau w_i, 0\\}$,
+/// where the threshold $\tau$ is computed as for the projection on the simplex.
+pub struct WeightedBall1<'a> {
+    weights: Cow<'a, [f64]>,
+    center: Option<Cow<'a, [f64]>>,
+    radius: f64,
+}
+
+impl<'a> WeightedBall1<'a> {
+    /// Construct a new weighted $\ell_1$ ball
+    ///
+    /// # Arguments
+    ///
+    /// - `weights`: the weights, $w$
+    /// - `center`: the center of the ball (if `None`, it is the origin)
+    /// - `radius`: the radius of the ball
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `radius` is not positive, if any of the weights is
+    /// not positive, or if `weights` and `center` have different dimensions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use optimization_engine::constraints::{Constraint, WeightedBall1};
+    ///
+    /// // |x[0]| + 2|x[1]| <= 1
+    /// let weights = [1.0, 2.0];
+    /// let ball = WeightedBall1::new(&weights, None, 1.0);
+    /// let mut x = [2.0, 2.0];
+    /// ball.project(&mut x);
+    /// assert!((x[0] + 2.0 * x[1] - 1.0).abs() < 1e-12);
+    /// ```
+    ///
+    pub fn new(weights: &'a [f64], center: Option<&'a [f64]>, radius: f64) -> Self {
+        WeightedBall1::from_cow(Cow::Borrowed(weights), center.map(Cow::Borrowed), radius)
+    }
+
+    /// Construct a new weighted $\ell_1$ ball, or return an error if the data
+    /// is invalid (see `new`)
+    ///
+    /// # Errors
+    ///
+    /// - `ConstructionError::InvalidParameter` if `radius` or any of the weights
+    ///   is not positive
+    /// - `ConstructionError::DimensionMismatch` if `weights` and `center` have
+    ///   different dimensions
+    ///
+    pub fn try_new(
+        weights: &'a [f64],
+        center: Option<&'a [f64]>,
+        radius: f64,
+    ) -> Result<Self, ConstructionError> {
+        if radius.is_nan() || radius <= 0.0 {
+            return Err(ConstructionError::InvalidParameter {
+                name: "radius",
+                value: radius,
+            });
+        }
+        if let Some(&wi) = weights.iter().find(|&&wi| wi.is_nan() || wi <= 0.0) {
+            return Err(ConstructionError::InvalidParameter {
+                name: "weights",
+                value: wi,
+            });
+        }
+        match center {
+            Some(center) if center.len() != weights.len() => {
+                Err(ConstructionError::DimensionMismatch {
+                    expected: weights.len(),
+                    found: center.len(),
+                })
+            }
+            _ => Ok(WeightedBall1::new(weights, center, radius)),
+        }
+    }
+
+    /// Construct a new weighted $\ell_1$ ball which owns its data (see `new`)
+    pub fn new_owned(weights: Vec<f64>, center: Option<Vec<f64>>, radius: f64) -> Self {
+        WeightedBall1::from_cow(Cow::Owned(weights), center.map(Cow::Owned), radius)
+    }
+
+    fn from_cow(weights: Cow<'a, [f64]>, center: Option<Cow<'a, [f64]>>, radius: f64) -> Self {
+        assert!(radius > 0.0, "radius must be positive");
+        assert!(
+            weights.iter().all(|&wi| wi > 0.0),
+            "weights must be positive"
+        );
+        assert!(
+            center.as_ref().map_or(weights.len(), |c| c.len()) == weights.len(),
+            "weights and center have incompatible dimensions"
+        );
+        WeightedBall1 {
+            weights,
+            center,
+            radius,
+        }
+    }
+
+    /// Weighted $\ell_1$ norm of $x$
+    fn weighted_norm1(&self, x: &[f64]) -> f64 {
+        x.iter()
+            .zip(self.weights.iter())
+            .fold(0.0, |sum, (xi, wi)| sum + wi * xi.abs())
+    }
+}
+
+impl<'a> Constraint for WeightedBall1<'a> {
+    /// Project on the weighted $\ell_1$ ball
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the number
+    /// of weights
+    ///
+    fn project(&self, x: &mut [f64]) {
+        assert!(x.len() == self.weights.len(), "x has wrong dimension");
+        if let Some(center) = &self.center {
+            x.iter_mut()
+                .zip(center.iter())
+                .for_each(|(xi, &ci)| *xi -= ci);
+        }
+        if self.weighted_norm1(x) > self.radius {
+            let weights = &self.weights;
+            let tau = simplex_threshold(x.len(), self.radius, |i| x[i].abs(), |i| weights[i]);
+            x.iter_mut()
+                .zip(weights.iter())
+                .for_each(|(xi, wi)| *xi = xi.signum() * f64::max(xi.abs() - tau * wi, 0.0));
+        }
+        if let Some(center) = &self.center {
+            x.iter_mut()
+                .zip(center.iter())
+                .for_each(|(xi, &ci)| *xi += ci);
+        }
+    }
+
+    fn is_convex(&self) -> bool {
+        true
+    }
+
+    fn contains(&self, x: &[f64], tol: f64) -> bool {
+        let weighted_norm1_difference = match &self.center {
+            Some(center) => x
+                .iter()
+                .zip(center.iter())
+                .zip(self.weights.iter())
+                .fold(0.0, |sum, ((xi, ci), wi)| sum + wi * (xi - ci).abs()),
+            None => self.weighted_norm1(x),
+        };
+        weighted_norm1_difference <= self.radius || self.distance(x) <= tol
+    }
+
+    fn dimension(&self) -> Option<usize> {
+        Some(self.weights.len())
+    }
+}
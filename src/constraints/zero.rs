@@ -1,4 +1,4 @@
-use super::Constraint;
+use super::{ActiveConstraint, ActiveSet, Constraint};
 
 #[derive(Clone, Copy, Default)]
 /// Set Zero, $\\{0\\}$
@@ -21,4 +21,22 @@ impl Constraint for Zero {
     fn is_convex(&self) -> bool {
         true
     }
+
+    fn distance_squared(&self, x: &[f64]) -> f64 {
+        crate::matrix_operations::norm2_squared(x)
+    }
+}
+
+impl ActiveSet for Zero {
+    /// All constraints, $x_i = 0$, are active
+    fn active_constraints(&self, x: &[f64], _tol: f64) -> Vec<ActiveConstraint> {
+        (0..x.len()).map(ActiveConstraint::Equality).collect()
+    }
+
+    fn support_function(&self, _y: &[f64]) -> f64 {
+        0.0
+    }
+
+    /// The normal cone is the whole space
+    fn project_on_normal_cone(&self, _x: &[f64], _y: &mut [f64], _tol: f64) {}
 }
@@ -1,9 +1,8 @@
 //! FBS Engine
 //!
 use crate::{
-    constraints,
     core::{fbs::FBSCache, AlgorithmEngine, Problem},
-    matrix_operations, FunctionCallResult, SolverError,
+    matrix_operations, proximal, FunctionCallResult, SolverError,
 };
 
 /// The FBE Engine defines the steps of the FBE algorithm and the termination criterion
@@ -12,7 +11,7 @@ pub struct FBSEngine<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     pub(crate) problem: Problem<'a, GradientType, ConstraintType, CostType>,
     pub(crate) cache: &'a mut FBSCache,
@@ -23,7 +22,7 @@ impl<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     /// Constructor for instances of `FBSEngine`
     ///
@@ -57,7 +56,9 @@ where
     }
 
     fn projection_step(&mut self, u_current: &mut [f64]) {
-        self.problem.constraints.project(u_current);
+        // u_current ← prox_{gamma g}(u_current), which is a projection if g is
+        // the indicator of a set
+        self.problem.constraints.prox(u_current, self.cache.gamma);
     }
 }
 
@@ -66,7 +67,7 @@ impl<'a, GradientType, ConstraintType, CostType> AlgorithmEngine
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult + 'a,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult + 'a,
-    ConstraintType: constraints::Constraint + 'a,
+    ConstraintType: proximal::ProximalOperator + 'a,
 {
     /// Take a forward-backward step and check whether the algorithm should terminate
     ///
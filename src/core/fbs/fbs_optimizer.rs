@@ -1,12 +1,11 @@
 //! FBS Algorithm
 //!
 use crate::{
-    constraints,
     core::{
         fbs::fbs_engine::FBSEngine, fbs::FBSCache, AlgorithmEngine, ExitStatus, Optimizer, Problem,
         SolverStatus,
     },
-    matrix_operations, FunctionCallResult, SolverError,
+    matrix_operations, proximal, FunctionCallResult, SolverError,
 };
 use std::time;
 
@@ -26,7 +25,7 @@ pub struct FBSOptimizer<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     fbs_engine: FBSEngine<'a, GradientType, ConstraintType, CostType>,
     max_iter: usize,
@@ -38,7 +37,7 @@ impl<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     /// Constructs a new instance of `FBSOptimizer`
     ///
@@ -96,7 +95,7 @@ impl<'life, GradientType, ConstraintType, CostType> Optimizer
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult + 'life,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult + 'life,
-    ConstraintType: constraints::Constraint + 'life,
+    ConstraintType: proximal::ProximalOperator + 'life,
 {
     fn solve(&mut self, u: &mut [f64]) -> Result<SolverStatus, SolverError> {
         let now = instant::Instant::now();
@@ -147,3 +147,20 @@ fn t_solve_fbs_many_times() {
         assert!(status.norm_fpr() < tolerance);
     }
 }
+
+#[test]
+fn t_solve_fbs_nonsmooth_norm1() {
+    let norm1 = crate::proximal::NormL1::new(None, 0.5);
+    let problem = Problem::new(&norm1, mocks::my_gradient, mocks::my_cost);
+    let gamma = 0.1;
+    let tolerance = 1e-8;
+
+    let mut fbs_cache = FBSCache::new(NonZeroUsize::new(N_DIM).unwrap(), gamma, tolerance);
+    let mut u = [0.0; N_DIM];
+    let mut optimizer = FBSOptimizer::new(problem, &mut fbs_cache).with_max_iter(10_000);
+
+    let status = optimizer.solve(&mut u).unwrap();
+
+    assert!(status.has_converged());
+    unit_test_utils::assert_nearly_equal_array(&mocks::SOLUTION_L1, &u, 1e-5, 1e-6, "u");
+}
@@ -7,8 +7,9 @@ pub mod fbs;
 pub mod panoc;
 pub mod problem;
 pub mod solver_status;
+pub mod zerofpr;
 
-pub use crate::{constraints, FunctionCallResult, SolverError};
+pub use crate::{constraints, proximal, ConstructionError, FunctionCallResult, SolverError};
 pub use problem::Problem;
 pub use solver_status::SolverStatus;
 
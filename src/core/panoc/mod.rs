@@ -9,5 +9,8 @@ mod panoc_optimizer;
 pub use panoc_cache::PANOCCache;
 pub use panoc_optimizer::PANOCOptimizer;
 
+pub(crate) use panoc_engine::PANOCEngine;
+pub(crate) use panoc_optimizer::{solve, PANOCTypeEngine};
+
 #[cfg(test)]
 mod tests;
@@ -1,3 +1,5 @@
+use crate::ConstructionError;
+
 const DEFAULT_SY_EPSILON: f64 = 1e-10;
 const DEFAULT_CBFGS_EPSILON: f64 = 1e-8;
 const DEFAULT_CBFGS_ALPHA: f64 = 1.0;
@@ -88,6 +90,52 @@ impl PANOCCache {
         }
     }
 
+    /// Construct a new instance of `PANOCCache`, or return an error if the
+    /// given parameters are invalid (see `new`)
+    ///
+    /// ## Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `problem_size` or `lbfgs_memory_size`
+    /// is zero, or if `tolerance` is not positive
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use optimization_engine::panoc::PANOCCache;
+    /// use optimization_engine::ConstructionError;
+    ///
+    /// assert!(PANOCCache::try_new(10, 1e-6, 5).is_ok());
+    /// assert_eq!(
+    ///     Some(ConstructionError::InvalidParameter { name: "tolerance", value: -1.0 }),
+    ///     PANOCCache::try_new(10, -1.0, 5).err()
+    /// );
+    /// ```
+    ///
+    pub fn try_new(
+        problem_size: usize,
+        tolerance: f64,
+        lbfgs_memory_size: usize,
+    ) -> Result<PANOCCache, ConstructionError> {
+        if problem_size == 0 {
+            Err(ConstructionError::InvalidParameter {
+                name: "problem_size",
+                value: 0.0,
+            })
+        } else if lbfgs_memory_size == 0 {
+            Err(ConstructionError::InvalidParameter {
+                name: "lbfgs_memory_size",
+                value: 0.0,
+            })
+        } else if tolerance.is_nan() || tolerance <= 0.0 {
+            Err(ConstructionError::InvalidParameter {
+                name: "tolerance",
+                value: tolerance,
+            })
+        } else {
+            Ok(PANOCCache::new(problem_size, tolerance, lbfgs_memory_size))
+        }
+    }
+
     /// Sets the AKKT-specific tolerance and activates the corresponding
     /// termination criterion
     ///
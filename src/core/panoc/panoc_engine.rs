@@ -1,7 +1,6 @@
 use crate::{
-    constraints,
     core::{panoc::PANOCCache, AlgorithmEngine, Problem},
-    matrix_operations, FunctionCallResult, SolverError,
+    matrix_operations, proximal, FunctionCallResult, SolverError,
 };
 
 /// Mimum estimated Lipschitz constant (initial estimate)
@@ -35,9 +34,9 @@ pub struct PANOCEngine<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
-    problem: Problem<'a, GradientType, ConstraintType, CostType>,
+    pub(crate) problem: Problem<'a, GradientType, ConstraintType, CostType>,
     pub(crate) cache: &'a mut PANOCCache,
 }
 
@@ -46,7 +45,7 @@ impl<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     /// Construct a new Engine for PANOC
     ///
@@ -81,7 +80,7 @@ where
     }
 
     /// Computes the FPR and its norm
-    fn compute_fpr(&mut self, u_current: &[f64]) {
+    pub(crate) fn compute_fpr(&mut self, u_current: &[f64]) {
         // compute the FPR:
         // fpr ← u - u_half_step
         let cache = &mut self.cache;
@@ -96,7 +95,7 @@ where
     }
 
     /// Computes a gradient step; does not compute the gradient
-    fn gradient_step(&mut self, u_current: &[f64]) {
+    pub(crate) fn gradient_step(&mut self, u_current: &[f64]) {
         // take a gradient step:
         // gradient_step ← u_current - gamma * gradient
         let cache = &mut self.cache;
@@ -123,12 +122,14 @@ where
             .for_each(|((grad_step, u), grad)| *grad_step = *u - gamma * *grad);
     }
 
-    /// Computes a projection on `gradient_step`
+    /// Computes a projection (or, generally, the proximal operator) on `gradient_step`
     fn half_step(&mut self) {
         let cache = &mut self.cache;
-        // u_half_step ← projection(gradient_step)
+        // u_half_step ← prox_{gamma g}(gradient_step)
         cache.u_half_step.copy_from_slice(&cache.gradient_step);
-        self.problem.constraints.project(&mut cache.u_half_step);
+        self.problem
+            .constraints
+            .prox(&mut cache.u_half_step, cache.gamma);
     }
 
     /// Computes an LBFGS direction; updates `cache.direction_lbfgs`
@@ -161,7 +162,7 @@ where
     }
 
     /// Updates the estimate of the Lipscthiz constant
-    fn update_lipschitz_constant(&mut self, u_current: &[f64]) -> FunctionCallResult {
+    pub(crate) fn update_lipschitz_constant(&mut self, u_current: &[f64]) -> FunctionCallResult {
         let mut cost_u_half_step = 0.0;
 
         // Compute the cost at the half step
@@ -216,32 +217,42 @@ where
             });
     }
 
-    /// Computes the RHS of the linesearch condition
-    fn compute_rhs_ls(&mut self) {
-        let cache = &mut self.cache;
+    /// Computes the forward-backward envelope at the point at which `cache.cost_value`,
+    /// `cache.gradient_u`, `cache.gradient_step` and `cache.u_half_step` were computed
+    fn fbe(&self) -> f64 {
+        let cache = &self.cache;
 
         // dist squared ← norm(gradient step - u half step)^2
         let dist_squared =
             matrix_operations::norm2_squared_diff(&cache.gradient_step, &cache.u_half_step);
 
-        // rhs_ls ← f - (gamma/2) * norm(gradf)^2
-        //            + 0.5 * dist squared / gamma
-        //            - sigma * norm_gamma_fpr^2
-        let fbe = cache.cost_value
-            - 0.5 * cache.gamma * matrix_operations::norm2_squared(&cache.gradient_u)
-            + 0.5 * dist_squared / cache.gamma;
-        let sigma_fpr_sq = cache.sigma * cache.norm_gamma_fpr.powi(2);
-        cache.rhs_ls = fbe - sigma_fpr_sq;
+        // fbe ← f - (gamma/2) * norm(gradf)^2
+        //         + 0.5 * dist squared / gamma
+        //         + g(u_half_step)
+        cache.cost_value - 0.5 * cache.gamma * matrix_operations::norm2_squared(&cache.gradient_u)
+            + 0.5 * dist_squared / cache.gamma
+            + self.problem.constraints.value(&cache.u_half_step)
+    }
+
+    /// Computes the RHS of the linesearch condition
+    pub(crate) fn compute_rhs_ls(&mut self) {
+        // rhs_ls ← fbe - sigma * norm_gamma_fpr^2
+        let sigma_fpr_sq = self.cache.sigma * self.cache.norm_gamma_fpr.powi(2);
+        self.cache.rhs_ls = self.fbe() - sigma_fpr_sq;
     }
 
     /// Computes the left hand side of the line search condition and compares it with the RHS;
     /// returns `true` if and only if lhs > rhs (when the line search should continue)
     fn line_search_condition(&mut self, u: &[f64]) -> Result<bool, SolverError> {
-        let gamma = self.cache.gamma;
-
         // u_plus ← u - (1-tau)*gamma_fpr + tau*direction
         self.compute_u_plus(u);
+        self.line_search_condition_at_u_plus()
+    }
 
+    /// Computes the left hand side of the line search condition (that is, the FBE)
+    /// at `cache.u_plus` and compares it with the RHS; returns `true` if and only if
+    /// lhs > rhs (when the line search should continue)
+    pub(crate) fn line_search_condition_at_u_plus(&mut self) -> Result<bool, SolverError> {
         // Note: Here `cache.cost_value` and `cache.gradient_u` are overwritten
         // with the values of the cost and its gradient at the next (candidate)
         // point `u_plus`
@@ -249,18 +260,10 @@ where
         (self.problem.gradf)(&self.cache.u_plus, &mut self.cache.gradient_u)?;
 
         self.gradient_step_uplus(); // gradient_step ← u_plus - gamma * gradient_u
-        self.half_step(); // u_half_step ← project(gradient_step)
-
-        // Compute: dist_squared ← norm(gradient_step - u_half_step)^2
-        let dist_squared = matrix_operations::norm2_squared_diff(
-            &self.cache.gradient_step,
-            &self.cache.u_half_step,
-        );
+        self.half_step(); // u_half_step ← prox(gradient_step)
 
-        // Update the LHS of the line search condition
-        self.cache.lhs_ls = self.cache.cost_value
-            - 0.5 * gamma * matrix_operations::norm2_squared(&self.cache.gradient_u)
-            + 0.5 * dist_squared / self.cache.gamma;
+        // Update the LHS of the line search condition (FBE at u_plus)
+        self.cache.lhs_ls = self.fbe();
 
         Ok(self.cache.lhs_ls > self.cache.rhs_ls)
     }
@@ -303,7 +306,7 @@ impl<'a, GradientType, ConstraintType, CostType> AlgorithmEngine
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     /// PANOC step
     ///
@@ -552,4 +555,37 @@ mod tests {
             "rhs_ls is wrong",
         );
     }
+
+    #[test]
+    fn t_compute_rhs_ls_nonsmooth() {
+        let norm1 = crate::proximal::NormL1::new(None, 2.0);
+        let problem = Problem::new(&norm1, mocks::void_gradient, mocks::void_cost);
+        let mut panoc_cache = PANOCCache::new(2, 1e-6, 5);
+        let mut panoc_engine = PANOCEngine::new(problem, &mut panoc_cache);
+
+        panoc_engine
+            .cache
+            .gradient_step
+            .copy_from_slice(&[-0.5, -0.4]);
+        panoc_engine
+            .cache
+            .u_half_step
+            .copy_from_slice(&[15.0, -14.8]);
+        panoc_engine.cache.cost_value = 24.0;
+        panoc_engine.cache.gamma = 2.34;
+        panoc_engine.cache.gradient_u.copy_from_slice(&[2.4, -9.7]);
+        panoc_engine.cache.sigma = 0.066;
+        panoc_engine.cache.norm_gamma_fpr = 2.5974;
+
+        panoc_engine.compute_rhs_ls();
+
+        // same as in `t_compute_rhs_ls`, plus g(u_half_step) = 2 * 29.8
+        unit_test_utils::assert_nearly_equal(
+            2.373_394_267_002_398 + 59.6,
+            panoc_engine.cache.rhs_ls,
+            1e-10,
+            1e-8,
+            "rhs_ls is wrong",
+        );
+    }
 }
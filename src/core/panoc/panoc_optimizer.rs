@@ -1,12 +1,11 @@
 //! PANOC optimizer
 //!
 use crate::{
-    constraints,
     core::{
         panoc::panoc_engine::PANOCEngine, panoc::PANOCCache, AlgorithmEngine, ExitStatus,
         Optimizer, Problem, SolverStatus,
     },
-    matrix_operations, FunctionCallResult, SolverError,
+    matrix_operations, proximal, FunctionCallResult, SolverError,
 };
 use std::time;
 
@@ -19,7 +18,7 @@ pub struct PANOCOptimizer<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     panoc_engine: PANOCEngine<'a, GradientType, ConstraintType, CostType>,
     max_iter: usize,
@@ -31,7 +30,7 @@ impl<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     /// Constructor of `PANOCOptimizer`
     ///
@@ -56,7 +55,7 @@ where
 
     /// Sets the tolerance on the norm of the fixed-point residual
     ///
-    /// The algorithm will exit if the form of gamma*FPR drops below
+    /// The algorithm will exit if the norm of gamma*FPR drops below
     /// this tolerance
     ///
     /// ## Panics
@@ -109,8 +108,8 @@ where
     }
 
     /// Sets the maximum solution time, useful in real-time applications
-    pub fn with_max_duration(mut self, max_duation: time::Duration) -> Self {
-        self.max_duration = Some(max_duation);
+    pub fn with_max_duration(mut self, max_duration: time::Duration) -> Self {
+        self.max_duration = Some(max_duration);
         self
     }
 }
@@ -120,65 +119,97 @@ impl<'life, GradientType, ConstraintType, CostType> Optimizer
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult + 'life,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint + 'life,
+    ConstraintType: proximal::ProximalOperator + 'life,
 {
     fn solve(&mut self, u: &mut [f64]) -> Result<SolverStatus, SolverError> {
-        let now = instant::Instant::now();
+        solve(&mut self.panoc_engine, u, self.max_iter, self.max_duration)
+    }
+}
 
-        /*
-         * Initialise [call panoc_engine.init()]
-         * and check whether it returns Ok(())
-         */
-        self.panoc_engine.init(u)?;
-
-        /* Main loop */
-        let mut num_iter: usize = 0;
-        let mut continue_num_iters = true;
-        let mut continue_runtime = true;
-
-        let mut step_flag = self.panoc_engine.step(u)?;
-        if let Some(dur) = self.max_duration {
-            while step_flag && continue_num_iters && continue_runtime {
-                num_iter += 1;
-                continue_num_iters = num_iter < self.max_iter;
-                continue_runtime = now.elapsed() <= dur;
-                step_flag = self.panoc_engine.step(u)?;
-            }
-        } else {
-            while step_flag && continue_num_iters {
-                num_iter += 1;
-                continue_num_iters = num_iter < self.max_iter;
-                step_flag = self.panoc_engine.step(u)?;
-            }
-        }
+/// Engine of an algorithm of the PANOC family (e.g., PANOC or ZeroFPR), which
+/// stores the quantities it computes at the current iterate in a `PANOCCache`
+pub(crate) trait PANOCTypeEngine: AlgorithmEngine {
+    /// The cache of the engine
+    fn panoc_cache(&self) -> &PANOCCache;
+}
 
-        // check for possible NaN/inf
-        if !matrix_operations::is_finite(u) {
-            return Err(SolverError::NotFiniteComputation);
-        }
+impl<'a, GradientType, ConstraintType, CostType> PANOCTypeEngine
+    for PANOCEngine<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    fn panoc_cache(&self) -> &PANOCCache {
+        self.cache
+    }
+}
 
-        // exit status
-        let exit_status = if !continue_num_iters {
-            ExitStatus::NotConvergedIterations
-        } else if !continue_runtime {
-            ExitStatus::NotConvergedOutOfTime
-        } else {
-            ExitStatus::Converged
-        };
+/// Runs an algorithm of the PANOC family starting from `u` until it converges or
+/// the maximum number of iterations, `max_iter`, or the maximum duration, if any,
+/// is reached; on exit, `u` is the last forward-backward point
+pub(crate) fn solve<EngineType: PANOCTypeEngine>(
+    engine: &mut EngineType,
+    u: &mut [f64],
+    max_iter: usize,
+    max_duration: Option<time::Duration>,
+) -> Result<SolverStatus, SolverError> {
+    let now = instant::Instant::now();
+
+    /*
+     * Initialise [call engine.init()]
+     * and check whether it returns Ok(())
+     */
+    engine.init(u)?;
+
+    /* Main loop */
+    let mut num_iter: usize = 0;
+    let mut continue_num_iters = true;
+    let mut continue_runtime = true;
+
+    let mut step_flag = engine.step(u)?;
+    if let Some(dur) = max_duration {
+        while step_flag && continue_num_iters && continue_runtime {
+            num_iter += 1;
+            continue_num_iters = num_iter < max_iter;
+            continue_runtime = now.elapsed() <= dur;
+            step_flag = engine.step(u)?;
+        }
+    } else {
+        while step_flag && continue_num_iters {
+            num_iter += 1;
+            continue_num_iters = num_iter < max_iter;
+            step_flag = engine.step(u)?;
+        }
+    }
 
-        // copy u_half_step into u (the algorithm should return u_bar,
-        // because it's always feasible, while u may violate the constraints)
-        u.copy_from_slice(&self.panoc_engine.cache.u_half_step);
-
-        // export solution status (exit status, num iterations and more)
-        Ok(SolverStatus::new(
-            exit_status,
-            num_iter,
-            now.elapsed(),
-            self.panoc_engine.cache.norm_gamma_fpr,
-            self.panoc_engine.cache.cost_value,
-        ))
+    // check for possible NaN/inf
+    if !matrix_operations::is_finite(u) {
+        return Err(SolverError::NotFiniteComputation);
     }
+
+    // exit status
+    let exit_status = if !continue_num_iters {
+        ExitStatus::NotConvergedIterations
+    } else if !continue_runtime {
+        ExitStatus::NotConvergedOutOfTime
+    } else {
+        ExitStatus::Converged
+    };
+
+    // copy u_half_step into u (the algorithm should return u_bar,
+    // because it's always feasible, while u may violate the constraints)
+    let cache = engine.panoc_cache();
+    u.copy_from_slice(&cache.u_half_step);
+
+    // export solution status (exit status, num iterations and more)
+    Ok(SolverStatus::new(
+        exit_status,
+        num_iter,
+        now.elapsed(),
+        cache.norm_gamma_fpr,
+        cache.cost_value,
+    ))
 }
 
 /* --------------------------------------------------------------------------------------------- */
@@ -216,3 +216,38 @@ fn t_zero_gamma_huber() {
     println!("iters = {}", panoc_cache.iteration);
     assert!(panoc_cache.norm_gamma_fpr <= tolerance);
 }
+
+#[test]
+fn t_panoc_nonsmooth_norm1() {
+    let tolerance = 1e-10;
+    let norm1 = crate::proximal::NormL1::new(None, 0.5);
+    let problem = Problem::new(&norm1, mocks::my_gradient, mocks::my_cost);
+    let mut panoc_cache = PANOCCache::new(N_DIM, tolerance, 5);
+    let mut panoc = PANOCOptimizer::new(problem, &mut panoc_cache).with_max_iter(200);
+    let mut u = [5.0, -3.0];
+    let status = panoc.solve(&mut u).unwrap();
+    assert!(status.has_converged());
+    assert!(status.norm_fpr() < tolerance);
+    unit_test_utils::assert_nearly_equal_array(&mocks::SOLUTION_L1, &u, 1e-6, 1e-8, "u");
+}
+
+#[test]
+fn t_panoc_cache_try_new() {
+    assert!(PANOCCache::try_new(N_DIM, 1e-6, 5).is_ok());
+    assert_eq!(
+        Some(ConstructionError::InvalidParameter {
+            name: "problem_size",
+            value: 0.0
+        }),
+        PANOCCache::try_new(0, 1e-6, 5).err()
+    );
+    assert_eq!(
+        Some(ConstructionError::InvalidParameter {
+            name: "lbfgs_memory_size",
+            value: 0.0
+        }),
+        PANOCCache::try_new(N_DIM, 1e-6, 0).err()
+    );
+    assert!(PANOCCache::try_new(N_DIM, 0.0, 5).is_err());
+    assert!(PANOCCache::try_new(N_DIM, f64::NAN, 5).is_err());
+}
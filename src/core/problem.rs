@@ -1,12 +1,13 @@
 //! An optimization problem
 //!
 //! This struct defines an optimization problem in terms of its cost function
-//! (cost function and its gradient) and constraints
+//! (cost function and its gradient) and constraints (or, more generally, a
+//! nonsmooth term of the cost function with an easy-to-compute proximal operator)
 //!
 //! Cost functions are user defined. They can either be defined in Rust or in
 //! C (and then invoked from Rust via an interface such as icasadi).
 //!
-use crate::{constraints, FunctionCallResult};
+use crate::{proximal, FunctionCallResult};
 
 /// Definition of an optimisation problem
 ///
@@ -14,14 +15,16 @@ use crate::{constraints, FunctionCallResult};
 /// - the gradient of the cost function
 /// - the cost function
 /// - the set of constraints, which is described by implementations of
-///   [Constraint](../../panoc_rs/constraints/trait.Constraint.html)
+///   [Constraint](../constraints/trait.Constraint.html), or a nonsmooth
+///   term of the cost function, $g$, which is described by implementations of
+///   [ProximalOperator](../proximal/trait.ProximalOperator.html)
 pub struct Problem<'a, GradientType, ConstraintType, CostType>
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
-    /// constraints
+    /// constraints (or nonsmooth term)
     pub(crate) constraints: &'a ConstraintType,
     /// gradient of the cost
     pub(crate) gradf: GradientType,
@@ -33,13 +36,13 @@ impl<'a, GradientType, ConstraintType, CostType> Problem<'a, GradientType, Const
 where
     GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
     CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
-    ConstraintType: constraints::Constraint,
+    ConstraintType: proximal::ProximalOperator,
 {
     /// Construct a new instance of an optimisation problem
     ///
     /// ## Arguments
     ///
-    /// - `constraints` constraints
+    /// - `constraints` constraints, or any implementation of `ProximalOperator`
     /// - `cost_gradient` gradient of the cost function
     /// - `cost` cost function
     ///
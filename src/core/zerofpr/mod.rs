@@ -0,0 +1,20 @@
+//! ZeroFPR algorithm
+//!
+//! ZeroFPR is a variant of PANOC in which the quasi-Newton (L-BFGS) directions
+//! are applied at the forward-backward point rather than at the current iterate.
+//!
+//! See: A. Themelis, L. Stella and P. Patrinos. Forward-backward envelope for the
+//! sum of two nonconvex functions: Further properties and nonmonotone line-search
+//! algorithms. <em>SIAM Journal on Optimization</em> 28(3), pp. 2274-2303, 2018.
+
+#![deny(missing_docs)]
+
+mod zerofpr_cache;
+mod zerofpr_engine;
+mod zerofpr_optimizer;
+
+pub use zerofpr_cache::ZeroFPRCache;
+pub use zerofpr_optimizer::ZeroFPROptimizer;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,203 @@
+use crate::constraints::Constraint;
+use crate::core::panoc::*;
+use crate::core::zerofpr::zerofpr_engine::ZeroFPREngine;
+use crate::core::zerofpr::*;
+use crate::core::*;
+use crate::{mocks, FunctionCallResult};
+
+const N_DIM: usize = 2;
+
+#[test]
+fn t_zerofpr_init() {
+    let radius = 0.2;
+    let ball = constraints::Ball2::new(None, radius);
+    let problem = Problem::new(&ball, mocks::my_gradient, mocks::my_cost);
+    let mut zerofpr_cache = ZeroFPRCache::new(N_DIM, 1e-6, 5);
+    let mut zerofpr_engine = ZeroFPREngine::new(problem, &mut zerofpr_cache);
+    let mut u = [0.75, -1.4];
+    zerofpr_engine.init(&mut u).unwrap();
+    assert!(2.549_509_967_743_775 > zerofpr_engine.panoc_engine.cache.lipschitz_constant);
+    assert!(
+        0.372_620_625_931_781 < zerofpr_engine.panoc_engine.cache.gamma,
+        "gamma"
+    );
+    unit_test_utils::assert_nearly_equal(
+        6.34125,
+        zerofpr_engine.panoc_engine.cache.cost_value,
+        1e-4,
+        1e-10,
+        "cost value",
+    );
+    unit_test_utils::assert_nearly_equal_array(
+        &[0.35, -3.05],
+        &zerofpr_engine.panoc_engine.cache.gradient_u,
+        1e-4,
+        1e-10,
+        "gradient at u",
+    );
+}
+
+#[test]
+fn t_test_zerofpr_basic() {
+    let bounds = constraints::Ball2::new(None, 0.2);
+    let problem = Problem::new(&bounds, mocks::my_gradient, mocks::my_cost);
+    let tolerance = 1e-9;
+    let mut zerofpr_cache = ZeroFPRCache::new(N_DIM, tolerance, 5);
+    let mut zerofpr_engine = ZeroFPREngine::new(problem, &mut zerofpr_cache);
+
+    let mut u = [0.0, 0.0];
+    zerofpr_engine.init(&mut u).unwrap();
+    let mut i = 0;
+    while zerofpr_engine.step(&mut u) == Ok(true) && i < 100 {
+        i += 1;
+    }
+    assert!(zerofpr_engine.panoc_engine.cache.norm_gamma_fpr <= tolerance);
+    unit_test_utils::assert_nearly_equal_array(&u, &mocks::SOLUTION_A, 1e-6, 1e-8, "");
+}
+
+#[test]
+fn t_test_zerofpr_hard() {
+    let radius: f64 = 0.05;
+    let bounds = constraints::Ball2::new(None, radius);
+    let problem = Problem::new(
+        &bounds,
+        mocks::hard_quadratic_gradient,
+        mocks::hard_quadratic_cost,
+    );
+    let n: usize = 3;
+    let lbfgs_memory: usize = 10;
+    let tolerance_fpr: f64 = 1e-12;
+    let mut zerofpr_cache = ZeroFPRCache::new(n, tolerance_fpr, lbfgs_memory);
+    let mut zerofpr_engine = ZeroFPREngine::new(problem, &mut zerofpr_cache);
+
+    let mut u = [-20., 10., 0.2];
+    zerofpr_engine.init(&mut u).unwrap();
+    let mut i = 1;
+    while zerofpr_engine.step(&mut u) == Ok(true) && i < 100 {
+        i += 1;
+    }
+    assert!(zerofpr_engine.panoc_engine.cache.norm_gamma_fpr <= tolerance_fpr);
+    unit_test_utils::assert_nearly_equal_array(&u, &mocks::SOLUTION_HARD, 1e-6, 1e-8, "");
+}
+
+#[test]
+fn t_zerofpr_nonsmooth_norm1() {
+    let tolerance = 1e-10;
+    let norm1 = crate::proximal::NormL1::new(None, 0.5);
+    let problem = Problem::new(&norm1, mocks::my_gradient, mocks::my_cost);
+    let mut zerofpr_cache = ZeroFPRCache::new(N_DIM, tolerance, 5);
+    let mut zerofpr = ZeroFPROptimizer::new(problem, &mut zerofpr_cache).with_max_iter(200);
+    let mut u = [5.0, -3.0];
+    let status = zerofpr.solve(&mut u).unwrap();
+    assert!(status.has_converged());
+    assert!(status.norm_fpr() < tolerance);
+    unit_test_utils::assert_nearly_equal_array(&mocks::SOLUTION_L1, &u, 1e-6, 1e-8, "u");
+}
+
+/// Forward-backward operator, $T_\gamma(u) = \Pi_C(u - \gamma\nabla f(u))$, of `mocks::my_cost`
+fn forward_backward_step(ball: &constraints::Ball2, gamma: f64, u: &[f64]) -> [f64; N_DIM] {
+    let mut grad = [0.0; N_DIM];
+    mocks::my_gradient(u, &mut grad).unwrap();
+    let mut u_fb = [u[0] - gamma * grad[0], u[1] - gamma * grad[1]];
+    ball.project(&mut u_fb);
+    u_fb
+}
+
+#[test]
+fn t_zerofpr_first_step_versus_panoc() {
+    // In its first iteration, PANOC performs a forward-backward step, whereas ZeroFPR
+    // (with an empty L-BFGS buffer and tau = 1) performs a forward-backward step at the
+    // forward-backward point, that is, the first iterates are T(u) and T(T(u))
+    let ball = constraints::Ball2::new(None, 0.2);
+    let u_init = [0.75, -1.4];
+
+    let mut panoc_cache = PANOCCache::new(N_DIM, 1e-12, 5);
+    let problem = Problem::new(&ball, mocks::my_gradient, mocks::my_cost);
+    let mut panoc_engine = PANOCEngine::new(problem, &mut panoc_cache);
+    let mut u_panoc = u_init;
+    panoc_engine.init(&mut u_panoc).unwrap();
+    assert_eq!(Ok(true), panoc_engine.step(&mut u_panoc));
+    let gamma = panoc_engine.cache.gamma;
+
+    let mut zerofpr_cache = ZeroFPRCache::new(N_DIM, 1e-12, 5);
+    let problem = Problem::new(&ball, mocks::my_gradient, mocks::my_cost);
+    let mut zerofpr_engine = ZeroFPREngine::new(problem, &mut zerofpr_cache);
+    let mut u_zerofpr = u_init;
+    zerofpr_engine.init(&mut u_zerofpr).unwrap();
+    assert_eq!(Ok(true), zerofpr_engine.step(&mut u_zerofpr));
+    assert_eq!(1.0, zerofpr_engine.panoc_engine.cache.tau);
+    assert_eq!(gamma, zerofpr_engine.panoc_engine.cache.gamma);
+
+    // Note: the gradient at the initial point is computed by the Lipschitz
+    // estimator, hence the tolerance in the comparisons with T(u)
+    let u_fb = forward_backward_step(&ball, gamma, &u_init);
+    let u_bar = &zerofpr_engine.u_bar.u;
+    assert!(crate::matrix_operations::norm_inf_diff(&u_fb, &u_panoc) < 1e-6);
+    assert!(crate::matrix_operations::norm_inf_diff(&u_fb, u_bar) < 1e-6);
+    assert!(crate::matrix_operations::norm_inf_diff(&u_panoc, &u_zerofpr) > 1e-3);
+    let u_fb_fb = forward_backward_step(&ball, gamma, u_bar);
+    assert!(crate::matrix_operations::norm_inf_diff(&u_fb_fb, &u_zerofpr) < 1e-12);
+}
+
+#[test]
+fn t_zerofpr_versus_panoc() {
+    let tolerance = 1e-8;
+    let (a_param, b_param) = (1.0, 100.0);
+    let cost_gradient = |u: &[f64], grad: &mut [f64]| -> FunctionCallResult {
+        mocks::rosenbrock_grad(a_param, b_param, u, grad);
+        Ok(())
+    };
+    let cost_function = |u: &[f64], c: &mut f64| -> FunctionCallResult {
+        *c = mocks::rosenbrock_cost(a_param, b_param, u);
+        Ok(())
+    };
+    let bounds = constraints::Ball2::new(None, 0.8);
+
+    let mut u_zerofpr = [-1.5, 0.9];
+    let mut zerofpr_cache = ZeroFPRCache::new(N_DIM, tolerance, 5);
+    let problem = Problem::new(&bounds, cost_gradient, cost_function);
+    let status_zerofpr = ZeroFPROptimizer::new(problem, &mut zerofpr_cache)
+        .with_max_iter(500)
+        .solve(&mut u_zerofpr)
+        .unwrap();
+
+    let mut u_panoc = [-1.5, 0.9];
+    let mut panoc_cache = PANOCCache::new(N_DIM, tolerance, 5);
+    let problem = Problem::new(&bounds, cost_gradient, cost_function);
+    let status_panoc = PANOCOptimizer::new(problem, &mut panoc_cache)
+        .with_max_iter(500)
+        .solve(&mut u_panoc)
+        .unwrap();
+
+    assert!(status_zerofpr.has_converged());
+    assert!(status_panoc.has_converged());
+    unit_test_utils::assert_nearly_equal_array(&u_panoc, &u_zerofpr, 1e-5, 1e-7, "u");
+    unit_test_utils::assert_nearly_equal(
+        status_panoc.cost_value(),
+        status_zerofpr.cost_value(),
+        1e-6,
+        1e-8,
+        "cost",
+    );
+}
+
+#[test]
+fn t_zerofpr_cache_try_new() {
+    assert!(ZeroFPRCache::try_new(N_DIM, 1e-6, 5).is_ok());
+    assert_eq!(
+        Some(ConstructionError::InvalidParameter {
+            name: "problem_size",
+            value: 0.0
+        }),
+        ZeroFPRCache::try_new(0, 1e-6, 5).err()
+    );
+    assert_eq!(
+        Some(ConstructionError::InvalidParameter {
+            name: "lbfgs_memory_size",
+            value: 0.0
+        }),
+        ZeroFPRCache::try_new(N_DIM, 1e-6, 0).err()
+    );
+    assert!(ZeroFPRCache::try_new(N_DIM, 0.0, 5).is_err());
+    assert!(ZeroFPRCache::try_new(N_DIM, f64::NAN, 5).is_err());
+}
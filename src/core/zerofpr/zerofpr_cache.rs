@@ -0,0 +1,166 @@
+use crate::{core::panoc::PANOCCache, ConstructionError};
+
+/// Cache for ZeroFPR
+///
+/// This struct carries all the information needed at every step of the algorithm.
+///
+/// An instance of `ZeroFPRCache` needs to be allocated once and a (mutable) reference to it
+/// should be passed to instances of [ZeroFPROptimizer](struct.ZeroFPROptimizer.html); the
+/// same cache can be reused in multiple solves (e.g., in model predictive control).
+///
+/// In addition to a [PANOCCache](../panoc/struct.PANOCCache.html), which stores the
+/// quantities that are needed at the current iterate, $u$, ZeroFPR stores the
+/// forward-backward point, $\bar{u} = T_\gamma(u)$, together with the cost,
+/// the gradient and the forward-backward step at $\bar{u}$, which are needed to compute
+/// the quasi-Newton direction and are reused if the line search fails.
+///
+#[derive(Debug)]
+pub struct ZeroFPRCache {
+    pub(crate) panoc_cache: PANOCCache,
+    pub(crate) u_bar: ForwardBackwardPoint,
+}
+
+/// Forward-backward point, $\bar{u} = T_\gamma(u)$, and the quantities that
+/// ZeroFPR computes at $\bar{u}$
+#[derive(Debug)]
+pub(crate) struct ForwardBackwardPoint {
+    /// Forward-backward point, $\bar{u}$
+    pub(crate) u: Vec<f64>,
+    /// Gradient of the cost at $\bar{u}$
+    pub(crate) gradient: Vec<f64>,
+    /// Forward-backward step at $\bar{u}$, $T_\gamma(\bar{u})$
+    pub(crate) half_step: Vec<f64>,
+    /// Fixed-point residual at $\bar{u}$, $\bar{u} - T_\gamma(\bar{u})$
+    pub(crate) gamma_fpr: Vec<f64>,
+    /// Cost at $\bar{u}$
+    pub(crate) cost: f64,
+}
+
+impl ForwardBackwardPoint {
+    fn new(problem_size: usize) -> ForwardBackwardPoint {
+        ForwardBackwardPoint {
+            u: vec![0.0; problem_size],
+            gradient: vec![0.0; problem_size],
+            half_step: vec![0.0; problem_size],
+            gamma_fpr: vec![0.0; problem_size],
+            cost: 0.0,
+        }
+    }
+}
+
+impl ZeroFPRCache {
+    /// Construct a new instance of `ZeroFPRCache`
+    ///
+    /// ## Arguments
+    ///
+    /// - `problem_size` dimension of the decision variables of the optimization problem
+    /// - `tolerance` specified tolerance
+    /// - `lbfgs_memory_size` memory of the LBFGS buffer
+    ///
+    /// ## Panics
+    ///
+    /// The method will panic if
+    ///
+    /// - the specified `tolerance` is not positive
+    /// - memory allocation fails (memory capacity overflow)
+    ///
+    /// ## Memory allocation
+    ///
+    /// This constructor allocated memory using `vec!`.
+    ///
+    /// It allocates a [PANOCCache](../panoc/struct.PANOCCache.html) and another
+    /// `4*problem_size + 1` floats (`f64`)
+    ///
+    pub fn new(problem_size: usize, tolerance: f64, lbfgs_memory_size: usize) -> ZeroFPRCache {
+        ZeroFPRCache {
+            panoc_cache: PANOCCache::new(problem_size, tolerance, lbfgs_memory_size),
+            u_bar: ForwardBackwardPoint::new(problem_size),
+        }
+    }
+
+    /// Construct a new instance of `ZeroFPRCache`, or return an error if the
+    /// given parameters are invalid (see `new`)
+    ///
+    /// ## Errors
+    ///
+    /// `ConstructionError::InvalidParameter` if `problem_size` or `lbfgs_memory_size`
+    /// is zero, or if `tolerance` is not positive
+    ///
+    pub fn try_new(
+        problem_size: usize,
+        tolerance: f64,
+        lbfgs_memory_size: usize,
+    ) -> Result<ZeroFPRCache, ConstructionError> {
+        Ok(ZeroFPRCache {
+            panoc_cache: PANOCCache::try_new(problem_size, tolerance, lbfgs_memory_size)?,
+            u_bar: ForwardBackwardPoint::new(problem_size),
+        })
+    }
+
+    /// Sets the AKKT-specific tolerance and activates the corresponding
+    /// termination criterion
+    ///
+    /// ## Arguments
+    ///
+    /// - `akkt_tolerance`: Tolerance for the AKKT-specific termination condition
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if `akkt_tolerance` is nonpositive
+    ///
+    pub fn set_akkt_tolerance(&mut self, akkt_tolerance: f64) {
+        self.panoc_cache.set_akkt_tolerance(akkt_tolerance);
+    }
+
+    /// Copies the value of the current cost gradient to `gradient_u_previous`,
+    /// which stores the previous gradient vector
+    ///
+    pub fn cache_previous_gradient(&mut self) {
+        self.panoc_cache.cache_previous_gradient();
+    }
+
+    /// Returns `true` iff all termination conditions are satisfied
+    ///
+    /// It checks whether:
+    ///  - the FPR condition, `gamma*||fpr|| < epsilon` ,
+    ///  - (if activated) the AKKT condition `||gamma*fpr + (df - df_prev)|| < eps_akkt`
+    ///
+    /// are satisfied.
+    pub fn exit_condition(&self) -> bool {
+        self.panoc_cache.exit_condition()
+    }
+
+    /// Resets the cache to its initial virgin state.
+    ///
+    /// In particular, it resets the underlying `PANOCCache` (see
+    /// [PANOCCache::reset](../panoc/struct.PANOCCache.html#method.reset))
+    /// and sets the cost at the forward-backward point to 0.0
+    pub fn reset(&mut self) {
+        self.panoc_cache.reset();
+        self.u_bar.cost = 0.0;
+    }
+
+    /// Sets the CBFGS parameters `alpha` and `epsilon`
+    ///
+    /// Read more in: D.-H. Li and M. Fukushima, “On the global convergence of the BFGS
+    /// method for nonconvex unconstrained optimization problems,” vol. 11,
+    /// no. 4, pp. 1054–1064, jan 2001.
+    ///
+    /// ## Arguments
+    ///
+    /// - alpha
+    /// - epsilon
+    /// - sy_epsilon
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if alpha or epsilon are nonpositive and if sy_epsilon
+    /// is negative.
+    ///
+    pub fn with_cbfgs_parameters(mut self, alpha: f64, epsilon: f64, sy_epsilon: f64) -> Self {
+        self.panoc_cache = self
+            .panoc_cache
+            .with_cbfgs_parameters(alpha, epsilon, sy_epsilon);
+        self
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    core::{
+        panoc::{PANOCCache, PANOCEngine, PANOCTypeEngine},
+        zerofpr::{zerofpr_cache::ForwardBackwardPoint, ZeroFPRCache},
+        AlgorithmEngine, Problem,
+    },
+    proximal, FunctionCallResult, SolverError,
+};
+
+/// Maximum number of linesearch iterations
+const MAX_LINESEARCH_ITERATIONS: u32 = 10;
+
+/// Engine for the ZeroFPR algorithm
+///
+/// ZeroFPR shares the estimation of the Lipschitz constant, the forward-backward
+/// steps and the forward-backward envelope with PANOC, so the engine wraps a
+/// `PANOCEngine` and only implements the steps at the forward-backward point
+pub struct ZeroFPREngine<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    pub(crate) panoc_engine: PANOCEngine<'a, GradientType, ConstraintType, CostType>,
+    pub(crate) u_bar: &'a mut ForwardBackwardPoint,
+}
+
+impl<'a, GradientType, ConstraintType, CostType>
+    ZeroFPREngine<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    /// Construct a new Engine for ZeroFPR
+    ///
+    /// The engine owns a problem specification borrows a mutable reference
+    /// to a `ZeroFPRCache` object which is created externally once
+    ///
+    /// ## Arguments
+    ///
+    /// - `problem` problem specification (instance of [Problem](../struct.Problem.html))
+    /// - `cache` an instance of [ZeroFPRCache](struct.ZeroFPRCache.html)
+    ///
+    ///
+    pub fn new(
+        problem: Problem<'a, GradientType, ConstraintType, CostType>,
+        cache: &'a mut ZeroFPRCache,
+    ) -> ZeroFPREngine<'a, GradientType, ConstraintType, CostType> {
+        let ZeroFPRCache { panoc_cache, u_bar } = cache;
+        ZeroFPREngine {
+            panoc_engine: PANOCEngine::new(problem, panoc_cache),
+            u_bar,
+        }
+    }
+
+    /// Computes the forward-backward step at the forward-backward point,
+    /// $\bar{u} = T_\gamma(u)$, and the corresponding fixed-point residual
+    fn forward_backward_step_u_bar(&mut self) -> FunctionCallResult {
+        let problem = &self.panoc_engine.problem;
+        let cache = &self.panoc_engine.cache;
+        let u_bar = &mut self.u_bar;
+        let gamma = cache.gamma;
+
+        // u_bar ← u_half_step
+        u_bar.u.copy_from_slice(&cache.u_half_step);
+        (problem.cost)(&u_bar.u, &mut u_bar.cost)?;
+        (problem.gradf)(&u_bar.u, &mut u_bar.gradient)?;
+
+        // u_bar_half_step ← prox_{gamma g}(u_bar - gamma * gradient at u_bar)
+        u_bar
+            .half_step
+            .iter_mut()
+            .zip(u_bar.u.iter())
+            .zip(u_bar.gradient.iter())
+            .for_each(|((half_step, u), grad)| *half_step = *u - gamma * *grad);
+        problem.constraints.prox(&mut u_bar.half_step, gamma);
+
+        // fpr at u_bar ← u_bar - u_bar_half_step
+        u_bar
+            .gamma_fpr
+            .iter_mut()
+            .zip(u_bar.u.iter())
+            .zip(u_bar.half_step.iter())
+            .for_each(|((fpr, u), uhalf)| *fpr = u - uhalf);
+
+        Ok(())
+    }
+
+    /// Computes an LBFGS direction at $\bar{u}$; updates `cache.direction_lbfgs`
+    fn lbfgs_direction(&mut self) {
+        let cache = &mut self.panoc_engine.cache;
+        // update the LBFGS buffer with the pair (u_bar, fpr at u_bar)
+        cache
+            .lbfgs
+            .update_hessian(&self.u_bar.gamma_fpr, &self.u_bar.u);
+
+        // direction ← H(fpr at u_bar); if the LBFGS buffer is empty, this is the
+        // fixed-point residual, so that u_bar - direction = T(u_bar)
+        cache.direction_lbfgs.copy_from_slice(&self.u_bar.gamma_fpr);
+        cache.lbfgs.apply_hessian(&mut cache.direction_lbfgs);
+    }
+
+    /// Computes $u^+ = \bar{u} - \tau d$ and checks the line search condition at $u^+$
+    /// (see `PANOCEngine::line_search_condition_at_u_plus`)
+    fn line_search_condition(&mut self) -> Result<bool, SolverError> {
+        // u_plus ← u_bar - tau * direction
+        let cache = &mut self.panoc_engine.cache;
+        let tau = cache.tau;
+        cache
+            .u_plus
+            .iter_mut()
+            .zip(self.u_bar.u.iter())
+            .zip(cache.direction_lbfgs.iter())
+            .for_each(|((u_plus_i, &u_bar_i), &dir_i)| *u_plus_i = u_bar_i - tau * dir_i);
+
+        self.panoc_engine.line_search_condition_at_u_plus()
+    }
+
+    /// Performs a line search to select tau and updates `u_current`
+    ///
+    /// If the line search fails, `tau` is set to zero and the next iterate is
+    /// $\bar{u}$, for which the required quantities are already available
+    fn linesearch(&mut self, u_current: &mut [f64]) -> FunctionCallResult {
+        self.panoc_engine.cache.tau = 1.0;
+        let mut num_ls_iters = 0;
+        while self.line_search_condition()? {
+            num_ls_iters += 1;
+            if num_ls_iters == MAX_LINESEARCH_ITERATIONS {
+                let cache = &mut self.panoc_engine.cache;
+                cache.tau = 0.;
+                u_current.copy_from_slice(&self.u_bar.u);
+                cache.cost_value = self.u_bar.cost;
+                cache.gradient_u.copy_from_slice(&self.u_bar.gradient);
+                cache.u_half_step.copy_from_slice(&self.u_bar.half_step);
+                self.panoc_engine.gradient_step(u_current); // gradient_step ← u_bar - gamma * gradient
+                return Ok(());
+            }
+            self.panoc_engine.cache.tau /= 2.0;
+        }
+        // u_current ← u_plus
+        u_current.copy_from_slice(&self.panoc_engine.cache.u_plus);
+
+        Ok(())
+    }
+}
+
+/// Implementation of the `step` and `init` methods of [trait.AlgorithmEngine.html]
+impl<'a, GradientType, ConstraintType, CostType> AlgorithmEngine
+    for ZeroFPREngine<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    /// ZeroFPR step
+    ///
+    /// Performs a step of ZeroFPR: computes the forward-backward point,
+    /// $\bar{u} = T_\gamma(u)$, a quasi-Newton (L-BFGS) direction, $d$, for the
+    /// fixed-point residual at $\bar{u}$, and the next iterate, $u^+ = \bar{u} - \tau d$,
+    /// where $\tau$ is determined by a line search on the forward-backward envelope,
+    /// $\varphi_\gamma$, so that
+    ///
+    /// $$
+    /// \varphi_\gamma(u^+) \leq \varphi_\gamma(u) - \sigma\Vert{}u - \bar{u}{}\Vert^2.
+    /// $$
+    ///
+    /// Unlike PANOC, for which $\tau=0$ corresponds to a forward-backward step,
+    /// the line search of ZeroFPR is always well defined: if it fails, $u^+ = \bar{u}$,
+    /// which satisfies the above condition.
+    ///
+    /// ## Arguments
+    ///
+    /// - `u_current` on entry is the current iterate; on exit, it is updated with the next
+    ///   iterate of ZeroFPR
+    ///
+    fn step(&mut self, u_current: &mut [f64]) -> Result<bool, SolverError> {
+        // caches the previous gradient vector (copies df to df_previous)
+        self.panoc_engine.cache.cache_previous_gradient();
+
+        // compute the fixed point residual
+        self.panoc_engine.compute_fpr(u_current);
+
+        // exit if the exit conditions are satisfied (||gamma*fpr|| < eps and,
+        // if activated, ||gamma*r + df - df_prev|| < eps_akkt)
+        if self.panoc_engine.cache.exit_condition() {
+            return Ok(false);
+        }
+        self.panoc_engine.update_lipschitz_constant(u_current)?; // update lipschitz constant
+        self.panoc_engine.compute_rhs_ls(); // compute the right hand side of the line search
+        self.forward_backward_step_u_bar()?; // T(u_bar) and fpr at u_bar
+        self.lbfgs_direction(); // compute LBFGS direction (update LBFGS buffer)
+        self.linesearch(u_current)?;
+
+        self.panoc_engine.cache.iteration += 1;
+        Ok(true)
+    }
+
+    /// Initialization of ZeroFPR
+    ///
+    /// Same as the initialization of PANOC: computes the cost and its gradient
+    /// at the initial point, an initial estimate of the Lipschitz constant of the
+    /// gradient of the cost, initial estimates of `gamma` and `sigma`, and a
+    /// forward-backward step
+    ///
+    fn init(&mut self, u_current: &mut [f64]) -> FunctionCallResult {
+        self.u_bar.cost = 0.0;
+        self.panoc_engine.init(u_current)
+    }
+}
+
+impl<'a, GradientType, ConstraintType, CostType> PANOCTypeEngine
+    for ZeroFPREngine<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    fn panoc_cache(&self) -> &PANOCCache {
+        self.panoc_engine.cache
+    }
+}
+
+/* --------------------------------------------------------------------------------------------- */
+/*       TESTS                                                                                   */
+/* --------------------------------------------------------------------------------------------- */
+
+#[cfg(test)]
+mod tests {
+
+    use crate::constraints;
+    use crate::core::zerofpr::zerofpr_engine::ZeroFPREngine;
+    use crate::core::zerofpr::*;
+    use crate::core::{AlgorithmEngine, Problem};
+    use crate::{matrix_operations, mocks};
+
+    #[test]
+    fn t_linesearch_failure() {
+        // If the line search fails, the next iterate is the forward-backward point,
+        // u_bar = T(u), and the cache holds the cost, the gradient and the
+        // forward-backward step at u_bar, which ZeroFPR has already computed
+        let ball = constraints::Ball2::new(None, 0.2);
+        let problem = Problem::new(&ball, mocks::my_gradient, mocks::my_cost);
+        let mut zerofpr_cache = ZeroFPRCache::new(2, 1e-12, 5);
+        let mut zerofpr_engine = ZeroFPREngine::new(problem, &mut zerofpr_cache);
+        let mut u = [0.75, -1.4];
+        zerofpr_engine.init(&mut u).unwrap();
+
+        zerofpr_engine.panoc_engine.compute_fpr(&u);
+        zerofpr_engine
+            .panoc_engine
+            .update_lipschitz_constant(&u)
+            .unwrap();
+        let u_half_step = zerofpr_engine.panoc_engine.cache.u_half_step.clone();
+        zerofpr_engine.panoc_engine.cache.rhs_ls = -f64::INFINITY; // no tau is accepted
+        zerofpr_engine.forward_backward_step_u_bar().unwrap();
+        zerofpr_engine.lbfgs_direction();
+        zerofpr_engine.linesearch(&mut u).unwrap();
+
+        let cache = &zerofpr_engine.panoc_engine.cache;
+        let u_bar = &zerofpr_engine.u_bar;
+        assert_eq!(0.0, cache.tau);
+        assert_eq!(u_half_step, u_bar.u);
+        assert_eq!(u_bar.u, u);
+        assert_eq!(u_bar.cost, cache.cost_value);
+        assert_eq!(u_bar.gradient, cache.gradient_u);
+        assert_eq!(u_bar.half_step, cache.u_half_step);
+
+        let mut cost = 0.0;
+        let mut grad = [0.0; 2];
+        mocks::my_cost(&u, &mut cost).unwrap();
+        mocks::my_gradient(&u, &mut grad).unwrap();
+        assert_eq!(cost, cache.cost_value);
+        assert!(matrix_operations::norm_inf_diff(&grad, &cache.gradient_u) < 1e-12);
+    }
+}
@@ -0,0 +1,135 @@
+//! ZeroFPR optimizer
+//!
+use crate::{
+    core::{
+        panoc, zerofpr::zerofpr_engine::ZeroFPREngine, zerofpr::ZeroFPRCache, Optimizer, Problem,
+        SolverStatus,
+    },
+    proximal, FunctionCallResult, SolverError,
+};
+use std::time;
+
+const MAX_ITER: usize = 100_usize;
+
+/// Optimizer using the ZeroFPR algorithm
+///
+///
+pub struct ZeroFPROptimizer<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    zerofpr_engine: ZeroFPREngine<'a, GradientType, ConstraintType, CostType>,
+    max_iter: usize,
+    max_duration: Option<time::Duration>,
+}
+
+impl<'a, GradientType, ConstraintType, CostType>
+    ZeroFPROptimizer<'a, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator,
+{
+    /// Constructor of `ZeroFPROptimizer`
+    ///
+    /// ## Arguments
+    ///
+    /// - problem: definition of optimization problem
+    /// - cache: cache object constructed once
+    ///
+    /// ## Panic
+    ///
+    /// Does not panic
+    pub fn new(
+        problem: Problem<'a, GradientType, ConstraintType, CostType>,
+        cache: &'a mut ZeroFPRCache,
+    ) -> Self {
+        ZeroFPROptimizer {
+            zerofpr_engine: ZeroFPREngine::new(problem, cache),
+            max_iter: MAX_ITER,
+            max_duration: None,
+        }
+    }
+
+    /// Sets the tolerance on the norm of the fixed-point residual
+    ///
+    /// The algorithm will exit if the norm of gamma*FPR drops below
+    /// this tolerance
+    ///
+    /// ## Panics
+    ///
+    /// The method panics if the specified tolerance is not positive
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        assert!(tolerance > 0.0, "tolerance must be larger than 0");
+
+        self.zerofpr_engine.panoc_engine.cache.tolerance = tolerance;
+        self
+    }
+
+    /// Specify the tolerance $\epsilon$ related to the AKKT condition
+    ///
+    /// $$
+    /// \Vert{}\gamma^{-1}(u-u^+) + \nabla f(u) - \nabla f(u^+){}\Vert \leq \epsilon
+    /// $$
+    ///
+    /// ## Arguments
+    ///
+    /// - `akkt_tolerance`: the AKKT-specific tolerance
+    ///
+    ///
+    /// ## Returns
+    ///
+    /// Returns the current mutable and updated instance of the provided object
+    ///
+    ///  
+    /// ## Panics
+    ///
+    /// The method panics if the provided value of the AKKT-specific tolerance is
+    /// not positive.
+    ///
+    pub fn with_akkt_tolerance(self, akkt_tolerance: f64) -> Self {
+        assert!(akkt_tolerance > 0.0, "akkt_tolerance must be positive");
+        self.zerofpr_engine
+            .panoc_engine
+            .cache
+            .set_akkt_tolerance(akkt_tolerance);
+        self
+    }
+
+    /// Sets the maximum number of iterations
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the provided number of iterations is equal to zero
+    pub fn with_max_iter(mut self, max_iter: usize) -> Self {
+        assert!(max_iter > 0, "max_iter must be larger than 0");
+
+        self.max_iter = max_iter;
+        self
+    }
+
+    /// Sets the maximum solution time, useful in real-time applications
+    pub fn with_max_duration(mut self, max_duration: time::Duration) -> Self {
+        self.max_duration = Some(max_duration);
+        self
+    }
+}
+
+impl<'life, GradientType, ConstraintType, CostType> Optimizer
+    for ZeroFPROptimizer<'life, GradientType, ConstraintType, CostType>
+where
+    GradientType: Fn(&[f64], &mut [f64]) -> FunctionCallResult + 'life,
+    CostType: Fn(&[f64], &mut f64) -> FunctionCallResult,
+    ConstraintType: proximal::ProximalOperator + 'life,
+{
+    fn solve(&mut self, u: &mut [f64]) -> Result<SolverStatus, SolverError> {
+        panoc::solve(
+            &mut self.zerofpr_engine,
+            u,
+            self.max_iter,
+            self.max_duration,
+        )
+    }
+}
@@ -52,14 +52,82 @@ pub enum SolverError {
 /// Result of a function call (status)
 pub type FunctionCallResult = Result<(), SolverError>;
 
+/// Errors that may arise when constructing sets, caches or problems from
+/// invalid data (see, e.g., `Rectangle::try_new`)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConstructionError {
+    /// The given data is empty
+    EmptyData,
+    /// The dimension of the given data is `found`, but `expected` was expected
+    DimensionMismatch {
+        /// expected dimension
+        expected: usize,
+        /// actual dimension
+        found: usize,
+    },
+    /// A parameter has an invalid value
+    InvalidParameter {
+        /// name of the parameter
+        name: &'static str,
+        /// given (invalid) value
+        value: f64,
+    },
+    /// Neither a lower nor an upper bound was provided
+    MissingBounds,
+    /// The indices of a Cartesian product must be strictly increasing
+    IndexNotIncreasing {
+        /// given index
+        index: usize,
+        /// previous index
+        previous: usize,
+    },
+    /// The system of linear equations $Ax = b$ has no solution
+    InconsistentSystem,
+    /// The set defined by the given data is empty
+    EmptySet,
+    /// The given set must be convex
+    NonconvexSet,
+}
+
+impl std::fmt::Display for ConstructionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstructionError::EmptyData => write!(f, "empty data not allowed"),
+            ConstructionError::DimensionMismatch { expected, found } => write!(
+                f,
+                "incompatible dimensions (expected {}, found {})",
+                expected, found
+            ),
+            ConstructionError::InvalidParameter { name, value } => {
+                write!(f, "invalid value of {}: {}", name, value)
+            }
+            ConstructionError::MissingBounds => write!(f, "no bounds were provided"),
+            ConstructionError::IndexNotIncreasing { index, previous } => write!(
+                f,
+                "index {} is not larger than the previous index, {}",
+                index, previous
+            ),
+            ConstructionError::InconsistentSystem => {
+                write!(f, "the system of linear equations is inconsistent")
+            }
+            ConstructionError::EmptySet => write!(f, "the set is empty"),
+            ConstructionError::NonconvexSet => write!(f, "the set must be convex"),
+        }
+    }
+}
+
+impl std::error::Error for ConstructionError {}
+
 pub mod alm;
 pub mod constraints;
 pub mod core;
 pub mod lipschitz_estimator;
 pub mod matrix_operations;
+pub mod proximal;
 
 pub use crate::core::fbs;
 pub use crate::core::panoc;
+pub use crate::core::zerofpr;
 pub use crate::core::{AlgorithmEngine, Optimizer, Problem};
 
 /* Use Jemalloc if the feature `jem` is activated */
@@ -36,6 +36,7 @@
 //! ```
 //!
 
+use ndarray::Array2;
 use num::{Float, Zero};
 use std::iter::Sum;
 use std::ops::Mul;
@@ -140,6 +141,130 @@ where
     !a.iter().any(|&xi| !xi.is_finite())
 }
 
+/// Maximum number of sweeps of the Jacobi eigenvalue algorithm
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// Computes the eigenvalue decomposition of a symmetric matrix using the
+/// cyclic Jacobi method, that is, $A = V\Lambda V^\intercal$
+///
+/// ## Arguments
+///
+/// - `a`: (in) symmetric matrix $A\in\mathbb{R}^{n\times n}$ (only its data is
+///   used, so it can be stored row-wise or column-wise), (out) it is overwritten
+///   by the (almost) diagonal matrix $\Lambda$
+/// - `eigenvalues`: (out) the $n$ eigenvalues of $A$ (not sorted)
+/// - `eigenvectors`: (out) the matrix $V$ stored row-wise; its $i$-th column is
+///   the eigenvector that corresponds to the $i$-th eigenvalue
+///
+/// ## Panics
+///
+/// The method panics if `a` or `eigenvectors` do not have length $n^2$, where
+/// $n$ is the length of `eigenvalues`
+///
+pub fn symmetric_eigen(a: &mut [f64], eigenvalues: &mut [f64], eigenvectors: &mut [f64]) {
+    let n = eigenvalues.len();
+    assert!(a.len() == n * n, "a has wrong dimension");
+    assert!(
+        eigenvectors.len() == n * n,
+        "eigenvectors has wrong dimension"
+    );
+
+    // V ← I
+    eigenvectors.iter_mut().for_each(|vi| *vi = 0.0);
+    (0..n).for_each(|i| eigenvectors[i * n + i] = 1.0);
+
+    let norm_a_sq = norm2_squared(a);
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut off_diagonal_sq = 0.0;
+        for p in 0..n {
+            for q in p + 1..n {
+                off_diagonal_sq += 2.0 * a[p * n + q].powi(2);
+            }
+        }
+        if off_diagonal_sq <= f64::EPSILON.powi(2) * norm_a_sq {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                let a_pq = a[p * n + q];
+                if a_pq == 0.0 {
+                    continue;
+                }
+                // Jacobi rotation that eliminates a[p, q]
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * a_pq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                // A ← AJ and V ← VJ (columns p and q)
+                for k in 0..n {
+                    let (a_kp, a_kq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * a_kp - s * a_kq;
+                    a[k * n + q] = s * a_kp + c * a_kq;
+                    let (v_kp, v_kq) = (eigenvectors[k * n + p], eigenvectors[k * n + q]);
+                    eigenvectors[k * n + p] = c * v_kp - s * v_kq;
+                    eigenvectors[k * n + q] = s * v_kp + c * v_kq;
+                }
+                // A ← J'A (rows p and q)
+                for k in 0..n {
+                    let (a_pk, a_qk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * a_pk - s * a_qk;
+                    a[q * n + k] = s * a_pk + c * a_qk;
+                }
+            }
+        }
+    }
+    eigenvalues
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, ei)| *ei = a[i * n + i]);
+}
+
+/// Computes the Cholesky factorisation, $M = LL^\intercal$, of a symmetric matrix $M$
+/// and returns the lower triangular matrix $L$, or `None` if $M$ is not positive definite
+pub(crate) fn cholesky_factor(mat: &Array2<f64>) -> Option<Array2<f64>> {
+    let n = mat.nrows();
+    let mut chol = Array2::<f64>::zeros((n, n));
+    for j in 0..n {
+        let mut diag = mat[(j, j)];
+        for k in 0..j {
+            diag -= chol[(j, k)] * chol[(j, k)];
+        }
+        if diag <= 0.0 || !diag.is_finite() {
+            return None;
+        }
+        let l_jj = diag.sqrt();
+        chol[(j, j)] = l_jj;
+        for i in j + 1..n {
+            let mut l_ij = mat[(i, j)];
+            for k in 0..j {
+                l_ij -= chol[(i, k)] * chol[(j, k)];
+            }
+            chol[(i, j)] = l_ij / l_jj;
+        }
+    }
+    Some(chol)
+}
+
+/// Solves the system $Mx = b$ given the Cholesky factorisation $M = LL^\intercal$
+pub(crate) fn cholesky_solve(chol: &Array2<f64>, b: &[f64], x: &mut [f64]) {
+    let m = b.len();
+    // Step 1: Solve Ly = b (the result is stored in x)
+    for i in 0..m {
+        x[i] = b[i];
+        for j in 0..i {
+            x[i] -= chol[(i, j)] * x[j];
+        }
+        x[i] /= chol[(i, i)];
+    }
+    // Step 2: Solve L'x = y
+    for i in (0..m).rev() {
+        for j in i + 1..m {
+            x[i] -= chol[(j, i)] * x[j];
+        }
+        x[i] /= chol[(i, i)];
+    }
+}
+
 /* ---------------------------------------------------------------------------- */
 /*          TESTS                                                               */
 /* ---------------------------------------------------------------------------- */
@@ -241,4 +366,36 @@ mod tests {
         let norm2sq = matrix_operations::norm2_squared_diff(&x, &y);
         unit_test_utils::assert_nearly_equal(190., norm2sq, 1e-10, 1e-12, "norm sq diff");
     }
+
+    #[test]
+    fn t_symmetric_eigen() {
+        let a = [4.0, 1.0, -2.0, 1.0, 2.0, 0.0, -2.0, 0.0, 3.0];
+        let mut a_work = a;
+        let mut eigenvalues = [0.0; 3];
+        let mut v = [0.0; 9];
+        matrix_operations::symmetric_eigen(&mut a_work, &mut eigenvalues, &mut v);
+        // check that A v_i = lambda_i v_i for all i
+        for i in 0..3 {
+            for r in 0..3 {
+                let a_v: f64 = (0..3).map(|k| a[r * 3 + k] * v[k * 3 + i]).sum();
+                unit_test_utils::assert_nearly_equal(
+                    eigenvalues[i] * v[r * 3 + i],
+                    a_v,
+                    1e-10,
+                    1e-12,
+                    "eigenvalue equation",
+                );
+            }
+        }
+        // check that V is orthogonal
+        for i in 0..3 {
+            for j in 0..3 {
+                let vi_vj: f64 = (0..3).map(|k| v[k * 3 + i] * v[k * 3 + j]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((expected - vi_vj).abs() < 1e-12, "V is not orthogonal");
+            }
+        }
+        let trace: f64 = eigenvalues.iter().sum();
+        unit_test_utils::assert_nearly_equal(9.0, trace, 1e-12, 1e-12, "trace");
+    }
 }
@@ -1,6 +1,8 @@
 use crate::{matrix_operations, SolverError};
 
 pub const SOLUTION_A: [f64; 2] = [-0.148_959_718_255_77, 0.133_457_867_273_39];
+/// Solution of the problem with cost `my_cost(u) + 0.5 * norm1(u)`
+pub const SOLUTION_L1: [f64; 2] = [-1.5, 1.0];
 pub const SOLUTION_HARD: [f64; 3] = [
     -0.041_123_164_672_281,
     -0.028_440_417_469_206,
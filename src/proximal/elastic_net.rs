@@ -0,0 +1,46 @@
+use super::norm_l1::soft_threshold;
+use super::ProximalOperator;
+use crate::matrix_operations;
+
+#[derive(Copy, Clone)]
+/// The elastic net regularizer, that is, the function
+/// $g(x) = \lambda_1\Vert{}x{}\Vert_1 + \tfrac{\lambda_2}{2}\Vert{}x{}\Vert_2^2$
+pub struct ElasticNet {
+    lambda1: f64,
+    lambda2: f64,
+}
+
+impl ElasticNet {
+    /// Construct a new elastic net regularizer with parameters $\lambda_1$
+    /// and $\lambda_2$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if either `lambda1` or `lambda2` is negative
+    ///
+    pub fn new(lambda1: f64, lambda2: f64) -> Self {
+        assert!(lambda1 >= 0.0, "lambda1 must be nonnegative");
+        assert!(lambda2 >= 0.0, "lambda2 must be nonnegative");
+        ElasticNet { lambda1, lambda2 }
+    }
+}
+
+impl ProximalOperator for ElasticNet {
+    /// Computes the proximal operator of $\gamma g$, which is given by
+    ///
+    /// $$
+    /// (\mathrm{prox}_{\gamma g}(v))_i = \frac{\mathrm{sgn}(v_i)\max\\{|v_i| - \gamma\lambda_1, 0\\}}{1 + \gamma\lambda_2}
+    /// $$
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64) {
+        let kappa = gamma * self.lambda1;
+        let scaling = 1.0 + gamma * self.lambda2;
+        x.iter_mut()
+            .for_each(|xi| *xi = soft_threshold(*xi, kappa) / scaling);
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        self.lambda1 * matrix_operations::norm1(x)
+            + 0.5 * self.lambda2 * matrix_operations::norm2_squared(x)
+    }
+}
@@ -0,0 +1,65 @@
+use super::ProximalOperator;
+
+#[derive(Copy, Clone)]
+/// The Huber function, $g(x) = \lambda\sum_i h_\delta(x_i)$, where
+///
+/// $$
+/// h_\delta(t) = \begin{cases}\tfrac{1}{2}t^2,&\text{ if } |t| \leq \delta\\\\
+/// \delta(|t| - \tfrac{\delta}{2}),&\text{ otherwise}\end{cases}
+/// $$
+///
+/// The Huber function is smooth, but its gradient has a large Lipschitz
+/// constant when $\delta$ is small, so it is often preferable to treat it
+/// via its proximal operator.
+pub struct Huber {
+    delta: f64,
+    lambda: f64,
+}
+
+impl Huber {
+    /// Construct a new Huber function with parameters $\delta$ and $\lambda$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `delta` is not positive, or if `lambda` is negative
+    ///
+    pub fn new(delta: f64, lambda: f64) -> Self {
+        assert!(delta > 0.0, "delta must be positive");
+        assert!(lambda >= 0.0, "lambda must be nonnegative");
+        Huber { delta, lambda }
+    }
+}
+
+impl ProximalOperator for Huber {
+    /// Computes the proximal operator of $\gamma g$, which is given by
+    ///
+    /// $$
+    /// (\mathrm{prox}_{\gamma g}(v))_i = \begin{cases}\frac{v_i}{1 + \gamma\lambda},&\text{ if } |v_i| \leq \delta(1 + \gamma\lambda)\\\\
+    /// v_i - \gamma\lambda\delta\,\mathrm{sgn}(v_i),&\text{ otherwise}\end{cases}
+    /// $$
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64) {
+        let gamma_lambda = gamma * self.lambda;
+        let threshold = self.delta * (1.0 + gamma_lambda);
+        x.iter_mut().for_each(|xi| {
+            if xi.abs() <= threshold {
+                *xi /= 1.0 + gamma_lambda;
+            } else {
+                *xi -= gamma_lambda * self.delta * xi.signum();
+            }
+        });
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        let delta = self.delta;
+        self.lambda
+            * x.iter().fold(0.0, |sum, &xi| {
+                let abs_xi = xi.abs();
+                if abs_xi <= delta {
+                    sum + 0.5 * xi * xi
+                } else {
+                    sum + delta * (abs_xi - 0.5 * delta)
+                }
+            })
+    }
+}
@@ -0,0 +1,82 @@
+#![deny(missing_docs)]
+//! Proximal operators
+//!
+//! This module defines the trait [`ProximalOperator`], which describes the
+//! nonsmooth part, $g$, of a cost function of the form $f(u) + g(u)$, where
+//! $f$ is smooth. Algorithms such as PANOC and FBS only access $g$ via its
+//! proximal operator and its value.
+//!
+//! Every [`Constraint`] is a proximal operator: the proximal operator of the
+//! indicator function of a set, $\delta_C$, is the projection onto $C$.
+//! This module also provides a collection of commonly used nonsmooth functions,
+//! such as the (weighted) $\ell_1$ norm, the group lasso norm, the elastic net
+//! and the Huber function.
+//!
+//! [`ProximalOperator`]: trait.ProximalOperator.html
+//! [`Constraint`]: ../constraints/trait.Constraint.html
+
+mod elastic_net;
+mod huber;
+mod norm_inf;
+mod norm_l1;
+mod norm_l21;
+mod rectangle_norm_l1;
+
+pub use elastic_net::ElasticNet;
+pub use huber::Huber;
+pub use norm_inf::NormInf;
+pub use norm_l1::NormL1;
+pub use norm_l21::NormL21;
+pub use rectangle_norm_l1::RectangleNormL1;
+
+use crate::constraints::Constraint;
+
+/// A nonsmooth function, $g$, whose proximal operator can be easily computed
+///
+/// This trait is implemented by all constraints (as indicator functions) and
+/// by nonsmooth functions such as norms.
+pub trait ProximalOperator {
+    /// Proximal operator of $\gamma g$, that is,
+    ///
+    /// $$
+    /// \mathrm{prox}_{\gamma g}(v) = \mathrm{argmin}_{z}
+    /// \left\\{g(z) + \tfrac{1}{2\gamma}\Vert{}z-v{}\Vert^2\right\\}
+    /// $$
+    ///
+    /// ## Arguments
+    ///
+    /// - `x`: The given vector $v$ is updated with $\mathrm{prox}_{\gamma g}(v)$
+    /// - `gamma`: positive parameter $\gamma$
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64);
+
+    /// Value of $g$ at a given point $x$
+    ///
+    /// This is needed for the evaluation of the forward-backward envelope.
+    fn value(&self, x: &[f64]) -> f64;
+}
+
+/// Every constraint is a proximal operator; the proximal operator of the
+/// indicator function of a set $C$ is the projection onto $C$ (for all
+/// $\gamma > 0$).
+impl<C> ProximalOperator for C
+where
+    C: Constraint + ?Sized,
+{
+    fn prox(&self, x: &mut [f64], _gamma: f64) {
+        self.project(x);
+    }
+
+    /// Returns zero, which is the value of the indicator function of the
+    /// set at points in the set (this method is only called at points that
+    /// have been projected onto the set)
+    fn value(&self, _x: &[f64]) -> f64 {
+        0.0
+    }
+}
+
+/* ---------------------------------------------------------------------------- */
+/*          TESTS                                                               */
+/* ---------------------------------------------------------------------------- */
+#[cfg(test)]
+mod tests;
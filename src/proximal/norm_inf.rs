@@ -0,0 +1,53 @@
+use super::ProximalOperator;
+use crate::constraints::simplex_threshold;
+use crate::matrix_operations;
+
+#[derive(Copy, Clone)]
+/// The $\ell_\infty$ norm, that is, the function $g(x) = \lambda\Vert{}x{}\Vert_\infty$
+pub struct NormInf {
+    lambda: f64,
+}
+
+impl NormInf {
+    /// Construct a new $\ell_\infty$ norm with regularization parameter $\lambda$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `lambda` is negative
+    ///
+    pub fn new(lambda: f64) -> Self {
+        assert!(lambda >= 0.0, "lambda must be nonnegative");
+        NormInf { lambda }
+    }
+}
+
+impl ProximalOperator for NormInf {
+    /// Computes the proximal operator of $\gamma g$ using the Moreau decomposition;
+    /// since the conjugate of $\lambda\Vert{}\cdot{}\Vert_\infty$ is the indicator of
+    /// the $\ell_1$ ball of radius $\lambda$,
+    ///
+    /// $$
+    /// \mathrm{prox}_{\gamma g}(v) = v - \Pi_{B_1^{\gamma\lambda}}(v).
+    /// $$
+    ///
+    /// If $\Vert{}v{}\Vert_1 > \gamma\lambda$, the projection on the $\ell_1$ ball is
+    /// $\mathrm{sign}(v_i)\max\\{|v_i| - \tau, 0\\}$, so the proximal operator is obtained
+    /// (in place) by clipping $v$ to $[-\tau, \tau]$; otherwise, it is zero.
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64) {
+        let radius = gamma * self.lambda;
+        if radius <= 0.0 {
+            return;
+        }
+        if matrix_operations::norm1(x) <= radius {
+            x.iter_mut().for_each(|xi| *xi = 0.0);
+        } else {
+            let tau = simplex_threshold(x.len(), radius, |i| x[i].abs(), |_| 1.0);
+            x.iter_mut().for_each(|xi| *xi = xi.clamp(-tau, tau));
+        }
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        self.lambda * matrix_operations::norm_inf(x)
+    }
+}
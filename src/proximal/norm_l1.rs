@@ -0,0 +1,75 @@
+use super::ProximalOperator;
+
+#[derive(Copy, Clone)]
+/// A (weighted) $\ell_1$ norm, that is, the function
+/// $g(x) = \lambda\Vert{}x{}\Vert_1 = \lambda\sum_{i}|x_i|$, or, given a vector of
+/// nonnegative weights, $w$, the function $g(x) = \lambda\sum_i w_i |x_i|$
+pub struct NormL1<'a> {
+    weights: Option<&'a [f64]>,
+    lambda: f64,
+}
+
+impl<'a> NormL1<'a> {
+    /// Construct a new (weighted) $\ell_1$ norm with given weights and
+    /// regularization parameter $\lambda$
+    ///
+    /// If no `weights` are given, then all weights are assumed to be equal to 1
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `lambda` is negative or if any of the weights is
+    /// negative; `prox` and `value` panic if the given vector and the weights
+    /// have different lengths
+    ///
+    pub fn new(weights: Option<&'a [f64]>, lambda: f64) -> Self {
+        assert!(lambda >= 0.0, "lambda must be nonnegative");
+        if let Some(w) = weights {
+            assert!(w.iter().all(|&wi| wi >= 0.0), "weights must be nonnegative");
+        }
+        NormL1 { weights, lambda }
+    }
+}
+
+/// Soft thresholding operator, $x \mapsto \mathrm{sgn}(x)\max\\{|x| - \kappa, 0\\}$
+pub(crate) fn soft_threshold(x: f64, kappa: f64) -> f64 {
+    x.signum() * f64::max(x.abs() - kappa, 0.0)
+}
+
+impl<'a> ProximalOperator for NormL1<'a> {
+    /// Computes the proximal operator of $\gamma g$ by soft thresholding, that is,
+    ///
+    /// $$
+    /// (\mathrm{prox}_{\gamma g}(v))_i = \mathrm{sgn}(v_i)\max\\{|v_i| - \gamma\lambda w_i, 0\\}
+    /// $$
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64) {
+        let gamma_lambda = gamma * self.lambda;
+        if let Some(weights) = &self.weights {
+            assert!(
+                weights.len() == x.len(),
+                "x and weights have incompatible dimensions"
+            );
+            x.iter_mut()
+                .zip(weights.iter())
+                .for_each(|(xi, &wi)| *xi = soft_threshold(*xi, gamma_lambda * wi));
+        } else {
+            x.iter_mut()
+                .for_each(|xi| *xi = soft_threshold(*xi, gamma_lambda));
+        }
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        if let Some(weights) = &self.weights {
+            assert!(
+                weights.len() == x.len(),
+                "x and weights have incompatible dimensions"
+            );
+            self.lambda
+                * x.iter()
+                    .zip(weights.iter())
+                    .fold(0.0, |sum, (&xi, &wi)| sum + wi * xi.abs())
+        } else {
+            self.lambda * crate::matrix_operations::norm1(x)
+        }
+    }
+}
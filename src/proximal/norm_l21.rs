@@ -0,0 +1,118 @@
+use super::ProximalOperator;
+use crate::matrix_operations;
+
+/// The $\ell_{2,1}$ (group lasso) norm
+///
+/// A vector $x\in\mathbb{R}^n$ is split in parts (groups)
+///
+/// $$x = (x_0, x_1, ..., x_{m-1}),$$
+///
+/// where $x_j$ has dimension $n_j$, and the $\ell_{2,1}$ norm of $x$ is
+///
+/// $$
+/// g(x) = \lambda\sum_{j=0}^{m-1}\Vert{}x_j{}\Vert_2.
+/// $$
+///
+/// Groups are specified by indices in the same way as in
+/// [`CartesianProduct`](../constraints/struct.CartesianProduct.html).
+///
+#[derive(Clone)]
+pub struct NormL21 {
+    idx: Vec<usize>,
+    lambda: f64,
+}
+
+impl NormL21 {
+    /// Construct a new $\ell_{2,1}$ norm with given regularization parameter $\lambda$
+    /// and no groups; groups are added using `add_group`
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `lambda` is negative
+    ///
+    pub fn new(lambda: f64) -> Self {
+        assert!(lambda >= 0.0, "lambda must be nonnegative");
+        NormL21 {
+            idx: Vec::new(),
+            lambda,
+        }
+    }
+
+    /// Dimension of the vectors on which the norm is defined
+    pub fn dimension(&self) -> usize {
+        *self.idx.last().unwrap_or(&0)
+    }
+
+    /// Add a group
+    ///
+    /// # Arguments
+    ///
+    /// - `ni`: total length of vector `(x(0), ..., x(i))`, that is, the
+    ///   new group consists of the elements of `x` from the previous index
+    ///   (or zero) up to `ni` (exclusive)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use optimization_engine::proximal::*;
+    ///
+    /// // two groups: x0 = (x[0], x[1], x[2]) and x1 = (x[3], x[4])
+    /// let group_norm = NormL21::new(0.5)
+    ///     .add_group(3)
+    ///     .add_group(5);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `ni` is less than or equal to the previous index
+    ///
+    pub fn add_group(mut self, ni: usize) -> Self {
+        assert!(
+            self.dimension() < ni,
+            "provided index is smaller than or equal to previous index, or zero"
+        );
+        self.idx.push(ni);
+        self
+    }
+}
+
+impl ProximalOperator for NormL21 {
+    /// Computes the proximal operator by block soft thresholding, that is,
+    /// for every group $x_j$,
+    ///
+    /// $$
+    /// (\mathrm{prox}_{\gamma g}(v))_j = \max\left\\{1 - \frac{\gamma\lambda}{\Vert{}v_j{}\Vert_2}, 0\right\\} v_j
+    /// $$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if the dimension of `x` is not equal to the
+    /// dimension of the norm (see `dimension()`)
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64) {
+        assert!(x.len() == self.dimension(), "x has wrong size");
+        let gamma_lambda = gamma * self.lambda;
+        let mut j = 0;
+        self.idx.iter().for_each(|&i| {
+            let x_group = &mut x[j..i];
+            let norm_group = matrix_operations::norm2(x_group);
+            let scaling = if norm_group > gamma_lambda {
+                1.0 - gamma_lambda / norm_group
+            } else {
+                0.0
+            };
+            x_group.iter_mut().for_each(|xi| *xi *= scaling);
+            j = i;
+        });
+    }
+
+    fn value(&self, x: &[f64]) -> f64 {
+        let mut j = 0;
+        self.lambda
+            * self.idx.iter().fold(0.0, |sum, &i| {
+                let norm_group = matrix_operations::norm2(&x[j..i]);
+                j = i;
+                sum + norm_group
+            })
+    }
+}
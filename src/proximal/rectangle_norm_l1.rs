@@ -0,0 +1,49 @@
+use super::norm_l1::soft_threshold;
+use super::ProximalOperator;
+use crate::constraints::{Constraint, Rectangle};
+use crate::matrix_operations;
+
+#[derive(Clone)]
+/// The sum of the indicator function of a rectangle and an $\ell_1$ norm, that is,
+/// $g(x) = \delta_R(x) + \lambda\Vert{}x{}\Vert_1$, where
+/// $R = \\{x \in \mathbb{R}^n {}:{} x_{\min} {}\leq{} x {}\leq{} x_{\max}\\}$
+///
+/// This is useful to impose box constraints on sparse decision variables.
+pub struct RectangleNormL1<'a> {
+    rectangle: Rectangle<'a>,
+    lambda: f64,
+}
+
+impl<'a> RectangleNormL1<'a> {
+    /// Construct a new instance of $\delta_R + \lambda\Vert{}\cdot{}\Vert_1$ given
+    /// a rectangle, $R$, and a regularization parameter $\lambda$
+    ///
+    /// # Panics
+    ///
+    /// The method panics if `lambda` is negative
+    ///
+    pub fn new(rectangle: Rectangle<'a>, lambda: f64) -> Self {
+        assert!(lambda >= 0.0, "lambda must be nonnegative");
+        RectangleNormL1 { rectangle, lambda }
+    }
+}
+
+impl<'a> ProximalOperator for RectangleNormL1<'a> {
+    /// Computes the proximal operator of $\gamma g$; since $g$ is separable, this
+    /// is done by soft thresholding followed by a projection on the rectangle, that is,
+    ///
+    /// $$
+    /// \mathrm{prox}_{\gamma g}(v) = \Pi_R(\mathrm{prox}_{\gamma\lambda\Vert{}\cdot{}\Vert_1}(v))
+    /// $$
+    ///
+    fn prox(&self, x: &mut [f64], gamma: f64) {
+        let kappa = gamma * self.lambda;
+        x.iter_mut().for_each(|xi| *xi = soft_threshold(*xi, kappa));
+        self.rectangle.project(x);
+    }
+
+    /// Returns $\lambda\Vert{}x{}\Vert_1$; it is assumed that $x\in{}R$
+    fn value(&self, x: &[f64]) -> f64 {
+        self.lambda * matrix_operations::norm1(x)
+    }
+}
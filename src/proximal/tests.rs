@@ -0,0 +1,300 @@
+use super::*;
+use crate::constraints::*;
+
+#[test]
+fn t_constraint_prox_is_projection() {
+    let ball = Ball2::new(None, 1.0);
+    let mut x_prox = [3.0, 4.0];
+    let mut x_proj = [3.0, 4.0];
+    ball.prox(&mut x_prox, 0.5);
+    ball.project(&mut x_proj);
+    unit_test_utils::assert_nearly_equal_array(
+        &x_proj,
+        &x_prox,
+        1e-12,
+        1e-14,
+        "prox of indicator is not the projection",
+    );
+    unit_test_utils::assert_nearly_equal(0.0, ball.value(&x_prox), 1e-14, 1e-14, "value");
+}
+
+#[test]
+fn t_constraint_prox_independent_of_gamma() {
+    let xmin = [-1.0, 0.0];
+    let xmax = [1.0, 2.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    let mut x1 = [-5.0, 3.0];
+    let mut x2 = [-5.0, 3.0];
+    rectangle.prox(&mut x1, 0.01);
+    rectangle.prox(&mut x2, 100.0);
+    unit_test_utils::assert_nearly_equal_array(&[-1.0, 2.0], &x1, 1e-12, 1e-14, "x1");
+    unit_test_utils::assert_nearly_equal_array(&x1, &x2, 1e-12, 1e-14, "x2");
+}
+
+/// Checks that `z = prox(v)` minimizes `g(z) + 1/(2 gamma) ||z - v||^2` by
+/// comparing with random perturbations of `z`
+fn assert_prox_optimality(op: &impl ProximalOperator, v: &[f64], gamma: f64) {
+    let mut z = v.to_vec();
+    op.prox(&mut z, gamma);
+    let prox_objective =
+        |w: &[f64]| op.value(w) + 0.5 * crate::matrix_operations::norm2_squared_diff(w, v) / gamma;
+    let best = prox_objective(&z);
+    for scale in [1e-4, 1e-2, 1.0].iter() {
+        for _ in 0..100 {
+            let w: Vec<f64> = z
+                .iter()
+                .map(|zi| zi + scale * (2. * rand::random::<f64>() - 1.))
+                .collect();
+            assert!(
+                prox_objective(&w) >= best - 1e-12 * (1. + best.abs()),
+                "prox is not optimal"
+            );
+        }
+    }
+}
+
+fn random_vector(n: usize, scale: f64) -> Vec<f64> {
+    (0..n)
+        .map(|_| scale * (2. * rand::random::<f64>() - 1.))
+        .collect()
+}
+
+#[test]
+fn t_norm_l1_prox() {
+    let norm_l1 = NormL1::new(None, 2.0);
+    let mut x = [3.0, -0.5, 0.1, -10.0];
+    norm_l1.prox(&mut x, 0.5);
+    unit_test_utils::assert_nearly_equal_array(
+        &[2.0, 0.0, 0.0, -9.0],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of l1 norm",
+    );
+    unit_test_utils::assert_nearly_equal(22.0, norm_l1.value(&x), 1e-12, 1e-14, "value");
+}
+
+#[test]
+fn t_norm_l1_weighted_prox() {
+    let weights = [1.0, 0.0, 2.0];
+    let norm_l1 = NormL1::new(Some(&weights), 1.5);
+    let mut x = [3.0, -0.5, -4.0];
+    norm_l1.prox(&mut x, 1.0);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.5, -0.5, -1.0],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of weighted l1 norm",
+    );
+    unit_test_utils::assert_nearly_equal(5.25, norm_l1.value(&x), 1e-12, 1e-14, "value");
+}
+
+#[test]
+#[should_panic]
+fn t_norm_l1_weighted_prox_wrong_dimension() {
+    let weights = [1.0, 2.0];
+    let norm_l1 = NormL1::new(Some(&weights), 1.5);
+    let mut x = [3.0, -0.5, -4.0];
+    norm_l1.prox(&mut x, 1.0);
+}
+
+#[test]
+#[should_panic]
+fn t_norm_l1_weighted_value_wrong_dimension() {
+    let weights = [1.0, 2.0, 3.0, 4.0];
+    let norm_l1 = NormL1::new(Some(&weights), 1.5);
+    norm_l1.value(&[3.0, -0.5, -4.0]);
+}
+
+#[test]
+fn t_norm_l1_random_optimality() {
+    let weights = random_vector(10, 1.0)
+        .iter()
+        .map(|wi| wi.abs())
+        .collect::<Vec<f64>>();
+    let norm_l1 = NormL1::new(Some(&weights), 0.7);
+    for _ in 0..50 {
+        let v = random_vector(10, 5.0);
+        assert_prox_optimality(&norm_l1, &v, 0.8);
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_norm_l1_negative_lambda() {
+    let _ = NormL1::new(None, -1.0);
+}
+
+#[test]
+#[should_panic]
+fn t_norm_l1_negative_weights() {
+    let weights = [1.0, -1.0];
+    let _ = NormL1::new(Some(&weights), 1.0);
+}
+
+#[test]
+fn t_norm_l21_prox() {
+    let group_norm = NormL21::new(1.0).add_group(2).add_group(5);
+    assert_eq!(5, group_norm.dimension());
+    let mut x = [3.0, 4.0, 0.1, -0.2, 0.2];
+    group_norm.prox(&mut x, 2.5);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.5, 2.0, 0.0, 0.0, 0.0],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of l21 norm",
+    );
+    unit_test_utils::assert_nearly_equal(2.5, group_norm.value(&x), 1e-12, 1e-14, "value");
+}
+
+#[test]
+fn t_norm_l21_random_optimality() {
+    let group_norm = NormL21::new(0.9).add_group(3).add_group(4).add_group(8);
+    for _ in 0..50 {
+        let v = random_vector(8, 2.0);
+        assert_prox_optimality(&group_norm, &v, 1.3);
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_norm_l21_incoherent_indices() {
+    let _ = NormL21::new(1.0).add_group(3).add_group(2);
+}
+
+#[test]
+#[should_panic]
+fn t_norm_l21_wrong_vector_dim() {
+    let group_norm = NormL21::new(1.0).add_group(3);
+    let mut x = [0.0; 4];
+    group_norm.prox(&mut x, 1.0);
+}
+
+#[test]
+fn t_elastic_net_prox() {
+    let elastic_net = ElasticNet::new(1.0, 2.0);
+    let mut x = [3.0, -0.5, -5.0];
+    elastic_net.prox(&mut x, 0.5);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.25, 0.0, -2.25],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of elastic net",
+    );
+}
+
+#[test]
+fn t_elastic_net_random_optimality() {
+    let elastic_net = ElasticNet::new(0.4, 1.7);
+    for _ in 0..50 {
+        let v = random_vector(6, 4.0);
+        assert_prox_optimality(&elastic_net, &v, 0.6);
+    }
+}
+
+#[test]
+fn t_norm_inf_prox() {
+    let norm_inf = NormInf::new(1.0);
+    let mut x = [3.0, -2.0, 0.5];
+    norm_inf.prox(&mut x, 1.5);
+    // Moreau decomposition: x - proj_{B1(1.5)}(x) = [3, -2, 0.5] - [1.25, -0.25, 0]
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.75, -1.75, 0.5],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of infinity norm",
+    );
+    unit_test_utils::assert_nearly_equal(1.75, norm_inf.value(&x), 1e-12, 1e-14, "value");
+
+    // inside the l1 ball of radius gamma*lambda, the prox is zero
+    let mut x = [0.5, -0.25, 0.5];
+    norm_inf.prox(&mut x, 1.5);
+    unit_test_utils::assert_nearly_equal_array(&[0.0; 3], &x, 1e-12, 1e-14, "prox inside ball");
+}
+
+#[test]
+fn t_norm_inf_random_optimality() {
+    let norm_inf = NormInf::new(2.0);
+    for _ in 0..50 {
+        let v = random_vector(7, 3.0);
+        assert_prox_optimality(&norm_inf, &v, 0.9);
+    }
+}
+
+#[test]
+fn t_huber_prox() {
+    let huber = Huber::new(1.0, 2.0);
+    let mut x = [0.9, -2.0, 5.0];
+    huber.prox(&mut x, 0.5);
+    unit_test_utils::assert_nearly_equal_array(
+        &[0.45, -1.0, 4.0],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of Huber function",
+    );
+    unit_test_utils::assert_nearly_equal(8.2025, huber.value(&x), 1e-12, 1e-14, "value");
+}
+
+#[test]
+fn t_huber_random_optimality() {
+    let huber = Huber::new(0.3, 1.1);
+    for _ in 0..50 {
+        let v = random_vector(6, 2.0);
+        assert_prox_optimality(&huber, &v, 0.7);
+    }
+}
+
+#[test]
+#[should_panic]
+fn t_huber_zero_delta() {
+    let _ = Huber::new(0.0, 1.0);
+}
+
+#[test]
+fn t_rectangle_norm_l1_prox() {
+    let xmin = [-1.0, 0.5, -5.0];
+    let xmax = [1.0, 2.0, 5.0];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    let rectangle_l1 = RectangleNormL1::new(rectangle, 1.0);
+    let mut x = [3.0, 0.2, -0.5];
+    rectangle_l1.prox(&mut x, 1.0);
+    unit_test_utils::assert_nearly_equal_array(
+        &[1.0, 0.5, 0.0],
+        &x,
+        1e-12,
+        1e-14,
+        "wrong prox of rectangle + l1",
+    );
+    unit_test_utils::assert_nearly_equal(1.5, rectangle_l1.value(&x), 1e-12, 1e-14, "value");
+}
+
+#[test]
+fn t_rectangle_norm_l1_brute_force() {
+    // compare with a brute-force minimization in one dimension
+    let xmin = [-0.7];
+    let xmax = [1.2];
+    let rectangle = Rectangle::new(Some(&xmin), Some(&xmax));
+    let lambda = 0.8;
+    let gamma = 0.6;
+    let rectangle_l1 = RectangleNormL1::new(rectangle, lambda);
+    for _ in 0..100 {
+        let v = 3.0 * (2. * rand::random::<f64>() - 1.);
+        let mut x = [v];
+        rectangle_l1.prox(&mut x, gamma);
+        let n_grid = 20_000;
+        let mut best = (f64::INFINITY, 0.0);
+        for k in 0..=n_grid {
+            let z = xmin[0] + (xmax[0] - xmin[0]) * (k as f64) / (n_grid as f64);
+            let obj = lambda * z.abs() + 0.5 * (z - v).powi(2) / gamma;
+            if obj < best.0 {
+                best = (obj, z);
+            }
+        }
+        assert!((best.1 - x[0]).abs() <= 1e-3, "wrong prox");
+    }
+}